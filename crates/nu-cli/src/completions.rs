@@ -1,195 +1,143 @@
-use nu_engine::eval_block;
-use nu_parser::{flatten_expression, parse};
+use nu_engine::{eval_block, eval_variable};
+use nu_parser::{flatten_expression, parse, FlatShape};
 use nu_protocol::{
-    ast::Statement,
+    ast::{PathMember, Statement},
     engine::{EngineState, Stack, StateWorkingSet},
-    PipelineData, Span,
+    DeclId, PipelineData, Span, Value, CONFIG_VARIABLE_ID, ENV_VARIABLE_ID, IN_VARIABLE_ID,
+    NU_VARIABLE_ID, SCOPE_VARIABLE_ID,
 };
 use reedline::Completer;
+use std::path::{Path, PathBuf};
 
 const SEP: char = std::path::MAIN_SEPARATOR;
 
+// The variables the parser knows without them being declared. Their values, like the environment
+// of `$env`, come from the stack of the completer.
+const BUILTIN_VARIABLES: [(&str, usize, &str); 5] = [
+    ("$nu", NU_VARIABLE_ID, "paths and environment of the shell"),
+    ("$env", ENV_VARIABLE_ID, "the environment variables"),
+    ("$config", CONFIG_VARIABLE_ID, "the configuration"),
+    (
+        "$scope",
+        SCOPE_VARIABLE_ID,
+        "the variables, commands and aliases in scope",
+    ),
+    ("$in", IN_VARIABLE_ID, "the input of the pipeline"),
+];
+
+/// A completion, with a description of what it is to show next to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub value: String,
+    pub description: Option<String>,
+    pub span: reedline::Span,
+}
+
 #[derive(Clone)]
 pub struct NuCompleter {
     engine_state: EngineState,
+    stack: Stack,
 }
 
 impl NuCompleter {
-    pub fn new(engine_state: EngineState) -> Self {
-        Self { engine_state }
+    /// The stack is used for the values of variables and the PATH to look for externals in
+    pub fn new(engine_state: EngineState, stack: Stack) -> Self {
+        Self {
+            engine_state,
+            stack,
+        }
     }
-}
 
-impl Completer for NuCompleter {
-    fn complete(&self, line: &str, pos: usize) -> Vec<(reedline::Span, String)> {
+    /// The completions for the text before `pos` in `line`
+    pub fn complete_with_descriptions(&self, line: &str, pos: usize) -> Vec<Suggestion> {
         let mut working_set = StateWorkingSet::new(&self.engine_state);
         let offset = working_set.next_span_start();
-        let pos = offset + pos;
+
         let (output, _err) = parse(&mut working_set, Some("completer"), line.as_bytes(), false);
 
+        // A cell path that is still being typed, eg) `$foo.`, doesn't parse to one, so these are
+        // found from the text
+        if let Some(suggestions) = self.variable_completion(&working_set, line, pos) {
+            return suggestions;
+        }
+
+        let pos = offset + pos;
+
         for stmt in output.stmts.into_iter() {
             if let Statement::Pipeline(pipeline) = stmt {
                 for expr in pipeline.expressions {
                     if pos >= expr.span.start
                         && (pos <= (line.len() + offset) || pos <= expr.span.end)
                     {
-                        let possible_cmd = working_set.get_span_contents(Span {
+                        let span = Span {
                             start: expr.span.start,
                             end: pos,
-                        });
-
-                        let results = working_set.find_commands_by_prefix(possible_cmd);
+                        };
+                        let results = self.command_completion(&working_set, span, true);
 
                         if !results.is_empty() {
-                            return results
-                                .into_iter()
-                                .map(move |x| {
-                                    (
-                                        reedline::Span {
-                                            start: expr.span.start - offset,
-                                            end: pos - offset,
-                                        },
-                                        String::from_utf8_lossy(&x).to_string(),
-                                    )
-                                })
-                                .collect();
+                            return to_suggestions(results, offset);
                         }
                     }
 
                     let flattened = flatten_expression(&working_set, &expr);
+                    let mut call_decl = None;
+
                     for flat in flattened {
-                        if pos >= flat.0.start && pos <= flat.0.end {
-                            match &flat.1 {
-                                nu_parser::FlatShape::Custom(custom_completion) => {
-                                    let prefix = working_set.get_span_contents(flat.0).to_vec();
+                        if flat.1 == FlatShape::InternalCall {
+                            if flat.0.start > pos {
+                                break;
+                            }
+                            call_decl =
+                                working_set.find_decl(working_set.get_span_contents(flat.0));
+                        }
 
-                                    let (block, ..) = parse(
-                                        &mut working_set,
-                                        None,
-                                        custom_completion.as_bytes(),
-                                        false,
-                                    );
+                        if pos >= flat.0.start && pos <= flat.0.end {
+                            let prefix = working_set.get_span_contents(flat.0);
 
-                                    let mut stack = Stack::default();
-                                    let result = eval_block(
-                                        &self.engine_state,
-                                        &mut stack,
-                                        &block,
-                                        PipelineData::new(flat.0),
-                                    );
+                            // Anything that starts with a dash might be a flag of the call it is in
+                            if prefix.starts_with(b"-") {
+                                if let Some(decl_id) = call_decl {
+                                    let results =
+                                        flag_completion(&working_set, decl_id, flat.0, prefix);
+                                    if !results.is_empty() {
+                                        return to_suggestions(results, offset);
+                                    }
+                                }
+                            }
 
-                                    let v: Vec<_> = match result {
-                                        Ok(pd) => pd
-                                            .into_iter()
-                                            .map(move |x| {
-                                                let s = x.as_string().expect(
-                                        "FIXME: better error handling for custom completions",
+                            match &flat.1 {
+                                FlatShape::Custom(custom_completion) => {
+                                    let results = self.custom_completion(
+                                        &mut working_set,
+                                        flat.0,
+                                        custom_completion,
                                     );
-
-                                                (
-                                                    reedline::Span {
-                                                        start: flat.0.start - offset,
-                                                        end: flat.0.end - offset,
-                                                    },
-                                                    s,
-                                                )
-                                            })
-                                            .filter(|x| x.1.as_bytes().starts_with(&prefix))
-                                            .collect(),
-                                        _ => vec![],
-                                    };
-
-                                    return v;
+                                    return to_suggestions(results, offset);
                                 }
-                                nu_parser::FlatShape::External
-                                | nu_parser::FlatShape::InternalCall
-                                | nu_parser::FlatShape::String => {
-                                    let prefix = working_set.get_span_contents(flat.0);
-                                    let results = working_set.find_commands_by_prefix(prefix);
+                                FlatShape::External
+                                | FlatShape::InternalCall
+                                | FlatShape::String => {
+                                    let mut results = self.command_completion(
+                                        &working_set,
+                                        flat.0,
+                                        flat.1 != FlatShape::String,
+                                    );
 
                                     let prefix = String::from_utf8_lossy(prefix).to_string();
-                                    let results2 = file_path_completion(flat.0, &prefix)
-                                        .into_iter()
-                                        .map(move |x| {
-                                            (
-                                                reedline::Span {
-                                                    start: x.0.start - offset,
-                                                    end: x.0.end - offset,
-                                                },
-                                                x.1,
-                                            )
-                                        });
-
-                                    return results
-                                        .into_iter()
-                                        .map(move |x| {
-                                            (
-                                                reedline::Span {
-                                                    start: flat.0.start - offset,
-                                                    end: flat.0.end - offset,
-                                                },
-                                                String::from_utf8_lossy(&x).to_string(),
-                                            )
-                                        })
-                                        .chain(results2.into_iter())
-                                        .collect();
+                                    results.extend(file_path_completion(flat.0, &prefix));
+
+                                    return to_suggestions(results, offset);
                                 }
-                                nu_parser::FlatShape::Filepath
-                                | nu_parser::FlatShape::GlobPattern
-                                | nu_parser::FlatShape::ExternalArg => {
-                                    let prefix = working_set.get_span_contents(flat.0);
+                                FlatShape::Filepath
+                                | FlatShape::GlobPattern
+                                | FlatShape::ExternalArg => {
                                     let prefix = String::from_utf8_lossy(prefix).to_string();
-
                                     let results = file_path_completion(flat.0, &prefix);
 
-                                    return results
-                                        .into_iter()
-                                        .map(move |x| {
-                                            (
-                                                reedline::Span {
-                                                    start: x.0.start - offset,
-                                                    end: x.0.end - offset,
-                                                },
-                                                x.1,
-                                            )
-                                        })
-                                        .collect();
-                                }
-                                _ => {
-                                    let prefix = working_set.get_span_contents(flat.0);
-
-                                    if prefix.starts_with(b"$") {
-                                        let mut output = vec![];
-
-                                        for scope in &working_set.delta.scope {
-                                            for v in &scope.vars {
-                                                if v.0.starts_with(prefix) {
-                                                    output.push((
-                                                        reedline::Span {
-                                                            start: flat.0.start - offset,
-                                                            end: flat.0.end - offset,
-                                                        },
-                                                        String::from_utf8_lossy(v.0).to_string(),
-                                                    ));
-                                                }
-                                            }
-                                        }
-                                        for scope in &self.engine_state.scope {
-                                            for v in &scope.vars {
-                                                if v.0.starts_with(prefix) {
-                                                    output.push((
-                                                        reedline::Span {
-                                                            start: flat.0.start - offset,
-                                                            end: flat.0.end - offset,
-                                                        },
-                                                        String::from_utf8_lossy(v.0).to_string(),
-                                                    ));
-                                                }
-                                            }
-                                        }
-                                        return output;
-                                    }
+                                    return to_suggestions(results, offset);
                                 }
+                                _ => {}
                             }
                         }
                     }
@@ -199,13 +147,348 @@ impl Completer for NuCompleter {
 
         vec![]
     }
+
+    // The commands starting with the text in the span. With `externals`, the executables on the
+    // PATH are included when the text is a single word.
+    fn command_completion(
+        &self,
+        working_set: &StateWorkingSet,
+        span: Span,
+        externals: bool,
+    ) -> Vec<(Span, String, Option<String>)> {
+        let prefix = working_set.get_span_contents(span);
+
+        let mut results: Vec<_> = working_set
+            .find_commands_by_prefix(prefix)
+            .into_iter()
+            .map(|name| {
+                let usage = working_set
+                    .find_decl(&name)
+                    .map(|decl_id| working_set.get_decl(decl_id).usage().to_string());
+
+                (span, String::from_utf8_lossy(&name).to_string(), usage)
+            })
+            .collect();
+
+        if externals && !prefix.is_empty() && !prefix.iter().any(u8::is_ascii_whitespace) {
+            let prefix = String::from_utf8_lossy(prefix);
+
+            for (name, path) in self.external_commands(&prefix) {
+                if !results.iter().any(|(_, value, _)| *value == name) {
+                    results.push((span, name, Some(path.to_string_lossy().to_string())));
+                }
+            }
+        }
+
+        results
+    }
+
+    // The executables in the PATH directories whose names start with the prefix. A name found
+    // in more than one directory is the one that would be run, the first.
+    fn external_commands(&self, prefix: &str) -> Vec<(String, PathBuf)> {
+        let mut results: Vec<(String, PathBuf)> = vec![];
+
+        for dir in self.path_dirs() {
+            if let Ok(entries) = dir.read_dir() {
+                for entry in entries.flatten() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+
+                    if name.starts_with(prefix)
+                        && is_executable(&entry.path())
+                        && !results.iter().any(|(found, _)| *found == name)
+                    {
+                        results.push((name, entry.path()));
+                    }
+                }
+            }
+        }
+
+        results
+    }
+
+    fn path_dirs(&self) -> Vec<PathBuf> {
+        let path = self
+            .stack
+            .get_env_var("PATH")
+            .or_else(|| self.stack.get_env_var("Path"));
+
+        match path {
+            // The PATH is a list once it has been converted from the environment
+            Some(Value::List { vals, .. }) => vals
+                .iter()
+                .filter_map(|dir| dir.as_string().ok())
+                .map(PathBuf::from)
+                .collect(),
+            Some(Value::String { val, .. }) => std::env::split_paths(&val).collect(),
+            _ => match std::env::var_os("PATH") {
+                Some(path) => std::env::split_paths(&path).collect(),
+                None => vec![],
+            },
+        }
+    }
+
+    fn custom_completion(
+        &self,
+        working_set: &mut StateWorkingSet,
+        span: Span,
+        custom_completion: &str,
+    ) -> Vec<(Span, String, Option<String>)> {
+        let prefix = working_set.get_span_contents(span).to_vec();
+
+        let (block, ..) = parse(working_set, None, custom_completion.as_bytes(), false);
+
+        let mut stack = Stack::default();
+        let result = eval_block(
+            &self.engine_state,
+            &mut stack,
+            &block,
+            PipelineData::new(span),
+        );
+
+        match result {
+            Ok(pd) => pd
+                .into_iter()
+                .filter_map(|x| x.as_string().ok())
+                .filter(|x| x.as_bytes().starts_with(&prefix))
+                .map(|x| (span, x, None))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    // Completes the name of a variable, or a column of its value when it is followed by a cell
+    // path, eg) `$nu.env.PA`. Returns None when the text before `pos` isn't a variable.
+    fn variable_completion(
+        &self,
+        working_set: &StateWorkingSet,
+        line: &str,
+        pos: usize,
+    ) -> Option<Vec<Suggestion>> {
+        let before = line.get(..pos)?;
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace() || "([{|;".contains(*c))
+            .map(|(idx, c)| idx + c.len_utf8())
+            .unwrap_or(0);
+        let word = &before[start..];
+
+        if !word.starts_with('$') || word.contains(|c: char| c == '"' || c == '\'') {
+            return None;
+        }
+
+        let mut members: Vec<&str> = word.split('.').collect();
+        let partial = members.pop()?;
+        let span = reedline::Span {
+            start: pos - partial.len(),
+            end: pos,
+        };
+
+        if members.is_empty() {
+            return Some(self.variable_names(working_set, partial, span));
+        }
+
+        let head = members.remove(0);
+        let var_id = BUILTIN_VARIABLES
+            .iter()
+            .find(|(name, ..)| *name == head)
+            .map(|(_, var_id, _)| *var_id)
+            .or_else(|| working_set.find_variable(head.as_bytes()))?;
+
+        let cell_path: Vec<_> = members
+            .into_iter()
+            .map(|member| match member.parse::<usize>() {
+                Ok(val) => PathMember::Int {
+                    val,
+                    span: Span::unknown(),
+                },
+                Err(_) => PathMember::String {
+                    val: member.to_string(),
+                    span: Span::unknown(),
+                },
+            })
+            .collect();
+
+        // Without a value there's nothing to complete, but it's still a variable
+        let value = eval_variable(&self.engine_state, &self.stack, var_id, Span::unknown())
+            .and_then(|value| value.follow_cell_path(&cell_path));
+
+        let suggestions = match value {
+            Ok(value) => columns(&value)
+                .into_iter()
+                .filter(|(column, _)| column.starts_with(partial))
+                .map(|(column, value)| Suggestion {
+                    value: column,
+                    description: value.map(|value| value.get_type().to_string()),
+                    span,
+                })
+                .collect(),
+            Err(_) => vec![],
+        };
+
+        Some(suggestions)
+    }
+
+    fn variable_names(
+        &self,
+        working_set: &StateWorkingSet,
+        prefix: &str,
+        span: reedline::Span,
+    ) -> Vec<Suggestion> {
+        let mut output: Vec<Suggestion> = BUILTIN_VARIABLES
+            .iter()
+            .filter(|(name, ..)| name.starts_with(prefix))
+            .map(|(name, _, description)| Suggestion {
+                value: name.to_string(),
+                description: Some(description.to_string()),
+                span,
+            })
+            .collect();
+
+        let names = working_set
+            .delta
+            .scope
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.vars.keys())
+            .chain(
+                self.engine_state
+                    .scope
+                    .iter()
+                    .rev()
+                    .flat_map(|scope| scope.vars.keys()),
+            );
+
+        for name in names {
+            if !name.starts_with(prefix.as_bytes()) {
+                continue;
+            }
+
+            let name = String::from_utf8_lossy(name).to_string();
+            if output.iter().any(|suggestion| suggestion.value == name) {
+                continue;
+            }
+
+            let description = working_set
+                .find_variable(name.as_bytes())
+                .map(|var_id| working_set.get_variable(var_id).to_string());
+
+            output.push(Suggestion {
+                value: name,
+                description,
+                span,
+            });
+        }
+
+        output
+    }
+}
+
+impl Completer for NuCompleter {
+    fn complete(&self, line: &str, pos: usize) -> Vec<(reedline::Span, String)> {
+        self.complete_with_descriptions(line, pos)
+            .into_iter()
+            .map(|suggestion| (suggestion.span, suggestion.value))
+            .collect()
+    }
+}
+
+fn to_suggestions(results: Vec<(Span, String, Option<String>)>, offset: usize) -> Vec<Suggestion> {
+    results
+        .into_iter()
+        .map(|(span, value, description)| Suggestion {
+            value,
+            description,
+            span: reedline::Span {
+                start: span.start - offset,
+                end: span.end - offset,
+            },
+        })
+        .collect()
+}
+
+// The long and short flags of the command that start with the prefix
+fn flag_completion(
+    working_set: &StateWorkingSet,
+    decl_id: DeclId,
+    span: Span,
+    prefix: &[u8],
+) -> Vec<(Span, String, Option<String>)> {
+    let signature = working_set.get_decl(decl_id).signature();
+    let mut output = vec![];
+
+    for flag in signature.named {
+        let long = format!("--{}", flag.long);
+        if !flag.long.is_empty() && long.as_bytes().starts_with(prefix) {
+            output.push((span, long, Some(flag.desc.clone())));
+            continue;
+        }
+
+        if let Some(short) = flag.short {
+            let short = format!("-{}", short);
+            if short.as_bytes().starts_with(prefix) {
+                output.push((span, short, Some(flag.desc)));
+            }
+        }
+    }
+
+    output
+}
+
+// The columns of a record, or of the rows of a table, with their value when there is one
+fn columns(value: &Value) -> Vec<(String, Option<Value>)> {
+    match value {
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .cloned()
+            .zip(vals.iter().cloned().map(Some))
+            .collect(),
+        Value::List { vals, .. } => {
+            let mut output: Vec<(String, Option<Value>)> = vec![];
+            for column in vals.iter().flat_map(|row| row.columns()) {
+                if !output.iter().any(|(found, _)| *found == column) {
+                    output.push((column, None));
+                }
+            }
+            output
+        }
+        _ => vec![],
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    const EXTENSIONS: [&str; 4] = ["exe", "bat", "cmd", "com"];
+
+    path.is_file()
+        && path
+            .extension()
+            .map(|extension| {
+                let extension = extension.to_string_lossy().to_ascii_lowercase();
+                EXTENSIONS.contains(&extension.as_str())
+            })
+            .unwrap_or(false)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
 }
 
 fn file_path_completion(
     span: nu_protocol::Span,
     partial: &str,
-) -> Vec<(nu_protocol::Span, String)> {
-    use std::path::{is_separator, Path};
+) -> Vec<(nu_protocol::Span, String, Option<String>)> {
+    use std::path::is_separator;
 
     let partial = if let Some(s) = partial.strip_prefix('"') {
         s
@@ -253,7 +536,7 @@ fn file_path_completion(
                             path = format!("\"{}\"", path);
                         }
 
-                        Some((span, path))
+                        Some((span, path, None))
                     } else {
                         None
                     }
@@ -269,3 +552,151 @@ fn matches(partial: &str, from: &str) -> bool {
     from.to_ascii_lowercase()
         .starts_with(&partial.to_ascii_lowercase())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nu_protocol::{ast::Call, engine::Command, ShellError, Signature, SyntaxShape, Type};
+
+    #[derive(Clone)]
+    struct Greet;
+
+    impl Command for Greet {
+        fn name(&self) -> &str {
+            "greet"
+        }
+
+        fn signature(&self) -> Signature {
+            Signature::build("greet")
+                .switch("verbose", "greet at length", Some('v'))
+                .named("name", SyntaxShape::String, "who to greet", Some('n'))
+        }
+
+        fn usage(&self) -> &str {
+            "Greet someone."
+        }
+
+        fn run(
+            &self,
+            _engine_state: &EngineState,
+            _stack: &mut Stack,
+            call: &Call,
+            _input: PipelineData,
+        ) -> Result<PipelineData, ShellError> {
+            Ok(PipelineData::new(call.head))
+        }
+    }
+
+    // A completer knowing the `greet` command and a `$person` record
+    fn completer() -> NuCompleter {
+        let mut engine_state = EngineState::new();
+        let mut stack = Stack::new();
+
+        let delta = {
+            let mut working_set = StateWorkingSet::new(&engine_state);
+            working_set.add_decl(Box::new(Greet));
+
+            let var_id = working_set.add_variable(b"$person".to_vec(), Type::Unknown);
+            stack.add_var(
+                var_id,
+                Value::Record {
+                    cols: vec!["name".into(), "age".into()],
+                    vals: vec![Value::test_string("Ada"), Value::test_int(36)],
+                    span: Span::unknown(),
+                },
+            );
+
+            working_set.render()
+        };
+        engine_state
+            .merge_delta(delta)
+            .expect("unable to merge delta");
+
+        stack.add_env_var("GREETING".into(), Value::test_string("hello"));
+
+        NuCompleter::new(engine_state, stack)
+    }
+
+    fn suggestion(value: &str, description: &str, start: usize, end: usize) -> Suggestion {
+        Suggestion {
+            value: value.into(),
+            description: Some(description.into()),
+            span: reedline::Span { start, end },
+        }
+    }
+
+    #[test]
+    fn completes_flags() {
+        let line = "greet --ver";
+
+        assert_eq!(
+            completer().complete_with_descriptions(line, line.len()),
+            vec![suggestion("--verbose", "greet at length", 6, 11)]
+        );
+    }
+
+    #[test]
+    fn completes_variables() {
+        let line = "echo $per";
+
+        assert_eq!(
+            completer().complete_with_descriptions(line, line.len()),
+            vec![suggestion("$person", "unknown", 5, 9)]
+        );
+    }
+
+    #[test]
+    fn completes_cell_paths() {
+        let line = "$person.na";
+
+        assert_eq!(
+            completer().complete_with_descriptions(line, line.len()),
+            vec![suggestion("name", "string", 8, 10)]
+        );
+    }
+
+    #[test]
+    fn completes_env_from_the_stack() {
+        let line = "$env.GREE";
+
+        assert_eq!(
+            completer().complete_with_descriptions(line, line.len()),
+            vec![suggestion("GREETING", "string", 5, 9)]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn completes_executables_on_path() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("nu-completions-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("unable to create directory");
+
+        let executable = dir.join("nu-greeter");
+        std::fs::write(&executable, "#!/bin/sh\n").expect("unable to write executable");
+        std::fs::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))
+            .expect("unable to make file executable");
+        std::fs::write(dir.join("nu-greeting.txt"), "").expect("unable to write file");
+
+        let mut completer = completer();
+        completer.stack.add_env_var(
+            "PATH".into(),
+            Value::test_string(dir.to_string_lossy().to_string()),
+        );
+
+        let line = "nu-greet";
+        let suggestions = completer.complete_with_descriptions(line, line.len());
+        std::fs::remove_dir_all(&dir).expect("unable to remove directory");
+
+        assert_eq!(
+            suggestions,
+            vec![suggestion(
+                "nu-greeter",
+                &executable.to_string_lossy(),
+                0,
+                8
+            )]
+        );
+    }
+}
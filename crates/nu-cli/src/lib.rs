@@ -4,7 +4,7 @@ mod prompt;
 mod syntax_highlight;
 mod validation;
 
-pub use completions::NuCompleter;
+pub use completions::{NuCompleter, Suggestion};
 pub use errors::CliError;
 pub use prompt::NushellPrompt;
 pub use syntax_highlight::NuHighlighter;
@@ -6,16 +6,16 @@ use {
 };
 
 /// Nushell prompt definition
+///
+/// Every part of the prompt can be customized, the ones that aren't fall back to their default.
 #[derive(Clone)]
 pub struct NushellPrompt {
-    prompt_command: String,
-    prompt_string: String,
-    // These are part of the struct definition in case we want to allow
-    // further customization to the shell status
-    default_prompt_indicator: String,
-    default_vi_insert_prompt_indicator: String,
-    default_vi_visual_prompt_indicator: String,
-    default_multiline_indicator: String,
+    left_prompt_string: Option<String>,
+    right_prompt_string: Option<String>,
+    default_prompt_indicator: Option<String>,
+    default_vi_insert_prompt_indicator: Option<String>,
+    default_vi_normal_prompt_indicator: Option<String>,
+    default_multiline_indicator: Option<String>,
 }
 
 impl Default for NushellPrompt {
@@ -27,22 +27,31 @@ impl Default for NushellPrompt {
 impl NushellPrompt {
     pub fn new() -> NushellPrompt {
         NushellPrompt {
-            prompt_command: "".to_string(),
-            prompt_string: "".to_string(),
-            default_prompt_indicator: "〉".to_string(),
-            default_vi_insert_prompt_indicator: ": ".to_string(),
-            default_vi_visual_prompt_indicator: "v ".to_string(),
-            default_multiline_indicator: "::: ".to_string(),
+            left_prompt_string: None,
+            right_prompt_string: None,
+            default_prompt_indicator: None,
+            default_vi_insert_prompt_indicator: None,
+            default_vi_normal_prompt_indicator: None,
+            default_multiline_indicator: None,
         }
     }
 
-    pub fn is_new_prompt(&self, prompt_command: &str) -> bool {
-        self.prompt_command != prompt_command
-    }
+    pub fn update_all_prompt_strings(
+        &mut self,
+        left_prompt_string: Option<String>,
+        right_prompt_string: Option<String>,
+        prompt_indicator_string: Option<String>,
+        prompt_multiline_indicator_string: Option<String>,
+        prompt_vi: (Option<String>, Option<String>),
+    ) {
+        let (prompt_vi_insert_string, prompt_vi_normal_string) = prompt_vi;
 
-    pub fn update_prompt(&mut self, prompt_command: String, prompt_string: String) {
-        self.prompt_command = prompt_command;
-        self.prompt_string = prompt_string;
+        self.left_prompt_string = left_prompt_string;
+        self.right_prompt_string = right_prompt_string;
+        self.default_prompt_indicator = prompt_indicator_string;
+        self.default_multiline_indicator = prompt_multiline_indicator_string;
+        self.default_vi_insert_prompt_indicator = prompt_vi_insert_string;
+        self.default_vi_normal_prompt_indicator = prompt_vi_normal_string;
     }
 
     fn default_wrapped_custom_string(&self, str: String) -> String {
@@ -51,25 +60,47 @@ impl NushellPrompt {
 }
 
 impl Prompt for NushellPrompt {
-    fn render_prompt(&self, _: usize) -> Cow<str> {
-        self.prompt_string.as_str().into()
+    fn render_prompt_left(&self) -> Cow<str> {
+        match &self.left_prompt_string {
+            Some(prompt) => prompt.as_str().into(),
+            None => "".into(),
+        }
+    }
+
+    fn render_prompt_right(&self) -> Cow<str> {
+        match &self.right_prompt_string {
+            Some(prompt) => prompt.as_str().into(),
+            None => "".into(),
+        }
     }
 
     fn render_prompt_indicator(&self, edit_mode: PromptEditMode) -> Cow<str> {
         match edit_mode {
-            PromptEditMode::Default => self.default_prompt_indicator.as_str().into(),
-            PromptEditMode::Emacs => self.default_prompt_indicator.as_str().into(),
+            PromptEditMode::Default | PromptEditMode::Emacs => match &self.default_prompt_indicator
+            {
+                Some(indicator) => indicator.as_str().into(),
+                None => "〉".into(),
+            },
             PromptEditMode::Vi(vi_mode) => match vi_mode {
-                PromptViMode::Normal => self.default_prompt_indicator.as_str().into(),
-                PromptViMode::Insert => self.default_vi_insert_prompt_indicator.as_str().into(),
-                PromptViMode::Visual => self.default_vi_visual_prompt_indicator.as_str().into(),
+                PromptViMode::Normal => match &self.default_vi_normal_prompt_indicator {
+                    Some(indicator) => indicator.as_str().into(),
+                    None => "〉".into(),
+                },
+                PromptViMode::Insert => match &self.default_vi_insert_prompt_indicator {
+                    Some(indicator) => indicator.as_str().into(),
+                    None => ": ".into(),
+                },
+                PromptViMode::Visual => "v ".into(),
             },
             PromptEditMode::Custom(str) => self.default_wrapped_custom_string(str).into(),
         }
     }
 
     fn render_prompt_multiline_indicator(&self) -> Cow<str> {
-        Cow::Borrowed(self.default_multiline_indicator.as_str())
+        match &self.default_multiline_indicator {
+            Some(indicator) => indicator.as_str().into(),
+            None => "::: ".into(),
+        }
     }
 
     fn render_prompt_history_search_indicator(
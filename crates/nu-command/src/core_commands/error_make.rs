@@ -0,0 +1,76 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct ErrorMake;
+
+impl Command for ErrorMake {
+    fn name(&self) -> &str {
+        "error make"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("error make")
+            .required(
+                "error_struct",
+                SyntaxShape::Any,
+                "the error to create, as a record with 'msg' and 'label' fields",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Create an error."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let arg: Value = call.req(engine_state, stack, 0)?;
+
+        Err(make_error(&arg, call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Create a custom error for a custom command",
+            example: r#"def foo [x] { error make {msg: "this is fishy", label: "fish right here"} }"#,
+            result: None,
+        }]
+    }
+}
+
+fn make_error(value: &Value, span: nu_protocol::Span) -> ShellError {
+    match value {
+        Value::Record { .. } => {
+            let msg = value.get_data_by_key("msg");
+            let label = value.get_data_by_key("label");
+
+            match (msg, label) {
+                (Some(Value::String { val: msg, .. }), Some(Value::String { val: label, .. })) => {
+                    ShellError::SpannedLabeledError(msg, label, span)
+                }
+                (Some(Value::String { val: msg, .. }), None) => {
+                    ShellError::SpannedLabeledError(msg, "originates from here".into(), span)
+                }
+                _ => ShellError::SpannedLabeledError(
+                    "Creating error value not supported.".into(),
+                    "expected a record with a string 'msg' and optional string 'label'".into(),
+                    value.span().unwrap_or(span),
+                ),
+            }
+        }
+        Value::Error { error } => error.clone(),
+        other => ShellError::SpannedLabeledError(
+            "Creating error value not supported.".into(),
+            format!("expected a record, found {}", other.get_type()),
+            other.span().unwrap_or(span),
+        ),
+    }
+}
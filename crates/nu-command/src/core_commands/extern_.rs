@@ -0,0 +1,52 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, Signature, SyntaxShape};
+
+#[derive(Clone)]
+pub struct Extern;
+
+impl Command for Extern {
+    fn name(&self) -> &str {
+        "extern"
+    }
+
+    fn usage(&self) -> &str {
+        "Define a signature for an external command"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Calls to the external are parsed with the signature, so their flags are checked and completed, and are then run as the external with the arguments as they were written."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("extern")
+            .required("def_name", SyntaxShape::String, "definition name")
+            .required("params", SyntaxShape::Signature, "parameters")
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Declare the flags of git clone",
+                example: r#"extern "git clone" [repository: string, --depth: int, --branch(-b): string]"#,
+                result: None,
+            },
+            Example {
+                description: "Complete the branches of git checkout with a custom command",
+                example: r#"def git-branches [] { ^git branch | lines | str trim }; extern "git checkout" [branch: string(git-branches)]"#,
+                result: None,
+            },
+        ]
+    }
+}
@@ -2,9 +2,10 @@ use nu_engine::{eval_block, eval_expression};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, Signature, Span, SyntaxShape,
-    Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Value,
 };
+use std::sync::atomic::Ordering;
 
 #[derive(Clone)]
 pub struct For;
@@ -65,37 +66,72 @@ impl Command for For {
         let block = engine_state.get_block(block_id).clone();
         let mut stack = stack.collect_captures(&block.captures);
 
-        match values {
-            Value::List { vals, .. } => Ok(vals
-                .into_iter()
-                .map(move |x| {
-                    stack.add_var(var_id, x);
-
-                    //let block = engine_state.get_block(block_id);
-                    match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head)) {
-                        Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+        let values = match values {
+            Value::List { vals, .. } => {
+                // A list is already in memory, so its loop runs right away. That way a `break`,
+                // `continue` or `return` in it is handled even when nothing reads the output
+                let mut output = vec![];
+                for x in vals {
+                    if let Some(ctrlc) = &ctrlc {
+                        if ctrlc.load(Ordering::SeqCst) {
+                            break;
+                        }
                     }
-                })
-                .into_pipeline_data(ctrlc)),
-            Value::Range { val, .. } => Ok(val
-                .into_range_iter()?
-                .map(move |x| {
+
                     stack.add_var(var_id, x);
 
-                    //let block = engine_state.get_block(block_id);
                     match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head)) {
-                        Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+                        Ok(pipeline_data) => output.push(pipeline_data.into_value(head)),
+                        Err(ShellError::Break(_)) => break,
+                        Err(ShellError::Continue(_)) => continue,
+                        Err(error @ ShellError::Return(..)) => return Err(error),
+                        Err(error) => output.push(Value::Error { error }),
                     }
-                })
-                .into_pipeline_data(ctrlc)),
+                }
+
+                return Ok(Value::List {
+                    vals: output,
+                    span: head,
+                }
+                .into_pipeline_data());
+            }
+            Value::Range { val, .. } => val.into_range_iter()?,
             x => {
                 stack.add_var(var_id, x);
 
-                eval_block(&engine_state, &mut stack, &block, PipelineData::new(head))
+                return match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head))
+                {
+                    Err(ShellError::Break(_)) | Err(ShellError::Continue(_)) => {
+                        Ok(PipelineData::new(head))
+                    }
+                    result => result,
+                };
             }
-        }
+        };
+
+        // A range can be endless, so its loop stays a lazy stream. A `break` ends it, a `continue`
+        // leaves nothing for its iteration, and a `return` is passed on as the last value for the
+        // custom command to catch
+        Ok(values
+            .scan(false, move |returned, x| {
+                if *returned {
+                    return None;
+                }
+
+                stack.add_var(var_id, x);
+
+                match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head)) {
+                    Ok(pipeline_data) => Some(Some(pipeline_data.into_value(head))),
+                    Err(ShellError::Break(_)) => None,
+                    Err(ShellError::Continue(_)) => Some(None),
+                    Err(error) => {
+                        *returned = matches!(error, ShellError::Return(..));
+                        Some(Some(Value::Error { error }))
+                    }
+                }
+            })
+            .flatten()
+            .into_pipeline_data(ctrlc))
     }
 
     fn examples(&self) -> Vec<Example> {
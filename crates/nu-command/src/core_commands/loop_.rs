@@ -0,0 +1,69 @@
+use nu_engine::eval_block;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+use std::sync::atomic::Ordering;
+
+#[derive(Clone)]
+pub struct Loop;
+
+impl Command for Loop {
+    fn name(&self) -> &str {
+        "loop"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block in a loop until it breaks or returns."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("loop")
+            .required("block", SyntaxShape::Block(Some(vec![])), "block to loop")
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let block_id = call.positional[0]
+            .as_block()
+            .expect("internal error: expected block");
+
+        let block = engine_state.get_block(block_id);
+        let mut stack = stack.collect_captures(&block.captures);
+
+        loop {
+            if let Some(ctrlc) = &engine_state.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            match eval_block(engine_state, &mut stack, block, PipelineData::new(head)) {
+                Ok(pipeline_data) => {
+                    if let Value::Error { error } = pipeline_data.into_value(head) {
+                        return Err(error);
+                    }
+                }
+                Err(ShellError::Break(_)) => break,
+                Err(ShellError::Continue(_)) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(PipelineData::new(head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Retry until a condition holds",
+            example: "loop { if (random bool) { break } }",
+            result: None,
+        }]
+    }
+}
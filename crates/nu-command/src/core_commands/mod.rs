@@ -1,42 +1,70 @@
 mod alias;
+mod break_;
+mod continue_;
 mod debug;
 mod def;
 mod describe;
 mod do_;
 mod echo;
+mod error_make;
 mod export;
 mod export_def;
 mod export_env;
+mod extern_;
 mod for_;
 mod help;
 mod hide;
 mod if_;
 mod let_;
+mod loop_;
 mod module;
+mod return_;
 mod source;
+mod try_;
 mod use_;
 mod version;
+mod while_;
 
 pub use alias::Alias;
+pub use break_::Break;
+pub use continue_::Continue;
 pub use debug::Debug;
 pub use def::Def;
 pub use describe::Describe;
 pub use do_::Do;
 pub use echo::Echo;
+pub use error_make::ErrorMake;
 pub use export::ExportCommand;
 pub use export_def::ExportDef;
 pub use export_env::ExportEnv;
+pub use extern_::Extern;
 pub use for_::For;
 pub use help::Help;
 pub use hide::Hide;
 pub use if_::If;
 pub use let_::Let;
+pub use loop_::Loop;
 pub use module::Module;
+pub use return_::Return;
 pub use source::Source;
+pub use try_::Try;
 pub use use_::Use;
 pub use version::Version;
+pub use while_::While;
+#[cfg(feature = "plugin")]
+mod plugin_list;
+#[cfg(feature = "plugin")]
+mod plugin_rescan;
+#[cfg(feature = "plugin")]
+mod plugin_stop;
 #[cfg(feature = "plugin")]
 mod register;
 
+#[cfg(feature = "plugin")]
+pub use plugin_list::PluginList;
+#[cfg(feature = "plugin")]
+pub use plugin_rescan::PluginRescan;
+#[cfg(feature = "plugin")]
+pub use plugin_stop::PluginStop;
 #[cfg(feature = "plugin")]
 pub use register::Register;
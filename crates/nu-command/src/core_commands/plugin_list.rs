@@ -0,0 +1,135 @@
+use nu_plugin::plugin_manager;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Value,
+};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone)]
+pub struct PluginList;
+
+impl Command for PluginList {
+    fn name(&self) -> &str {
+        "plugin list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin list").category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "List the registered plugins and whether their process is running."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let running = plugin_manager().running();
+
+        // The commands each plugin provides, grouped by the plugin's file
+        let mut plugins: Vec<(PathBuf, String, Vec<String>)> = vec![];
+        for decl in engine_state.plugin_decls() {
+            let (filename, encoding) = decl.is_plugin().expect("plugin should have file name");
+
+            match plugins.iter_mut().find(|(path, ..)| path == filename) {
+                Some((_, _, commands)) => commands.push(decl.name().to_string()),
+                None => plugins.push((
+                    filename.clone(),
+                    encoding.to_string(),
+                    vec![decl.name().to_string()],
+                )),
+            }
+        }
+
+        let output: Vec<Value> = plugins
+            .into_iter()
+            .map(|(filename, encoding, commands)| {
+                let pid = running
+                    .iter()
+                    .find(|plugin| plugin.filename == filename)
+                    .map(|plugin| plugin.pid);
+
+                Value::Record {
+                    cols: vec![
+                        "name".into(),
+                        "filename".into(),
+                        "encoding".into(),
+                        "running".into(),
+                        "pid".into(),
+                        "commands".into(),
+                    ],
+                    vals: vec![
+                        Value::String {
+                            val: plugin_name(&filename),
+                            span,
+                        },
+                        Value::String {
+                            val: filename.to_string_lossy().to_string(),
+                            span,
+                        },
+                        Value::String {
+                            val: encoding,
+                            span,
+                        },
+                        Value::Bool {
+                            val: pid.is_some(),
+                            span,
+                        },
+                        match pid {
+                            Some(pid) => Value::Int {
+                                val: pid as i64,
+                                span,
+                            },
+                            None => Value::Nothing { span },
+                        },
+                        Value::List {
+                            vals: commands
+                                .into_iter()
+                                .map(|val| Value::String { val, span })
+                                .collect(),
+                            span,
+                        },
+                    ],
+                    span,
+                }
+            })
+            .collect();
+
+        Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the plugins that currently have a running process",
+            example: "plugin list | where running",
+            result: None,
+        }]
+    }
+}
+
+/// The name a plugin goes by in `plugin list` and `plugin stop`: its file name without extension
+pub fn plugin_name(filename: &Path) -> String {
+    filename
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| filename.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(PluginList {})
+    }
+}
@@ -0,0 +1,118 @@
+use nu_plugin::{
+    get_signature, plugin_hash, plugin_manager, plugin_modified, EncodingType, PluginDeclaration,
+};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack, StateWorkingSet};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature};
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct PluginRescan;
+
+impl Command for PluginRescan {
+    fn name(&self) -> &str {
+        "plugin rescan"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin rescan").category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Ask the registered plugins for their signatures again."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The signatures of the plugins are saved in the plugin file and only read again when a plugin has changed. This forces them to be read again, restarting the plugins that are running. The commands are updated once the command line has run, and the new signatures are saved to the plugin file."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        // The registered plugins, each with the encoding it was registered with, and the names
+        // of their commands
+        let mut plugins: Vec<(PathBuf, EncodingType)> = vec![];
+        let mut plugin_commands: Vec<String> = vec![];
+        for decl in engine_state.plugin_decls() {
+            if let Some((path, encoding)) = decl.is_plugin() {
+                plugin_commands.push(decl.name().to_string());
+
+                if plugins.iter().any(|(registered, _)| registered == path) {
+                    continue;
+                }
+
+                if let Some(encoding) = EncodingType::try_from_bytes(encoding.as_bytes()) {
+                    plugins.push((path.clone(), encoding));
+                }
+            }
+        }
+
+        let mut working_set = StateWorkingSet::new(engine_state);
+
+        // The commands a plugin no longer has stay hidden, the others are replaced below
+        for name in plugin_commands {
+            working_set.hide_decl(name.as_bytes());
+        }
+
+        for (path, encoding) in plugins {
+            // A plugin that is still running would answer with the signatures it was started with
+            plugin_manager().stop(&path);
+
+            // Read before the plugin is called, so a change made while it is running isn't missed
+            let modified = plugin_modified(&path);
+            let hash = plugin_hash(&path);
+
+            let signatures = get_signature(&path, &encoding).map_err(|err| {
+                ShellError::SpannedLabeledError(
+                    "Error getting signatures".into(),
+                    err.to_string(),
+                    call.head,
+                )
+            })?;
+
+            for signature in signatures {
+                let plugin_decl = PluginDeclaration::new(
+                    path.clone(),
+                    signature,
+                    encoding.clone(),
+                    modified,
+                    hash.clone(),
+                );
+
+                working_set.add_decl(Box::new(plugin_decl));
+            }
+        }
+
+        working_set.mark_plugins_file_dirty();
+
+        // The commands can't change the state they run with, so the new declarations are merged
+        // once the evaluation is done, which also writes them to the plugin file
+        engine_state.queue_delta(working_set.render());
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Update the commands of the registered plugins",
+            example: "plugin rescan",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(PluginRescan {})
+    }
+}
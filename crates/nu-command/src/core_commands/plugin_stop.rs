@@ -0,0 +1,79 @@
+use super::plugin_list::plugin_name;
+use nu_engine::CallExt;
+use nu_plugin::plugin_manager;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct PluginStop;
+
+impl Command for PluginStop {
+    fn name(&self) -> &str {
+        "plugin stop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("plugin stop")
+            .required(
+                "name",
+                SyntaxShape::String,
+                "the name or filename of the plugin to stop",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Stop the running process of a plugin."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The plugin is started again the next time one of its commands is used."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+        let plugin = plugin_manager().running().into_iter().find(|plugin| {
+            plugin_name(&plugin.filename) == name.item || plugin.filename == Path::new(&name.item)
+        });
+
+        match plugin {
+            Some(plugin) if plugin_manager().stop(&plugin.filename) => {
+                Ok(PipelineData::new(call.head))
+            }
+            _ => Err(ShellError::SpannedLabeledError(
+                "Plugin not running".into(),
+                format!("no running plugin named '{}'", name.item),
+                name.span,
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop the inc plugin",
+            example: "plugin stop nu_plugin_inc",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(PluginStop {})
+    }
+}
@@ -14,6 +14,10 @@ impl Command for Register {
         "Register a plugin"
     }
 
+    fn extra_usage(&self) -> &str {
+        "When a signature is given, the plugin is only asked for its signatures again if it has changed since its --modified time, and its contents no longer match its --hash."
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("register")
             .required(
@@ -27,6 +31,18 @@ impl Command for Register {
                 "Encoding used to communicate with plugin. Options: [capnp, json]",
                 Some('e'),
             )
+            .named(
+                "modified",
+                SyntaxShape::Int,
+                "Modification time of the plugin when the signature was read from it",
+                None,
+            )
+            .named(
+                "hash",
+                SyntaxShape::String,
+                "Sha256 hash of the plugin when the signature was read from it",
+                None,
+            )
             .optional(
                 "signature",
                 SyntaxShape::Any,
@@ -0,0 +1,45 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct Return;
+
+impl Command for Return {
+    fn name(&self) -> &str {
+        "return"
+    }
+
+    fn usage(&self) -> &str {
+        "Return early from a custom command."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("return")
+            .optional("return_value", SyntaxShape::Any, "optional value to return")
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let return_value: Option<Value> = call.opt(engine_state, stack, 0)?;
+
+        let return_value = return_value.unwrap_or(Value::Nothing { span: call.head });
+
+        Err(ShellError::Return(call.head, Box::new(return_value)))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Return early",
+            example: r#"def foo [] { return "early"; "late" }"#,
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,144 @@
+use nu_engine::eval_block;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Try;
+
+impl Command for Try {
+    fn name(&self) -> &str {
+        "try"
+    }
+
+    fn usage(&self) -> &str {
+        "Try to run a block, if it fails optionally run a catch block."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("try")
+            .required(
+                "try_block",
+                SyntaxShape::Block(Some(vec![])),
+                "block to run",
+            )
+            .optional(
+                "catch_block",
+                SyntaxShape::Keyword(
+                    b"catch".to_vec(),
+                    Box::new(SyntaxShape::Block(Some(vec![SyntaxShape::Any]))),
+                ),
+                "block to run if the try block fails, receiving the error as a record",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let try_block = call.positional[0]
+            .as_block()
+            .expect("internal error: expected block");
+        let catch_block = call
+            .positional
+            .get(1)
+            .and_then(|catch_case| catch_case.as_keyword())
+            .and_then(|catch_expr| catch_expr.as_block());
+
+        let block = engine_state.get_block(try_block);
+        let mut try_stack = stack.collect_captures(&block.captures);
+
+        let error = match eval_block(engine_state, &mut try_stack, block, input) {
+            Err(error) if error.is_control_flow() => return Err(error),
+            Err(error) => error,
+            Ok(PipelineData::Value(Value::Error { error }, ..)) => error,
+            Ok(PipelineData::Stream(stream, ..)) => {
+                // An error anywhere in the stream fails the whole block, so the output has to be
+                // collected before it can be handed on
+                let mut vals = vec![];
+                for val in stream {
+                    if let Value::Error { error } = val {
+                        return run_catch(engine_state, stack, call, catch_block, error);
+                    }
+                    vals.push(val);
+                }
+
+                return Ok(Value::List {
+                    vals,
+                    span: call.head,
+                }
+                .into_pipeline_data());
+            }
+            Ok(output) => return Ok(output),
+        };
+
+        run_catch(engine_state, stack, call, catch_block, error)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Try to run a failing block, ignoring the error",
+                example: "try { 'nu' | into int }",
+                result: Some(Value::Nothing {
+                    span: nu_protocol::Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Try to run a failing block, running another block on failure",
+                example: "try { 'nu' | into int } catch { |e| 'fallback' }",
+                result: Some(Value::test_string("fallback")),
+            },
+            Example {
+                description: "Inspect the error that was raised",
+                example:
+                    "try { error make {msg: 'oops', label: 'raised here'} } catch { |e| $e.msg }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn run_catch(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    catch_block: Option<nu_protocol::BlockId>,
+    error: ShellError,
+) -> Result<PipelineData, ShellError> {
+    match catch_block {
+        Some(block_id) => {
+            let block = engine_state.get_block(block_id);
+            let mut stack = stack.collect_captures(&block.captures);
+
+            let error = error.into_value(call.head);
+
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, error.clone());
+                }
+            }
+
+            eval_block(engine_state, &mut stack, block, error.into_pipeline_data())
+        }
+        None => Ok(PipelineData::new(call.head)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Try {})
+    }
+}
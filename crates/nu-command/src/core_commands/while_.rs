@@ -0,0 +1,116 @@
+use nu_engine::{eval_block, eval_expression};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+use std::sync::atomic::Ordering;
+
+#[derive(Clone)]
+pub struct While;
+
+impl Command for While {
+    fn name(&self) -> &str {
+        "while"
+    }
+
+    fn usage(&self) -> &str {
+        "Conditionally run a block in a loop."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("while")
+            .required("cond", SyntaxShape::Expression, "condition to check")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "block to loop if check succeeds",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cond = &call.positional[0];
+        let block_id = call.positional[1]
+            .as_block()
+            .expect("internal error: expected block");
+
+        let block = engine_state.get_block(block_id);
+        let mut block_stack = stack.collect_captures(&block.captures);
+
+        loop {
+            if let Some(ctrlc) = &engine_state.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            let result = eval_expression(engine_state, stack, cond)?;
+            match &result {
+                Value::Bool { val: true, .. } => {
+                    match eval_block(
+                        engine_state,
+                        &mut block_stack,
+                        block,
+                        PipelineData::new(head),
+                    ) {
+                        Ok(pipeline_data) => {
+                            if let Value::Error { error } = pipeline_data.into_value(head) {
+                                return Err(error);
+                            }
+                        }
+                        Err(ShellError::Break(_)) => break,
+                        Err(ShellError::Continue(_)) => continue,
+                        Err(error) => return Err(error),
+                    }
+                }
+                Value::Bool { val: false, .. } => break,
+                x => {
+                    return Err(ShellError::CantConvert(
+                        "bool".into(),
+                        x.get_type().to_string(),
+                        result.span()?,
+                    ))
+                }
+            }
+        }
+
+        Ok(PipelineData::new(head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Skip the block when the condition does not hold",
+                example: "while false { 'never runs' }",
+                result: Some(Value::Nothing {
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Stop the loop early",
+                example: "while true { break }",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(While {})
+    }
+}
@@ -0,0 +1,385 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+use polars::{
+    frame::groupby::GroupBy,
+    prelude::{col, Expr, LazyFrame, PolarsError},
+};
+
+use super::values::{Column, NuDataFrame, NuGroupBy, NuLazyFrame, NuLazyGroupBy};
+
+enum Operation {
+    Mean,
+    Sum,
+    Min,
+    Max,
+    First,
+    Last,
+    Quantile(f64),
+    Median,
+    Var,
+    Std,
+    Count,
+}
+
+impl Operation {
+    fn from_tagged(
+        name: &Spanned<String>,
+        quantile: Option<Spanned<f64>>,
+    ) -> Result<Operation, ShellError> {
+        match name.item.as_ref() {
+            "mean" => Ok(Operation::Mean),
+            "sum" => Ok(Operation::Sum),
+            "min" => Ok(Operation::Min),
+            "max" => Ok(Operation::Max),
+            "first" => Ok(Operation::First),
+            "last" => Ok(Operation::Last),
+            "quantile" => match quantile {
+                None => Err(ShellError::SpannedLabeledError(
+                    "Quantile value not found".into(),
+                    "quantile operation requires a quantile value, eg) --quantile 0.5".into(),
+                    name.span,
+                )),
+                Some(value) => {
+                    if (value.item < 0.0) | (value.item > 1.0) {
+                        Err(ShellError::SpannedLabeledError(
+                            "Inappropriate quantile".into(),
+                            "quantile value should be between 0.0 and 1.0".into(),
+                            value.span,
+                        ))
+                    } else {
+                        Ok(Operation::Quantile(value.item))
+                    }
+                }
+            },
+            "median" => Ok(Operation::Median),
+            "var" => Ok(Operation::Var),
+            "std" => Ok(Operation::Std),
+            "count" => Ok(Operation::Count),
+            _ => Err(ShellError::SpannedLabeledError(
+                "Operation not found".into(),
+                "expected one of mean, sum, min, max, first, last, quantile, median, var, std or count"
+                    .into(),
+                name.span,
+            )),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Self::Mean => "mean",
+            Self::Sum => "sum",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::First => "first",
+            Self::Last => "last",
+            Self::Quantile(_) => "quantile",
+            Self::Median => "median",
+            Self::Var => "var",
+            Self::Std => "std",
+            Self::Count => "count",
+        }
+    }
+
+    // What polars adds to the names of the columns it aggregates, eg) b_sum or b_quantile_0.50
+    fn column_suffix(&self) -> &'static str {
+        match self {
+            Self::Var => "_agg_var",
+            Self::Std => "_agg_std",
+            Self::Mean => "_mean",
+            Self::Sum => "_sum",
+            Self::Min => "_min",
+            Self::Max => "_max",
+            Self::First => "_first",
+            Self::Last => "_last",
+            Self::Quantile(_) => "_quantile",
+            Self::Median => "_median",
+            Self::Count => "_count",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateDF;
+
+impl Command for AggregateDF {
+    fn name(&self) -> &str {
+        "dataframe aggregate"
+    }
+
+    fn usage(&self) -> &str {
+        "Performs an aggregation operation on a dataframe or a groupby object, lazy or not"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "operation",
+                SyntaxShape::String,
+                "aggregate operation: mean, sum, min, max, first, last, quantile, median, var, std or count",
+            )
+            .named(
+                "quantile",
+                SyntaxShape::Number,
+                "quantile value for the quantile operation",
+                Some('q'),
+            )
+            .switch(
+                "explicit",
+                "keep the operation in the names of the aggregated columns",
+                Some('e'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Aggregate sum by grouping by column a and summing on col b",
+                example:
+                    "[[a b]; [one 1] [one 2] [two 3]] | dataframe to-df | dataframe group-by a | dataframe aggregate sum",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new(
+                            "a".to_string(),
+                            vec!["one".to_string().into(), "two".to_string().into()],
+                        ),
+                        Column::new("b".to_string(), vec![3.into(), 3.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "Aggregate sum in dataframe columns",
+                example: "[[a b]; [4 1] [5 2]] | dataframe to-df | dataframe aggregate sum",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![9.into()]),
+                        Column::new("b".to_string(), vec![3.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "Aggregate sum of a lazy dataframe grouped by column a",
+                example:
+                    "[[a b]; [one 1] [one 2]] | dataframe to-df | dataframe to-lazy | dataframe group-by a | dataframe aggregate sum | dataframe collect",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec!["one".to_string().into()]),
+                        Column::new("b".to_string(), vec![3.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let operation: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let quantile: Option<Spanned<f64>> = call.get_flag(engine_state, stack, "quantile")?;
+    let op = Operation::from_tagged(&operation, quantile)?;
+
+    match input.into_value(call.head) {
+        Value::CustomValue { val, span } => {
+            if let Some(lazy_groupby) = val.as_any().downcast_ref::<NuLazyGroupBy>() {
+                let res = perform_lazy_groupby_aggregation(
+                    lazy_groupby.clone(),
+                    &op,
+                    call.has_flag("explicit"),
+                );
+
+                return Ok(PipelineData::Value(
+                    NuLazyFrame::from(res).into_value(span),
+                    None,
+                ));
+            }
+
+            let df = val.as_any().downcast_ref::<NuDataFrame>();
+            let groupby = val.as_any().downcast_ref::<NuGroupBy>();
+
+            match (df, groupby) {
+                (Some(df), None) => {
+                    let res = perform_dataframe_aggregation(df.as_ref(), op, operation.span)?;
+
+                    Ok(PipelineData::Value(
+                        NuDataFrame::dataframe_into_value(res, span),
+                        None,
+                    ))
+                }
+                (None, Some(nu_groupby)) => {
+                    let groupby = nu_groupby.to_groupby()?;
+
+                    let res = perform_groupby_aggregation(
+                        groupby,
+                        op,
+                        operation.span,
+                        call.head,
+                        call.has_flag("explicit"),
+                    )?;
+
+                    Ok(PipelineData::Value(
+                        NuDataFrame::dataframe_into_value(res, span),
+                        None,
+                    ))
+                }
+                _ => Err(ShellError::IncompatibleParametersSingle(
+                    "expected a dataframe or a groupby object".into(),
+                    call.head,
+                )),
+            }
+        }
+        _ => Err(ShellError::IncompatibleParametersSingle(
+            "expected a dataframe or a groupby object".into(),
+            call.head,
+        )),
+    }
+}
+
+fn perform_groupby_aggregation(
+    groupby: GroupBy,
+    operation: Operation,
+    operation_span: Span,
+    agg_span: Span,
+    explicit: bool,
+) -> Result<polars::prelude::DataFrame, ShellError> {
+    let mut res = match &operation {
+        Operation::Mean => groupby.mean(),
+        Operation::Sum => groupby.sum(),
+        Operation::Min => groupby.min(),
+        Operation::Max => groupby.max(),
+        Operation::First => groupby.first(),
+        Operation::Last => groupby.last(),
+        Operation::Quantile(quantile) => groupby.quantile(*quantile),
+        Operation::Median => groupby.median(),
+        Operation::Var => groupby.var(),
+        Operation::Std => groupby.std(),
+        Operation::Count => groupby.count(),
+    }
+    .map_err(|e| {
+        let span = match &e {
+            PolarsError::NotFound(_) => agg_span,
+            _ => operation_span,
+        };
+
+        ShellError::SpannedLabeledError("Error calculating aggregation".into(), e.to_string(), span)
+    })?;
+
+    // The aggregated columns get back their own names, unless the operation is asked for
+    if !explicit {
+        let col_names = res
+            .get_column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<String>>();
+
+        for col in col_names {
+            if let Some(index) = col.find(operation.column_suffix()) {
+                res.rename(&col, &col[..index])
+                    .expect("Column is always there. Looping with known names");
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+// Every column that isn't grouped by is aggregated, and named like the eager aggregation names it
+fn perform_lazy_groupby_aggregation(
+    groupby: NuLazyGroupBy,
+    operation: &Operation,
+    explicit: bool,
+) -> LazyFrame {
+    let aggs = groupby
+        .value_columns()
+        .iter()
+        .map(|name| {
+            let expr = col(name);
+            let expr = match operation {
+                Operation::Mean => expr.mean(),
+                Operation::Sum => expr.sum(),
+                Operation::Min => expr.min(),
+                Operation::Max => expr.max(),
+                Operation::First => expr.first(),
+                Operation::Last => expr.last(),
+                Operation::Quantile(quantile) => expr.quantile(*quantile),
+                Operation::Median => expr.median(),
+                Operation::Var => expr.var(),
+                Operation::Std => expr.std(),
+                Operation::Count => expr.count(),
+            };
+
+            if explicit {
+                expr.alias(&format!("{}{}", name, operation.column_suffix()))
+            } else {
+                expr.alias(name)
+            }
+        })
+        .collect::<Vec<Expr>>();
+
+    groupby.agg(aggs)
+}
+
+fn perform_dataframe_aggregation(
+    dataframe: &polars::prelude::DataFrame,
+    operation: Operation,
+    operation_span: Span,
+) -> Result<polars::prelude::DataFrame, ShellError> {
+    match operation {
+        Operation::Mean => Ok(dataframe.mean()),
+        Operation::Sum => Ok(dataframe.sum()),
+        Operation::Min => Ok(dataframe.min()),
+        Operation::Max => Ok(dataframe.max()),
+        Operation::Quantile(quantile) => dataframe.quantile(quantile).map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error calculating quantile".into(),
+                e.to_string(),
+                operation_span,
+            )
+        }),
+        Operation::Median => Ok(dataframe.median()),
+        Operation::Var => Ok(dataframe.var()),
+        Operation::Std => Ok(dataframe.std()),
+        operation => Err(ShellError::SpannedLabeledError(
+            "Error calculating aggregation".into(),
+            format!(
+                "{} is only available on a groupby object. Use mean, sum, min, max, quantile, median, var or std on a dataframe",
+                operation.to_str()
+            ),
+            operation_span,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(AggregateDF {})
+    }
+}
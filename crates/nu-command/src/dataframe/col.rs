@@ -0,0 +1,60 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape,
+};
+use polars::prelude::col;
+
+use super::values::NuExpression;
+
+#[derive(Clone)]
+pub struct ExprCol;
+
+impl Command for ExprCol {
+    fn name(&self) -> &str {
+        "dataframe col"
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a column expression, used to filter lazy dataframes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("column", SyntaxShape::String, "column name")
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Creates an expression comparing column a with 2",
+            example: "(dataframe col a) > 2",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let column: String = call.req(engine_state, stack, 0)?;
+        let expr = NuExpression::new(col(&column));
+
+        Ok(PipelineData::Value(expr.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(ExprCol {})
+    }
+}
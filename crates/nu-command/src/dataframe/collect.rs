@@ -0,0 +1,64 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span,
+};
+
+use super::values::{Column, NuDataFrame, NuLazyFrame};
+
+#[derive(Clone)]
+pub struct CollectDF;
+
+impl Command for CollectDF {
+    fn name(&self) -> &str {
+        "dataframe collect"
+    }
+
+    fn usage(&self) -> &str {
+        "Runs the query plan of a lazy dataframe, creating a dataframe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Collects a lazy dataframe",
+            example:
+                "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-lazy | dataframe collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                    Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let lazy = NuLazyFrame::try_from_pipeline(input, call.head)?;
+        let df = lazy.collect(call.head)?;
+
+        Ok(PipelineData::Value(df.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(CollectDF {})
+    }
+}
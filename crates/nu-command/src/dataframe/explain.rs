@@ -0,0 +1,69 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Value,
+};
+
+use super::values::NuLazyFrame;
+
+#[derive(Clone)]
+pub struct ExplainDF;
+
+impl Command for ExplainDF {
+    fn name(&self) -> &str {
+        "dataframe explain"
+    }
+
+    fn usage(&self) -> &str {
+        "Shows the optimized query plan of a lazy dataframe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Shows how a filtered lazy dataframe is read",
+            example: "dataframe open test.csv --lazy | dataframe filter ((dataframe col a) > 2) | dataframe explain",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let lazy = NuLazyFrame::try_from_pipeline(input, call.head)?;
+
+        let plan = lazy.as_ref().describe_optimized_plan().map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error optimizing query plan".into(),
+                e.to_string(),
+                call.head,
+            )
+        })?;
+
+        Ok(PipelineData::Value(
+            Value::String {
+                val: plan,
+                span: call.head,
+            },
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(ExplainDF {})
+    }
+}
@@ -0,0 +1,106 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+use super::values::{Column, NuDataFrame, NuExpression, NuLazyFrame};
+
+#[derive(Clone)]
+pub struct FilterDF;
+
+impl Command for FilterDF {
+    fn name(&self) -> &str {
+        "dataframe filter"
+    }
+
+    fn usage(&self) -> &str {
+        "Filters the rows of a dataframe with an expression"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "expression",
+                SyntaxShape::Any,
+                "expression the rows have to match",
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keeps the rows where column a is greater than 2",
+                example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe filter ((dataframe col a) > 2)",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![3.into()]),
+                        Column::new("b".to_string(), vec![4.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "Filters a lazy dataframe, the rows are only filtered once it is collected",
+                example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-lazy | dataframe filter ((dataframe col b) == 2) | dataframe collect",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![1.into()]),
+                        Column::new("b".to_string(), vec![2.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let expr: Value = call.req(engine_state, stack, 0)?;
+    let expr = NuExpression::try_from_value(expr)?;
+
+    let value = input.into_value(call.head);
+    let is_lazy = NuLazyFrame::can_downcast(&value);
+
+    let lazy = NuLazyFrame::try_from_lazy_or_eager(value)?;
+    let lazy = NuLazyFrame::new(lazy.into_polars().filter(expr.into_polars()));
+
+    // A dataframe is filtered right away, so it stays a dataframe
+    let value = if is_lazy {
+        lazy.into_value(call.head)
+    } else {
+        lazy.collect(call.head)?.into_value(call.head)
+    };
+
+    Ok(PipelineData::Value(value, None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(FilterDF {})
+    }
+}
@@ -0,0 +1,99 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+
+use super::values::{utils::convert_columns, NuDataFrame, NuGroupBy, NuLazyFrame, NuLazyGroupBy};
+
+#[derive(Clone)]
+pub struct GroupByDF;
+
+impl Command for GroupByDF {
+    fn name(&self) -> &str {
+        "dataframe group-by"
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a groupby object that can be used for other aggregations"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .rest("rest", SyntaxShape::Any, "groupby columns")
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Grouping by column a",
+                example: "[[a b]; [one 1] [one 2]] | dataframe to-df | dataframe group-by a",
+                result: None,
+            },
+            Example {
+                description: "Grouping a lazy dataframe by column a",
+                example:
+                    "[[a b]; [one 1] [one 2]] | dataframe to-df | dataframe to-lazy | dataframe group-by a",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+    let (col_string, col_span) = convert_columns(columns, call.head)?;
+    let col_string = col_string
+        .into_iter()
+        .map(|col| col.item)
+        .collect::<Vec<String>>();
+
+    let value = input.into_value(call.head);
+
+    // The groups of a lazy frame are only computed once its aggregation is collected
+    if NuLazyFrame::can_downcast(&value) {
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let groupby = NuLazyGroupBy::new(lazy, col_string);
+
+        return Ok(PipelineData::Value(groupby.into_value(call.head), None));
+    }
+
+    let df = NuDataFrame::try_from_value(value)?;
+
+    let groupby = df.as_ref().groupby(&col_string).map_err(|e| {
+        ShellError::SpannedLabeledError("Error creating groupby".into(), e.to_string(), col_span)
+    })?;
+
+    let groups = groupby.get_groups().to_vec();
+    let groupby = NuGroupBy::new(df.as_ref().clone(), col_string, groups);
+
+    Ok(PipelineData::Value(groupby.into_value(call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(GroupByDF {})
+    }
+}
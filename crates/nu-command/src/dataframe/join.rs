@@ -0,0 +1,398 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+use polars::prelude::{col, DataFrame, Expr, JoinType, PolarsError, Series};
+
+use super::values::{utils::convert_columns, Column, NuDataFrame, NuLazyFrame};
+
+// The suffix polars gives to the clashing columns of the right dataframe
+const DEFAULT_SUFFIX: &str = "_right";
+
+#[derive(Clone)]
+pub struct JoinDF;
+
+impl Command for JoinDF {
+    fn name(&self) -> &str {
+        "dataframe join"
+    }
+
+    fn usage(&self) -> &str {
+        "Joins a dataframe using columns as reference"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("dataframe", SyntaxShape::Any, "right dataframe to join")
+            .required_named(
+                "left-on",
+                SyntaxShape::Table,
+                "left column names to perform join",
+                Some('l'),
+            )
+            .required_named(
+                "right-on",
+                SyntaxShape::Table,
+                "right column names to perform join",
+                Some('r'),
+            )
+            .switch(
+                "left",
+                "left join, keeping all the rows of the left dataframe",
+                None,
+            )
+            .switch(
+                "right",
+                "right join, keeping all the rows of the right dataframe",
+                None,
+            )
+            .switch(
+                "outer",
+                "outer join, keeping all the rows of both dataframes",
+                None,
+            )
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "suffix for the columns of the right dataframe",
+                Some('s'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "inner join dataframe",
+                example: r#"let right = ([[a b c]; [1 2 5] [3 4 5] [5 6 6]] | dataframe to-df); $right | dataframe join $right -l [a b] -r [a b]"#,
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![1.into(), 3.into(), 5.into()]),
+                        Column::new("b".to_string(), vec![2.into(), 4.into(), 6.into()]),
+                        Column::new("c".to_string(), vec![5.into(), 5.into(), 6.into()]),
+                        Column::new("c_right".to_string(), vec![5.into(), 5.into(), 6.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "left join keeping the rows without a match",
+                example: r#"let right = ([[a d]; [1 x]] | dataframe to-df); [[a b]; [1 2] [3 4]] | dataframe to-df | dataframe join $right --left -l [a] -r [a]"#,
+                result: None,
+            },
+            Example {
+                description: "right join, adding the suffix to the clashing right columns",
+                example: r#"let right = ([[k b]; [1 7] [3 8]] | dataframe to-df); [[a b]; [1 2] [3 4] [5 6]] | dataframe to-df | dataframe join $right --right -l [a] -r [k] -s _r"#,
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                        Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                        Column::new("b_r".to_string(), vec![7.into(), 8.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "join a lazy dataframe, the rows are only joined once it is collected",
+                example: r#"let right = ([[a c]; [1 5] [3 6]] | dataframe to-df); [[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-lazy | dataframe join $right -l [a] -r [a] | dataframe collect"#,
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                        Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                        Column::new("c".to_string(), vec![5.into(), 6.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let r_df: Value = call.req(engine_state, stack, 0)?;
+    let l_col = column_names(call.get_flag(engine_state, stack, "left-on")?, call.head)?;
+    let r_col = column_names(call.get_flag(engine_state, stack, "right-on")?, call.head)?;
+    let suffix: Option<String> = call.get_flag(engine_state, stack, "suffix")?;
+
+    let how = join_type(call)?;
+
+    let value = input.into_value(call.head);
+    if NuLazyFrame::can_downcast(&value) || NuLazyFrame::can_downcast(&r_df) {
+        if suffix.is_some() {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "--suffix can't be used to join lazy dataframes".into(),
+                call.head,
+            ));
+        }
+
+        return lazy_join(value, r_df, &l_col, &r_col, how, call.head);
+    }
+
+    let df = NuDataFrame::try_from_value(value)?;
+    let r_df = NuDataFrame::try_from_value(r_df)?;
+
+    check_column_datatypes(df.as_ref(), r_df.as_ref(), &l_col, &r_col, call.head)?;
+
+    let l_names: Vec<String> = l_col.iter().map(|col| col.item.clone()).collect();
+    let r_names: Vec<String> = r_col.iter().map(|col| col.item.clone()).collect();
+
+    let res = match how {
+        None => right_join(df.as_ref(), r_df.as_ref(), &l_names, &r_names, suffix),
+        Some(how) => df
+            .as_ref()
+            .join(r_df.as_ref(), &l_names, &r_names, how, suffix),
+    }
+    .map_err(|e| {
+        ShellError::SpannedLabeledError("Error joining dataframes".into(), e.to_string(), call.head)
+    })?;
+
+    Ok(PipelineData::Value(
+        NuDataFrame::dataframe_into_value(res, call.head),
+        None,
+    ))
+}
+
+// A dataframe joined with a lazy one becomes lazy too. The columns are only checked once the
+// join is collected
+fn lazy_join(
+    value: Value,
+    r_value: Value,
+    l_col: &[Spanned<String>],
+    r_col: &[Spanned<String>],
+    how: Option<JoinType>,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    if l_col.len() != r_col.len() {
+        return Err(ShellError::IncompatibleParametersSingle(
+            "the left and right dataframes need the same number of join columns".into(),
+            span,
+        ));
+    }
+
+    let lazy = NuLazyFrame::try_from_lazy_or_eager(value)?;
+    let r_lazy = NuLazyFrame::try_from_lazy_or_eager(r_value)?;
+
+    let l_names: Vec<String> = l_col.iter().map(|col| col.item.clone()).collect();
+    let r_names: Vec<String> = r_col.iter().map(|col| col.item.clone()).collect();
+
+    let l_exprs: Vec<Expr> = l_names.iter().map(|name| col(name)).collect();
+    let r_exprs: Vec<Expr> = r_names.iter().map(|name| col(name)).collect();
+
+    let res = match how {
+        None => {
+            let (renames, order) = right_join_columns(
+                &lazy.columns(),
+                &r_lazy.columns(),
+                &l_names,
+                &r_names,
+                DEFAULT_SUFFIX,
+            );
+
+            let renamed: Vec<Expr> = renames
+                .iter()
+                .map(|(name, new_name)| col(name).alias(new_name))
+                .collect();
+            let order: Vec<Expr> = order.iter().map(|name| col(name)).collect();
+
+            r_lazy
+                .into_polars()
+                .select(renamed)
+                .join(lazy.into_polars(), l_exprs.clone(), l_exprs, JoinType::Left)
+                .select(order)
+        }
+        Some(how) => lazy
+            .into_polars()
+            .join(r_lazy.into_polars(), l_exprs, r_exprs, how),
+    };
+
+    Ok(PipelineData::Value(
+        NuLazyFrame::from(res).into_value(span),
+        None,
+    ))
+}
+
+// Polars has no right join, so it is a left join with the dataframes swapped. The columns are
+// then put back in the order of the other joins: the left columns first, followed by the right
+// ones that aren't used in the join
+fn right_join(
+    df: &DataFrame,
+    r_df: &DataFrame,
+    l_names: &[String],
+    r_names: &[String],
+    suffix: Option<String>,
+) -> Result<DataFrame, PolarsError> {
+    let (renames, order) = right_join_columns(
+        &column_names_of(df),
+        &column_names_of(r_df),
+        l_names,
+        r_names,
+        suffix.as_deref().unwrap_or(DEFAULT_SUFFIX),
+    );
+
+    let renamed = r_df
+        .get_columns()
+        .iter()
+        .zip(renames.iter())
+        .map(|(series, (_, new_name))| {
+            let mut series = series.clone();
+            series.rename(new_name);
+            series
+        })
+        .collect::<Vec<Series>>();
+
+    DataFrame::new(renamed)?
+        .join(df, l_names, l_names, JoinType::Left, None)?
+        .select(&order)
+}
+
+// The new name of every column of the right dataframe and the column order of the joined frame.
+// The join columns take the names of the left ones, and the right columns clashing with a left
+// column get the suffix, as the swapped join would otherwise add it to the left ones
+fn right_join_columns(
+    l_columns: &[String],
+    r_columns: &[String],
+    l_names: &[String],
+    r_names: &[String],
+    suffix: &str,
+) -> (Vec<(String, String)>, Vec<String>) {
+    let renames: Vec<(String, String)> = r_columns
+        .iter()
+        .map(|name| {
+            let new_name = match r_names.iter().position(|r_name| r_name == name) {
+                Some(index) => l_names[index].clone(),
+                None if l_columns.contains(name) => format!("{}{}", name, suffix),
+                None => name.clone(),
+            };
+
+            (name.clone(), new_name)
+        })
+        .collect();
+
+    let order = l_columns
+        .iter()
+        .cloned()
+        .chain(
+            renames
+                .iter()
+                .filter(|(name, _)| !r_names.contains(name))
+                .map(|(_, new_name)| new_name.clone()),
+        )
+        .collect();
+
+    (renames, order)
+}
+
+fn column_names_of(df: &DataFrame) -> Vec<String> {
+    df.get_column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+// The join asked for with the switches, None being a right join
+fn join_type(call: &Call) -> Result<Option<JoinType>, ShellError> {
+    match (
+        call.has_flag("left"),
+        call.has_flag("right"),
+        call.has_flag("outer"),
+    ) {
+        (false, false, false) => Ok(Some(JoinType::Inner)),
+        (true, false, false) => Ok(Some(JoinType::Left)),
+        (false, true, false) => Ok(None),
+        (false, false, true) => Ok(Some(JoinType::Outer)),
+        _ => Err(ShellError::IncompatibleParametersSingle(
+            "only one of --left, --right and --outer can be used".into(),
+            call.head,
+        )),
+    }
+}
+
+fn column_names(columns: Option<Value>, span: Span) -> Result<Vec<Spanned<String>>, ShellError> {
+    match columns {
+        Some(Value::List { vals, .. }) => convert_columns(vals, span).map(|(cols, _)| cols),
+        Some(value) => Err(ShellError::SpannedLabeledError(
+            "Incorrect column format".into(),
+            "expected a list of column names".into(),
+            value.span()?,
+        )),
+        None => Err(ShellError::MissingParameter(
+            "list of column names".into(),
+            span,
+        )),
+    }
+}
+
+fn check_column_datatypes(
+    df_l: &DataFrame,
+    df_r: &DataFrame,
+    l_cols: &[Spanned<String>],
+    r_cols: &[Spanned<String>],
+    span: Span,
+) -> Result<(), ShellError> {
+    if l_cols.len() != r_cols.len() {
+        return Err(ShellError::IncompatibleParametersSingle(
+            "the left and right dataframes need the same number of join columns".into(),
+            span,
+        ));
+    }
+
+    for (l, r) in l_cols.iter().zip(r_cols) {
+        let l_series = df_l.column(&l.item).map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error selecting the columns".into(),
+                e.to_string(),
+                l.span,
+            )
+        })?;
+
+        let r_series = df_r.column(&r.item).map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error selecting the columns".into(),
+                e.to_string(),
+                r.span,
+            )
+        })?;
+
+        if l_series.dtype() != r_series.dtype() {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: format!("datatype {}", l_series.dtype()),
+                left_span: l.span,
+                right_message: format!("datatype {}", r_series.dtype()),
+                right_span: r.span,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(JoinDF {})
+    }
+}
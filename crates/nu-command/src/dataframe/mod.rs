@@ -1,23 +1,59 @@
 mod series;
 mod values;
 
+mod aggregate;
 mod append;
+mod col;
+mod collect;
 mod column;
 mod command;
 mod describe;
 mod drop;
 mod dtypes;
+mod explain;
+mod filter;
+mod filter_with;
+mod group_by;
+mod join;
 mod open;
+mod pivot;
+mod select;
+mod sort;
+mod to_arrow;
+mod to_csv;
 mod to_df;
+mod to_json;
+mod to_lazy;
+mod to_parquet;
+mod with_column;
 
+pub use aggregate::AggregateDF;
 pub use append::AppendDF;
+pub use col::ExprCol;
+pub use collect::CollectDF;
 pub use column::ColumnDF;
 pub use command::Dataframe;
 pub use describe::DescribeDF;
 pub use drop::DropDF;
 pub use dtypes::DataTypes;
+pub use explain::ExplainDF;
+pub use filter::FilterDF;
+pub use filter_with::FilterWith;
+pub use group_by::GroupByDF;
+pub use join::JoinDF;
 pub use open::OpenDataFrame;
+pub use pivot::PivotDF;
+pub use select::SelectDF;
+pub use sort::SortDF;
+pub use to_arrow::ToArrow;
+pub use to_csv::ToCSV;
 pub use to_df::ToDataFrame;
+pub use to_json::ToJson;
+pub use to_lazy::ToLazyFrame;
+pub use to_parquet::ToParquet;
+pub use with_column::WithColumn;
+
+pub use series::*;
 
 use nu_protocol::engine::StateWorkingSet;
 
@@ -31,15 +67,52 @@ pub fn add_dataframe_decls(working_set: &mut StateWorkingSet) {
             };
         }
 
+    // Dataframe commands
     bind_command!(
+        AggregateDF,
         AppendDF,
+        CollectDF,
         ColumnDF,
         Dataframe,
         DataTypes,
         DescribeDF,
         DropDF,
+        ExplainDF,
+        ExprCol,
+        FilterDF,
+        FilterWith,
+        GroupByDF,
+        JoinDF,
         OpenDataFrame,
-        ToDataFrame
+        PivotDF,
+        SelectDF,
+        SortDF,
+        ToArrow,
+        ToCSV,
+        ToDataFrame,
+        ToJson,
+        ToLazyFrame,
+        ToParquet,
+        WithColumn
+    );
+
+    // Series commands
+    bind_command!(
+        AsDateTime,
+        Contains,
+        Cumulative,
+        FillNull,
+        GetDay,
+        GetMonth,
+        GetYear,
+        IsNull,
+        Replace,
+        Rolling,
+        Shift,
+        StrLengths,
+        ToLowerCase,
+        Unique,
+        ValueCount
     );
 }
 
@@ -1,13 +1,16 @@
-use super::values::NuDataFrame;
+use super::values::{NuDataFrame, NuLazyFrame};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
 };
 use std::{fs::File, path::PathBuf};
 
-use polars::prelude::{CsvEncoding, CsvReader, JsonReader, ParquetReader, SerReader};
+use polars::prelude::{
+    col, CsvEncoding, CsvReader, Expr, IpcReader, JsonReader, LazyCsvReader, LazyFrame,
+    ParquetReader, SerReader,
+};
 
 #[derive(Clone)]
 pub struct OpenDataFrame;
@@ -18,7 +21,7 @@ impl Command for OpenDataFrame {
     }
 
     fn usage(&self) -> &str {
-        "Opens csv, json or parquet file to create dataframe"
+        "Opens csv, json, parquet or arrow file to create dataframe"
     }
 
     fn signature(&self) -> Signature {
@@ -54,18 +57,30 @@ impl Command for OpenDataFrame {
             .named(
                 "columns",
                 SyntaxShape::List(Box::new(SyntaxShape::String)),
-                "Columns to be selected from csv file. CSV and Parquet file",
+                "Columns to be selected from csv file. CSV, Parquet and Arrow file",
                 None,
             )
+            .switch(
+                "lazy",
+                "Creates a lazy dataframe, read once it is collected. CSV and Parquet file",
+                Some('l'),
+            )
             .category(Category::Custom("dataframe".into()))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Takes a file name and creates a dataframe",
-            example: "dataframe open test.csv",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Takes a file name and creates a dataframe",
+                example: "dataframe open test.csv",
+                result: None,
+            },
+            Example {
+                description: "Creates a lazy dataframe that reads the file when it is collected",
+                example: "dataframe open test.csv --lazy",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -84,7 +99,6 @@ fn command(
     stack: &mut Stack,
     call: &Call,
 ) -> Result<PipelineData, ShellError> {
-    let span = call.head;
     let file: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
 
     match file.item.extension() {
@@ -92,8 +106,9 @@ fn command(
             Some("csv") => from_csv(engine_state, stack, call),
             Some("parquet") => from_parquet(engine_state, stack, call),
             Some("json") => from_json(engine_state, stack, call),
+            Some("arrow") | Some("ipc") => from_ipc(engine_state, stack, call),
             _ => Err(ShellError::FileNotFoundCustom(
-                "Not a csv, parquet or json file".into(),
+                "Not a csv, parquet, json or arrow file".into(),
                 file.span,
             )),
         },
@@ -102,17 +117,44 @@ fn command(
             file.span,
         )),
     }
-    .map(|df| PipelineData::Value(NuDataFrame::dataframe_into_value(df, span), None))
+    .map(|value| PipelineData::Value(value, None))
+}
+
+// The lazy readers can't be limited to some columns, so the columns are selected in the plan
+fn select_columns(lazy: LazyFrame, columns: Option<Vec<String>>) -> LazyFrame {
+    match columns {
+        None => lazy,
+        Some(columns) => lazy.select(
+            columns
+                .iter()
+                .map(|column| col(column))
+                .collect::<Vec<Expr>>(),
+        ),
+    }
 }
 
 fn from_parquet(
     engine_state: &EngineState,
     stack: &mut Stack,
     call: &Call,
-) -> Result<polars::prelude::DataFrame, ShellError> {
+) -> Result<Value, ShellError> {
     let file: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
     let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
 
+    if call.has_flag("lazy") {
+        let lazy = LazyFrame::scan_parquet(file.item.to_string_lossy().into(), None, true)
+            .map_err(|e| {
+                ShellError::SpannedLabeledError(
+                    "Parquet reader error".into(),
+                    format!("{:?}", e),
+                    call.head,
+                )
+            })?;
+
+        let lazy = NuLazyFrame::new(select_columns(lazy, columns));
+        return Ok(lazy.into_value(call.head));
+    }
+
     let r = File::open(&file.item).map_err(|e| {
         ShellError::SpannedLabeledError("Error opening file".into(), e.to_string(), file.span)
     })?;
@@ -123,38 +165,94 @@ fn from_parquet(
         Some(columns) => reader.with_columns(Some(columns)),
     };
 
-    reader.finish().map_err(|e| {
-        ShellError::SpannedLabeledError(
-            "Parquet reader error".into(),
-            format!("{:?}", e),
-            call.head,
-        )
-    })
+    reader
+        .finish()
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Parquet reader error".into(),
+                format!("{:?}", e),
+                call.head,
+            )
+        })
+        .map(|df| NuDataFrame::dataframe_into_value(df, call.head))
+}
+
+fn from_ipc(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Value, ShellError> {
+    let file: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
+    let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
+
+    if call.has_flag("lazy") {
+        return Err(ShellError::SpannedLabeledError(
+            "Arrow file can't be opened lazily".into(),
+            "Lazy dataframes can be created from csv or parquet files".into(),
+            file.span,
+        ));
+    }
+
+    let r = File::open(&file.item).map_err(|e| {
+        ShellError::SpannedLabeledError("Error opening file".into(), e.to_string(), file.span)
+    })?;
+    let reader = IpcReader::new(r);
+
+    let reader = match columns {
+        None => reader,
+        Some(columns) => reader.with_columns(Some(columns)),
+    };
+
+    reader
+        .finish()
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "IPC reader error".into(),
+                format!("{:?}", e),
+                call.head,
+            )
+        })
+        .map(|df| NuDataFrame::dataframe_into_value(df, call.head))
 }
 
 fn from_json(
     engine_state: &EngineState,
     stack: &mut Stack,
     call: &Call,
-) -> Result<polars::prelude::DataFrame, ShellError> {
+) -> Result<Value, ShellError> {
     let file: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
 
+    if call.has_flag("lazy") {
+        return Err(ShellError::SpannedLabeledError(
+            "Json file can't be opened lazily".into(),
+            "Lazy dataframes can be created from csv or parquet files".into(),
+            file.span,
+        ));
+    }
+
     let r = File::open(&file.item).map_err(|e| {
         ShellError::SpannedLabeledError("Error opening file".into(), e.to_string(), file.span)
     })?;
 
     let reader = JsonReader::new(r);
 
-    reader.finish().map_err(|e| {
-        ShellError::SpannedLabeledError("Json reader error".into(), format!("{:?}", e), call.head)
-    })
+    reader
+        .finish()
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Json reader error".into(),
+                format!("{:?}", e),
+                call.head,
+            )
+        })
+        .map(|df| NuDataFrame::dataframe_into_value(df, call.head))
 }
 
 fn from_csv(
     engine_state: &EngineState,
     stack: &mut Stack,
     call: &Call,
-) -> Result<polars::prelude::DataFrame, ShellError> {
+) -> Result<Value, ShellError> {
     let file: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
     let delimiter: Option<Spanned<String>> = call.get_flag(engine_state, stack, "delimiter")?;
     let no_header: bool = call.has_flag("no_header");
@@ -162,6 +260,56 @@ fn from_csv(
     let skip_rows: Option<usize> = call.get_flag(engine_state, stack, "skip_rows")?;
     let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
 
+    let delimiter = match delimiter {
+        None => None,
+        Some(d) => {
+            if d.item.len() != 1 {
+                return Err(ShellError::SpannedLabeledError(
+                    "Incorrect delimiter".into(),
+                    "Delimiter has to be one character".into(),
+                    d.span,
+                ));
+            } else {
+                match d.item.chars().next() {
+                    Some(d) => Some(d as u8),
+                    None => unreachable!(),
+                }
+            }
+        }
+    };
+
+    if call.has_flag("lazy") {
+        let csv_reader = LazyCsvReader::new(file.item.to_string_lossy().into());
+
+        let csv_reader = match delimiter {
+            None => csv_reader,
+            Some(delimiter) => csv_reader.with_delimiter(delimiter),
+        };
+
+        let csv_reader = csv_reader.has_header(!no_header);
+
+        let csv_reader = match infer_schema {
+            None => csv_reader,
+            Some(r) => csv_reader.with_infer_schema_length(Some(r)),
+        };
+
+        let csv_reader = match skip_rows {
+            None => csv_reader,
+            Some(r) => csv_reader.with_skip_rows(r),
+        };
+
+        let lazy = csv_reader.finish().map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "CSV reader error".into(),
+                format!("{:?}", e),
+                call.head,
+            )
+        })?;
+
+        let lazy = NuLazyFrame::new(select_columns(lazy, columns));
+        return Ok(lazy.into_value(call.head));
+    }
+
     let csv_reader = CsvReader::from_path(&file.item)
         .map_err(|e| {
             ShellError::SpannedLabeledError(
@@ -174,21 +322,7 @@ fn from_csv(
 
     let csv_reader = match delimiter {
         None => csv_reader,
-        Some(d) => {
-            if d.item.len() != 1 {
-                return Err(ShellError::SpannedLabeledError(
-                    "Incorrect delimiter".into(),
-                    "Delimiter has to be one character".into(),
-                    d.span,
-                ));
-            } else {
-                let delimiter = match d.item.chars().next() {
-                    Some(d) => d as u8,
-                    None => unreachable!(),
-                };
-                csv_reader.with_delimiter(delimiter)
-            }
-        }
+        Some(delimiter) => csv_reader.with_delimiter(delimiter),
     };
 
     let csv_reader = csv_reader.has_header(!no_header);
@@ -208,11 +342,14 @@ fn from_csv(
         Some(columns) => csv_reader.with_columns(Some(columns)),
     };
 
-    csv_reader.finish().map_err(|e| {
-        ShellError::SpannedLabeledError(
-            "Parquet reader error".into(),
-            format!("{:?}", e),
-            call.head,
-        )
-    })
+    csv_reader
+        .finish()
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Parquet reader error".into(),
+                format!("{:?}", e),
+                call.head,
+            )
+        })
+        .map(|df| NuDataFrame::dataframe_into_value(df, call.head))
 }
@@ -0,0 +1,119 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+use polars::prelude::{col, Expr};
+
+use super::values::utils::convert_columns;
+use super::values::{Column, NuDataFrame, NuLazyFrame};
+
+#[derive(Clone)]
+pub struct SelectDF;
+
+impl Command for SelectDF {
+    fn name(&self) -> &str {
+        "dataframe select"
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a new dataframe with the selected columns"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .rest("rest", SyntaxShape::Any, "column names to be selected")
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Selects column a",
+                example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe select a",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "a".to_string(),
+                        vec![1.into(), 3.into()],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "Selects column b of a lazy dataframe",
+                example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-lazy | dataframe select b | dataframe collect",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "b".to_string(),
+                        vec![2.into(), 4.into()],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+    let (col_string, col_span) = convert_columns(columns, call.head)?;
+    let col_string = col_string
+        .into_iter()
+        .map(|col| col.item)
+        .collect::<Vec<String>>();
+
+    let value = input.into_value(call.head);
+
+    if NuLazyFrame::can_downcast(&value) {
+        let lazy = NuLazyFrame::try_from_value(value)?;
+        let exprs = col_string
+            .iter()
+            .map(|name| col(name))
+            .collect::<Vec<Expr>>();
+
+        let lazy = NuLazyFrame::new(lazy.into_polars().select(exprs));
+        return Ok(PipelineData::Value(lazy.into_value(call.head), None));
+    }
+
+    let df = NuDataFrame::try_from_value(value)?;
+
+    df.as_ref()
+        .select(&col_string)
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error selecting columns".into(),
+                e.to_string(),
+                col_span,
+            )
+        })
+        .map(|df| PipelineData::Value(NuDataFrame::dataframe_into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(SelectDF {})
+    }
+}
@@ -0,0 +1,112 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+};
+
+use super::super::values::{Column, NuDataFrame};
+
+enum CumType {
+    Min,
+    Max,
+    Sum,
+}
+
+impl CumType {
+    fn from_tagged(cum_type: &Spanned<String>) -> Result<Self, ShellError> {
+        match cum_type.item.as_str() {
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "sum" => Ok(Self::Sum),
+            _ => Err(ShellError::SpannedLabeledError(
+                "Wrong operation".into(),
+                "Operation not valid for cumulative, expected one of min, max or sum".into(),
+                cum_type.span,
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Cumulative;
+
+impl Command for Cumulative {
+    fn name(&self) -> &str {
+        "dataframe cumulative"
+    }
+
+    fn usage(&self) -> &str {
+        "Cumulative calculation for a series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "type",
+                SyntaxShape::String,
+                "cumulative operation: sum, min or max",
+            )
+            .switch("reverse", "Reverse cumulative calculation", Some('r'))
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Cumulative sum for a series",
+            example: "[1 2 3 4 5] | dataframe to-df | dataframe cumulative sum",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "0".to_string(),
+                    vec![1.into(), 3.into(), 6.into(), 10.into(), 15.into()],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let cum_type: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let cum_type = CumType::from_tagged(&cum_type)?;
+    let reverse = call.has_flag("reverse");
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let res = match cum_type {
+        CumType::Max => series.cum_max(reverse),
+        CumType::Min => series.cum_min(reverse),
+        CumType::Sum => series.cum_sum(reverse),
+    };
+
+    NuDataFrame::try_from_series(vec![res], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(Cumulative {})
+    }
+}
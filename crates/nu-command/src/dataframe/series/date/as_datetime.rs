@@ -0,0 +1,92 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape,
+};
+use polars::prelude::IntoSeries;
+
+use super::super::super::values::NuDataFrame;
+
+#[derive(Clone)]
+pub struct AsDateTime;
+
+impl Command for AsDateTime {
+    fn name(&self) -> &str {
+        "dataframe as-datetime"
+    }
+
+    fn usage(&self) -> &str {
+        "Converts string to datetime, with the format of the strings"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "format",
+                SyntaxShape::String,
+                "formatting date time string, eg) %Y-%m-%d %H:%M:%S",
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Converts string to datetime",
+            example: r#"["2021-12-30 00:00:00" "2021-12-31 00:00:00"] | dataframe to-df | dataframe as-datetime "%Y-%m-%d %H:%M:%S""#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let format: String = call.req(engine_state, stack, 0)?;
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let chunked = series.utf8().map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error casting to string".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    let res = chunked.as_datetime(Some(format.as_str())).map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error creating datetime".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    NuDataFrame::try_from_series(vec![res.into_series()], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(AsDateTime {})
+    }
+}
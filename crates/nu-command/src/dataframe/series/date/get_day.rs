@@ -0,0 +1,84 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span,
+};
+use polars::prelude::IntoSeries;
+
+use super::super::super::values::{Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct GetDay;
+
+impl Command for GetDay {
+    fn name(&self) -> &str {
+        "dataframe get-day"
+    }
+
+    fn usage(&self) -> &str {
+        "Gets day from date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns day from a date",
+            example: r#"["2020-08-04 16:39:18" "2021-12-31 00:00:00"] | dataframe to-df | dataframe as-datetime "%Y-%m-%d %H:%M:%S" | dataframe get-day"#,
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "0".to_string(),
+                    vec![4.into(), 31.into()],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let chunked = series.datetime().map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error casting to datetime".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    let res = chunked.day();
+
+    NuDataFrame::try_from_series(vec![res.into_series()], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(GetDay {})
+    }
+}
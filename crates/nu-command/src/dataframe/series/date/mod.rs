@@ -0,0 +1,9 @@
+mod as_datetime;
+mod get_day;
+mod get_month;
+mod get_year;
+
+pub use as_datetime::AsDateTime;
+pub use get_day::GetDay;
+pub use get_month::GetMonth;
+pub use get_year::GetYear;
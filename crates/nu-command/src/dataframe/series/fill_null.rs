@@ -0,0 +1,118 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+use polars::prelude::{ChunkSet, IntoSeries};
+
+use super::super::values::{Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct FillNull;
+
+impl Command for FillNull {
+    fn name(&self) -> &str {
+        "dataframe fill-null"
+    }
+
+    fn usage(&self) -> &str {
+        "Replaces the null values of a series with a value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "fill",
+                SyntaxShape::Any,
+                "value to replace the null values with",
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Fills the null values with 0",
+            example: "[1 2 3] | dataframe to-df | dataframe shift 1 | dataframe fill-null 0",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "0".to_string(),
+                    vec![0.into(), 1.into(), 2.into()],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let fill: Value = call.req(engine_state, stack, 0)?;
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+    let mask = series.is_null();
+
+    // The fill value has to have the type of the series
+    let res = match &fill {
+        Value::Int { val, .. } => series
+            .i64()
+            .and_then(|chunked| chunked.set(&mask, Some(*val)))
+            .map(|chunked| chunked.into_series()),
+        Value::Float { val, .. } => series
+            .f64()
+            .and_then(|chunked| chunked.set(&mask, Some(*val)))
+            .map(|chunked| chunked.into_series()),
+        Value::String { val, .. } => series
+            .utf8()
+            .and_then(|chunked| chunked.set(&mask, Some(val.as_str())))
+            .map(|chunked| chunked.into_series()),
+        Value::Bool { val, .. } => series
+            .bool()
+            .and_then(|chunked| chunked.set(&mask, Some(*val)))
+            .map(|chunked| chunked.into_series()),
+        _ => {
+            return Err(ShellError::SpannedLabeledError(
+                "Incorrect fill value".into(),
+                "expected an int, float, string or bool to fill the series with".into(),
+                fill.span()?,
+            ))
+        }
+    }
+    .map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error filling null values".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    NuDataFrame::try_from_series(vec![res], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(FillNull {})
+    }
+}
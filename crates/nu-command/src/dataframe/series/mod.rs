@@ -1 +1,21 @@
+mod date;
+pub use date::*;
 
+mod string;
+pub use string::*;
+
+mod cumulative;
+mod fill_null;
+mod is_null;
+mod rolling;
+mod shift;
+mod unique;
+mod value_counts;
+
+pub use cumulative::Cumulative;
+pub use fill_null::FillNull;
+pub use is_null::IsNull;
+pub use rolling::Rolling;
+pub use shift::Shift;
+pub use unique::Unique;
+pub use value_counts::ValueCount;
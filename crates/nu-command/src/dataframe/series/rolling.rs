@@ -0,0 +1,146 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+};
+use polars::prelude::RollingOptions;
+
+use super::super::values::NuDataFrame;
+
+enum RollType {
+    Min,
+    Max,
+    Sum,
+    Mean,
+}
+
+impl RollType {
+    fn from_tagged(roll_type: &Spanned<String>) -> Result<Self, ShellError> {
+        match roll_type.item.as_str() {
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "sum" => Ok(Self::Sum),
+            "mean" => Ok(Self::Mean),
+            _ => Err(ShellError::SpannedLabeledError(
+                "Wrong operation".into(),
+                "Operation not valid for rolling, expected one of min, max, sum or mean".into(),
+                roll_type.span,
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rolling;
+
+impl Command for Rolling {
+    fn name(&self) -> &str {
+        "dataframe rolling"
+    }
+
+    fn usage(&self) -> &str {
+        "Rolling calculation for a series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "type",
+                SyntaxShape::String,
+                "rolling operation: sum, mean, min or max",
+            )
+            .required_named(
+                "window",
+                SyntaxShape::Int,
+                "Window size for rolling",
+                Some('w'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description:
+                    "Rolling sum for a series, the first row has no full window and is null",
+                example: "[1 2 3 4 5] | dataframe to-df | dataframe rolling sum --window 2",
+                result: None,
+            },
+            Example {
+                description: "Rolling mean for a series",
+                example: "[1 2 3 4 5] | dataframe to-df | dataframe rolling mean -w 2",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let roll_type: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let roll_type = RollType::from_tagged(&roll_type)?;
+    let window_size: Spanned<i64> = call
+        .get_flag(engine_state, stack, "window")?
+        .ok_or_else(|| ShellError::MissingParameter("window".into(), call.head))?;
+
+    if window_size.item < 1 {
+        return Err(ShellError::SpannedLabeledError(
+            "Incorrect window size".into(),
+            "the window has to be at least one row".into(),
+            window_size.span,
+        ));
+    }
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let rolling_opts = RollingOptions {
+        window_size: window_size.item as usize,
+        min_periods: window_size.item as usize,
+        weights: None,
+        center: false,
+    };
+
+    let res = match roll_type {
+        RollType::Max => series.rolling_max(rolling_opts),
+        RollType::Min => series.rolling_min(rolling_opts),
+        RollType::Sum => series.rolling_sum(rolling_opts),
+        RollType::Mean => series.rolling_mean(rolling_opts),
+    }
+    .map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error calculating rolling values".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    NuDataFrame::try_from_series(vec![res], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(Rolling {})
+    }
+}
@@ -0,0 +1,99 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+};
+use polars::prelude::IntoSeries;
+
+use super::super::super::values::{Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct Contains;
+
+impl Command for Contains {
+    fn name(&self) -> &str {
+        "dataframe contains"
+    }
+
+    fn usage(&self) -> &str {
+        "Checks if a pattern is contained in a string"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "Regex pattern to be searched",
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Returns boolean indicating if pattern was found",
+            example: "[abc acb acb] | dataframe to-df | dataframe contains ab",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "0".to_string(),
+                    vec![true.into(), false.into(), false.into()],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let pattern: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let chunked = series.utf8().map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error casting to string".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    let res = chunked.contains(&pattern.item).map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error searching in series".into(),
+            e.to_string(),
+            pattern.span,
+        )
+    })?;
+
+    NuDataFrame::try_from_series(vec![res.into_series()], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(Contains {})
+    }
+}
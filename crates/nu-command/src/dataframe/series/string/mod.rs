@@ -0,0 +1,9 @@
+mod contains;
+mod replace;
+mod str_lengths;
+mod to_lowercase;
+
+pub use contains::Contains;
+pub use replace::Replace;
+pub use str_lengths::StrLengths;
+pub use to_lowercase::ToLowerCase;
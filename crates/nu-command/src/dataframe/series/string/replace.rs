@@ -0,0 +1,111 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+};
+use polars::prelude::IntoSeries;
+
+use super::super::super::values::{Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct Replace;
+
+impl Command for Replace {
+    fn name(&self) -> &str {
+        "dataframe replace"
+    }
+
+    fn usage(&self) -> &str {
+        "Replace the leftmost (sub)string by a regex pattern"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "pattern",
+                SyntaxShape::String,
+                "Regex pattern to be matched",
+                Some('p'),
+            )
+            .required_named(
+                "replace",
+                SyntaxShape::String,
+                "replacing string",
+                Some('r'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Replaces string",
+            example: "[abc abc abc] | dataframe to-df | dataframe replace -p ab -r AB",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![Column::new(
+                    "0".to_string(),
+                    vec!["ABc".to_string().into(), "ABc".to_string().into(), "ABc".to_string().into()],
+                )])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let pattern: Spanned<String> = call
+        .get_flag(engine_state, stack, "pattern")?
+        .ok_or_else(|| ShellError::MissingParameter("pattern".into(), call.head))?;
+    let replace: String = call
+        .get_flag(engine_state, stack, "replace")?
+        .ok_or_else(|| ShellError::MissingParameter("replace".into(), call.head))?;
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+    let series = df.as_series(call.head)?;
+
+    let chunked = series.utf8().map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error casting to string".into(),
+            e.to_string(),
+            call.head,
+        )
+    })?;
+
+    let res = chunked.replace(&pattern.item, &replace).map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error replacing in series".into(),
+            e.to_string(),
+            pattern.span,
+        )
+    })?;
+
+    NuDataFrame::try_from_series(vec![res.into_series()], call.head)
+        .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(Replace {})
+    }
+}
@@ -0,0 +1,116 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+use super::values::{utils::convert_columns, Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct SortDF;
+
+impl Command for SortDF {
+    fn name(&self) -> &str {
+        "dataframe sort"
+    }
+
+    fn usage(&self) -> &str {
+        "Creates new sorted dataframe or series"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .switch("reverse", "invert sort", Some('r'))
+            .rest("rest", SyntaxShape::Any, "column names to sort dataframe")
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create new sorted dataframe",
+                example: "[[a b]; [3 4] [1 2]] | dataframe to-df | dataframe sort a",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![
+                        Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                        Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                    ])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+            Example {
+                description: "Create new sorted series",
+                example: "[3 1 2] | dataframe to-df | dataframe sort",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "0".to_string(),
+                        vec![1.into(), 2.into(), 3.into()],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::unknown()),
+                ),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let reverse = call.has_flag("reverse");
+    let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+
+    let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+
+    // A series can be sorted without naming its only column
+    if df.is_series() && columns.is_empty() {
+        let series = df.as_series(call.head)?.sort(reverse);
+
+        return NuDataFrame::try_from_series(vec![series], call.head)
+            .map(|df| PipelineData::Value(df.into_value(call.head), None));
+    }
+
+    let (col_string, col_span) = convert_columns(columns, call.head)?;
+    let col_string = col_string
+        .into_iter()
+        .map(|col| col.item)
+        .collect::<Vec<String>>();
+    let reverse = vec![reverse; col_string.len()];
+
+    df.as_ref()
+        .sort(&col_string, reverse)
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error sorting dataframe".into(),
+                e.to_string(),
+                col_span,
+            )
+        })
+        .map(|df| PipelineData::Value(NuDataFrame::dataframe_into_value(df, call.head), None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(SortDF {})
+    }
+}
@@ -5,7 +5,7 @@ use nu_protocol::{
     PipelineData, Span, Value, CONFIG_VARIABLE_ID,
 };
 
-use super::ToDataFrame;
+use super::{AsDateTime, CollectDF, ExprCol, GroupByDF, Shift, ToDataFrame, ToLazyFrame};
 use crate::Let;
 
 pub fn test_dataframe(cmd: impl Command + 'static) {
@@ -18,6 +18,12 @@ pub fn test_dataframe(cmd: impl Command + 'static) {
         let mut working_set = StateWorkingSet::new(&*engine_state);
         working_set.add_decl(Box::new(Let));
         working_set.add_decl(Box::new(ToDataFrame));
+        working_set.add_decl(Box::new(GroupByDF));
+        working_set.add_decl(Box::new(ToLazyFrame));
+        working_set.add_decl(Box::new(CollectDF));
+        working_set.add_decl(Box::new(ExprCol));
+        working_set.add_decl(Box::new(AsDateTime));
+        working_set.add_decl(Box::new(Shift));
 
         // Adding the command that is being tested to the working set
         working_set.add_decl(Box::new(cmd));
@@ -0,0 +1,104 @@
+use std::{fs::File, path::PathBuf};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+use polars::prelude::{JsonFormat, JsonWriter, SerWriter};
+
+use super::values::NuDataFrame;
+
+#[derive(Clone)]
+pub struct ToJson;
+
+impl Command for ToJson {
+    fn name(&self) -> &str {
+        "dataframe to-json"
+    }
+
+    fn usage(&self) -> &str {
+        "Saves dataframe to json file"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("file", SyntaxShape::Filepath, "file path to save dataframe")
+            .switch(
+                "lines",
+                "writes a json object per row instead of a json array",
+                Some('l'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Saves dataframe to json file",
+                example: "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-json test.json",
+                result: None,
+            },
+            Example {
+                description: "Saves dataframe to json file with a line per row",
+                example:
+                    "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-json test.jsonl --lines",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let file_name: Spanned<PathBuf> = call.req(engine_state, stack, 0)?;
+    let format = if call.has_flag("lines") {
+        JsonFormat::JsonLines
+    } else {
+        JsonFormat::Json
+    };
+
+    let mut df = NuDataFrame::try_from_pipeline(input, call.head)?;
+
+    let mut file = File::create(&file_name.item).map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Error with file name".into(),
+            e.to_string(),
+            file_name.span,
+        )
+    })?;
+
+    JsonWriter::new(&mut file)
+        .with_json_format(format)
+        .finish(df.as_mut())
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error saving file".into(),
+                e.to_string(),
+                file_name.span,
+            )
+        })?;
+
+    Ok(PipelineData::Value(
+        Value::String {
+            val: format!("saved {:?}", &file_name.item),
+            span: call.head,
+        },
+        None,
+    ))
+}
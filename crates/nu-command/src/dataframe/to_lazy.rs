@@ -0,0 +1,64 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span,
+};
+
+use super::values::{Column, NuDataFrame, NuLazyFrame};
+
+#[derive(Clone)]
+pub struct ToLazyFrame;
+
+impl Command for ToLazyFrame {
+    fn name(&self) -> &str {
+        "dataframe to-lazy"
+    }
+
+    fn usage(&self) -> &str {
+        "Converts a dataframe into a lazy dataframe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Takes a dataframe and makes it lazy",
+            example:
+                "[[a b]; [1 2] [3 4]] | dataframe to-df | dataframe to-lazy | dataframe collect",
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                    Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let df = NuDataFrame::try_from_pipeline(input, call.head)?;
+        let lazy = NuLazyFrame::from_dataframe(df);
+
+        Ok(PipelineData::Value(lazy.into_value(call.head), None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(ToLazyFrame {})
+    }
+}
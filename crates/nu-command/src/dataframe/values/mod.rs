@@ -1,4 +1,12 @@
 mod nu_dataframe;
+mod nu_expression;
+mod nu_groupby;
+mod nu_lazyframe;
+mod nu_lazygroupby;
 pub mod utils;
 
 pub use nu_dataframe::{Axis, Column, NuDataFrame};
+pub use nu_expression::NuExpression;
+pub use nu_groupby::NuGroupBy;
+pub use nu_lazyframe::NuLazyFrame;
+pub use nu_lazygroupby::NuLazyGroupBy;
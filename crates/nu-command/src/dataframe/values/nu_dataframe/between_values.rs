@@ -4,7 +4,7 @@ use nu_protocol::{ast::Operator, span, ShellError, Span, Spanned, Value};
 use num::Zero;
 use polars::prelude::{
     BooleanType, ChunkCompare, ChunkedArray, DataType, Float64Type, Int64Type, IntoSeries,
-    NumOpsDispatchChecked, PolarsError, Series,
+    NumOpsDispatchChecked, PolarsError, Series, Utf8Type,
 };
 use std::ops::{Add, BitAnd, BitOr, Div, Mul, Sub};
 
@@ -266,6 +266,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::equal, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.equal(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -281,6 +284,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::not_equal, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.not_equal(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -294,6 +300,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::lt, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.lt(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -307,6 +316,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::lt_eq, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.lt_eq(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -320,6 +332,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::gt, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.gt(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -333,6 +348,9 @@ pub(super) fn compute_series_single_value(
             Value::Float { val, .. } => {
                 compare_series_decimal(&lhs, *val, ChunkedArray::gt_eq, lhs_span)
             }
+            Value::String { val, .. } => {
+                compare_series_string(&lhs, val, |series, val| series.gt_eq(val), lhs_span)
+            }
             _ => Err(ShellError::OperatorMismatch {
                 op_span: operator.span,
                 lhs_ty: left.get_type(),
@@ -603,6 +621,32 @@ where
     }
 }
 
+fn compare_series_string<F>(
+    series: &Series,
+    val: &str,
+    f: F,
+    span: Span,
+) -> Result<Value, ShellError>
+where
+    F: Fn(&ChunkedArray<Utf8Type>, &str) -> ChunkedArray<BooleanType>,
+{
+    match series.utf8() {
+        Ok(casted) => {
+            let res = f(casted, val);
+            let res = res.into_series();
+            NuDataFrame::series_to_value(res, span)
+        }
+        Err(_) => Err(ShellError::SpannedLabeledError(
+            "Incorrect type".into(),
+            format!(
+                "Series of type {} can not be compared with a string value",
+                series.dtype()
+            ),
+            span,
+        )),
+    }
+}
+
 fn contains_series_pat(series: &Series, pat: &str, span: Span) -> Result<Value, ShellError> {
     let casted = series.utf8();
     match casted {
@@ -0,0 +1,66 @@
+use super::NuExpression;
+use nu_protocol::{ast::Operator, Category, CustomValue, ShellError, Span, Spanned, Value};
+
+// CustomValue implementation for NuExpression
+impl CustomValue for NuExpression {
+    fn typetag_name(&self) -> &'static str {
+        "expression"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+
+    fn clone_value(&self, span: nu_protocol::Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self.clone()),
+            span,
+        }
+    }
+
+    fn category(&self) -> Category {
+        Category::Custom(self.typetag_name().into())
+    }
+
+    fn value_string(&self) -> String {
+        self.typetag_name().to_string()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::String {
+            val: format!("{:?}", self.as_ref()),
+            span,
+        })
+    }
+
+    fn to_json(&self) -> nu_json::Value {
+        nu_json::Value::Null
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_int(&self, _count: usize, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("expression".into(), span))
+    }
+
+    fn follow_path_string(&self, _column_name: String, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("expression".into(), span))
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        let operator = Spanned {
+            item: operator,
+            span: op,
+        };
+
+        self.compute_with_value(lhs_span, operator, right)
+    }
+}
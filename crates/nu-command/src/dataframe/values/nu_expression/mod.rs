@@ -0,0 +1,118 @@
+mod custom_value;
+
+use nu_protocol::{ast::Operator, ShellError, Span, Spanned, Type, Value};
+use polars::prelude::{col, lit, Expr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// A polars expression, eg) `(dataframe col a) > 2`, used to filter lazy frames. Comparing or
+// doing arithmetic with an expression gives another expression
+#[derive(Debug, Clone)]
+pub struct NuExpression(Expr);
+
+// Expressions can't be serialized, so an expression is never sent anywhere
+impl Serialize for NuExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for NuExpression {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(NuExpression(col("*")))
+    }
+}
+
+impl AsRef<Expr> for NuExpression {
+    fn as_ref(&self) -> &Expr {
+        &self.0
+    }
+}
+
+impl NuExpression {
+    pub fn new(expr: Expr) -> Self {
+        Self(expr)
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self),
+            span,
+        }
+    }
+
+    pub fn into_polars(self) -> Expr {
+        self.0
+    }
+
+    pub fn try_from_value(value: Value) -> Result<Self, ShellError> {
+        match value {
+            Value::CustomValue { val, span } => match val.as_any().downcast_ref::<Self>() {
+                Some(expr) => Ok(expr.clone()),
+                None => Err(ShellError::CantConvert(
+                    "lazy expression".into(),
+                    "non-expression value".into(),
+                    span,
+                )),
+            },
+            _ => Err(ShellError::CantConvert(
+                "lazy expression".into(),
+                "non-expression value".into(),
+                value.span()?,
+            )),
+        }
+    }
+
+    pub fn compute_with_value(
+        &self,
+        lhs_span: Span,
+        operator: Spanned<Operator>,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        let rhs = match right {
+            Value::CustomValue { val, .. } => match val.as_any().downcast_ref::<Self>() {
+                Some(expr) => expr.0.clone(),
+                None => return Err(mismatch(&operator, lhs_span, right)),
+            },
+            Value::Int { val, .. } => lit(*val),
+            Value::Float { val, .. } => lit(*val),
+            Value::String { val, .. } => lit(val.as_str()),
+            Value::Bool { val, .. } => lit(*val),
+            _ => return Err(mismatch(&operator, lhs_span, right)),
+        };
+
+        let lhs = self.0.clone();
+        let expr = match operator.item {
+            Operator::Equal => lhs.eq(rhs),
+            Operator::NotEqual => lhs.neq(rhs),
+            Operator::LessThan => lhs.lt(rhs),
+            Operator::LessThanOrEqual => lhs.lt_eq(rhs),
+            Operator::GreaterThan => lhs.gt(rhs),
+            Operator::GreaterThanOrEqual => lhs.gt_eq(rhs),
+            Operator::Plus => lhs + rhs,
+            Operator::Minus => lhs - rhs,
+            Operator::Multiply => lhs * rhs,
+            Operator::Divide => lhs / rhs,
+            Operator::And => lhs.and(rhs),
+            Operator::Or => lhs.or(rhs),
+            _ => return Err(mismatch(&operator, lhs_span, right)),
+        };
+
+        Ok(NuExpression(expr).into_value(lhs_span))
+    }
+}
+
+fn mismatch(operator: &Spanned<Operator>, lhs_span: Span, right: &Value) -> ShellError {
+    ShellError::OperatorMismatch {
+        op_span: operator.span,
+        lhs_ty: Type::Custom,
+        lhs_span,
+        rhs_ty: right.get_type(),
+        rhs_span: right.span().unwrap_or(operator.span),
+    }
+}
@@ -0,0 +1,96 @@
+mod custom_value;
+
+use nu_protocol::{PipelineData, ShellError, Span, Value};
+use polars::prelude::{DataFrame, GroupBy, GroupTuples};
+use serde::{Deserialize, Serialize};
+
+// A dataframe grouped by some of its columns. Polars' GroupBy borrows the dataframe it groups,
+// so the dataframe is kept together with the groups and the GroupBy is made again when needed
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NuGroupBy {
+    dataframe: DataFrame,
+    by: Vec<String>,
+    groups: GroupTuples,
+}
+
+impl AsRef<DataFrame> for NuGroupBy {
+    fn as_ref(&self) -> &polars::prelude::DataFrame {
+        &self.dataframe
+    }
+}
+
+impl NuGroupBy {
+    pub fn new(dataframe: DataFrame, by: Vec<String>, groups: GroupTuples) -> Self {
+        NuGroupBy {
+            dataframe,
+            by,
+            groups,
+        }
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self),
+            span,
+        }
+    }
+
+    pub fn try_from_value(value: Value) -> Result<Self, ShellError> {
+        match value {
+            Value::CustomValue { val, span } => match val.as_any().downcast_ref::<NuGroupBy>() {
+                Some(groupby) => Ok(NuGroupBy {
+                    dataframe: groupby.dataframe.clone(),
+                    by: groupby.by.clone(),
+                    groups: groupby.groups.clone(),
+                }),
+                None => Err(ShellError::CantConvert(
+                    "Group by not found".into(),
+                    "value is not a group by object".into(),
+                    span,
+                )),
+            },
+            _ => Err(ShellError::CantConvert(
+                "Group by not found".into(),
+                "value is not a group by object".into(),
+                value.span()?,
+            )),
+        }
+    }
+
+    pub fn try_from_pipeline(input: PipelineData, span: Span) -> Result<Self, ShellError> {
+        let value = input.into_value(span);
+        NuGroupBy::try_from_value(value)
+    }
+
+    pub fn to_groupby(&self) -> Result<GroupBy, ShellError> {
+        let by = self.dataframe.select_series(&self.by).map_err(|e| {
+            ShellError::LabeledError("Error creating groupby".into(), e.to_string())
+        })?;
+
+        Ok(GroupBy::new(&self.dataframe, by, self.groups.clone(), None))
+    }
+
+    // A group by is shown as the columns it groups by, with the number of groups
+    pub fn print(&self, span: Span) -> Result<Vec<Value>, ShellError> {
+        let values = self
+            .by
+            .iter()
+            .map(|col| Value::Record {
+                cols: vec!["group by".into(), "groups".into()],
+                vals: vec![
+                    Value::String {
+                        val: col.into(),
+                        span,
+                    },
+                    Value::Int {
+                        val: self.groups.len() as i64,
+                        span,
+                    },
+                ],
+                span,
+            })
+            .collect();
+
+        Ok(values)
+    }
+}
@@ -0,0 +1,67 @@
+use super::NuLazyFrame;
+use nu_protocol::{Category, CustomValue, ShellError, Span, Value};
+
+// CustomValue implementation for NuLazyFrame
+impl CustomValue for NuLazyFrame {
+    fn typetag_name(&self) -> &'static str {
+        "lazyframe"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+
+    fn clone_value(&self, span: nu_protocol::Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self.clone()),
+            span,
+        }
+    }
+
+    fn category(&self) -> Category {
+        Category::Custom(self.typetag_name().into())
+    }
+
+    fn value_string(&self) -> String {
+        self.typetag_name().to_string()
+    }
+
+    // A lazy frame is shown as its query plan, so showing it doesn't compute it
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let optimized_plan = self
+            .as_ref()
+            .describe_optimized_plan()
+            .unwrap_or_else(|_| "<NOT AVAILABLE>".to_string());
+
+        Ok(Value::Record {
+            cols: vec!["plan".into(), "optimized_plan".into()],
+            vals: vec![
+                Value::String {
+                    val: self.as_ref().describe_plan(),
+                    span,
+                },
+                Value::String {
+                    val: optimized_plan,
+                    span,
+                },
+            ],
+            span,
+        })
+    }
+
+    fn to_json(&self) -> nu_json::Value {
+        nu_json::Value::Null
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_int(&self, _count: usize, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("lazyframe".into(), span))
+    }
+
+    fn follow_path_string(&self, _column_name: String, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("lazyframe".into(), span))
+    }
+}
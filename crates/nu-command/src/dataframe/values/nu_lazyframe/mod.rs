@@ -0,0 +1,130 @@
+mod custom_value;
+
+use super::NuDataFrame;
+use nu_protocol::{PipelineData, ShellError, Span, Value};
+use polars::prelude::{DataFrame, IntoLazy, LazyFrame};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+// A dataframe that hasn't been computed yet. Commands add to its query plan, which polars
+// optimizes and runs only once the frame is collected into a NuDataFrame
+#[derive(Clone)]
+pub struct NuLazyFrame(LazyFrame);
+
+// The query plan of a LazyFrame can't be serialized, so a lazy frame is never sent anywhere
+impl Serialize for NuLazyFrame {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for NuLazyFrame {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(NuLazyFrame(DataFrame::default().lazy()))
+    }
+}
+
+impl fmt::Debug for NuLazyFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NuLazyFrame")
+    }
+}
+
+impl AsRef<LazyFrame> for NuLazyFrame {
+    fn as_ref(&self) -> &LazyFrame {
+        &self.0
+    }
+}
+
+impl From<LazyFrame> for NuLazyFrame {
+    fn from(lazy_frame: LazyFrame) -> Self {
+        NuLazyFrame(lazy_frame)
+    }
+}
+
+impl NuLazyFrame {
+    pub fn new(lazy_frame: LazyFrame) -> Self {
+        Self(lazy_frame)
+    }
+
+    pub fn from_dataframe(df: NuDataFrame) -> Self {
+        Self(df.as_ref().clone().lazy())
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self),
+            span,
+        }
+    }
+
+    pub fn into_polars(self) -> LazyFrame {
+        self.0
+    }
+
+    pub fn collect(self, span: Span) -> Result<NuDataFrame, ShellError> {
+        self.0.collect().map(NuDataFrame::new).map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error collecting lazy frame".into(),
+                e.to_string(),
+                span,
+            )
+        })
+    }
+
+    // The names of the columns the frame will have once it is collected
+    pub fn columns(&self) -> Vec<String> {
+        self.0
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect()
+    }
+
+    pub fn can_downcast(value: &Value) -> bool {
+        if let Value::CustomValue { val, .. } = value {
+            val.as_any().downcast_ref::<Self>().is_some()
+        } else {
+            false
+        }
+    }
+
+    pub fn try_from_value(value: Value) -> Result<Self, ShellError> {
+        match value {
+            Value::CustomValue { val, span } => match val.as_any().downcast_ref::<Self>() {
+                Some(lazy) => Ok(lazy.clone()),
+                None => Err(ShellError::CantConvert(
+                    "lazy frame".into(),
+                    "non-lazy frame".into(),
+                    span,
+                )),
+            },
+            _ => Err(ShellError::CantConvert(
+                "lazy frame".into(),
+                "non-lazy frame".into(),
+                value.span()?,
+            )),
+        }
+    }
+
+    pub fn try_from_pipeline(input: PipelineData, span: Span) -> Result<Self, ShellError> {
+        let value = input.into_value(span);
+        Self::try_from_value(value)
+    }
+
+    // The frame as it is, or a dataframe made lazy. Lazy commands take either one
+    pub fn try_from_lazy_or_eager(value: Value) -> Result<Self, ShellError> {
+        if Self::can_downcast(&value) {
+            Self::try_from_value(value)
+        } else {
+            NuDataFrame::try_from_value(value).map(Self::from_dataframe)
+        }
+    }
+}
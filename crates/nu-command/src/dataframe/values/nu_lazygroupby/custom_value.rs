@@ -0,0 +1,62 @@
+use super::NuLazyGroupBy;
+use nu_protocol::{Category, CustomValue, ShellError, Span, Value};
+
+// CustomValue implementation for NuLazyGroupBy
+impl CustomValue for NuLazyGroupBy {
+    fn typetag_name(&self) -> &'static str {
+        "lazygroupby"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+
+    fn clone_value(&self, span: nu_protocol::Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self.clone()),
+            span,
+        }
+    }
+
+    fn category(&self) -> Category {
+        Category::Custom(self.typetag_name().into())
+    }
+
+    fn value_string(&self) -> String {
+        self.typetag_name().to_string()
+    }
+
+    // Like an eager group by, a lazy one is shown as the columns it groups by
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let vals = self
+            .by()
+            .iter()
+            .map(|col| Value::Record {
+                cols: vec!["group by".into()],
+                vals: vec![Value::String {
+                    val: col.into(),
+                    span,
+                }],
+                span,
+            })
+            .collect();
+
+        Ok(Value::List { vals, span })
+    }
+
+    fn to_json(&self) -> nu_json::Value {
+        nu_json::Value::Null
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_int(&self, _count: usize, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("lazygroupby".into(), span))
+    }
+
+    fn follow_path_string(&self, _column_name: String, span: Span) -> Result<Value, ShellError> {
+        Err(ShellError::IncompatiblePathAccess("lazygroupby".into(), span))
+    }
+}
@@ -0,0 +1,76 @@
+mod custom_value;
+
+use super::NuLazyFrame;
+use nu_protocol::{Span, Value};
+use polars::prelude::{col, Expr, LazyFrame};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+// A lazy frame grouped by some of its columns. Like the frame, the grouping is only computed
+// once the aggregation of the groups is collected
+#[derive(Clone)]
+pub struct NuLazyGroupBy {
+    lazy: NuLazyFrame,
+    by: Vec<String>,
+}
+
+// A lazy group by can't be serialized, so it is never sent anywhere
+impl Serialize for NuLazyGroupBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for NuLazyGroupBy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(NuLazyGroupBy {
+            lazy: NuLazyFrame::deserialize(deserializer)?,
+            by: vec![],
+        })
+    }
+}
+
+impl fmt::Debug for NuLazyGroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NuLazyGroupBy")
+    }
+}
+
+impl NuLazyGroupBy {
+    pub fn new(lazy: NuLazyFrame, by: Vec<String>) -> Self {
+        Self { lazy, by }
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self),
+            span,
+        }
+    }
+
+    pub fn by(&self) -> &[String] {
+        &self.by
+    }
+
+    // The columns that are aggregated, all but the ones grouped by
+    pub fn value_columns(&self) -> Vec<String> {
+        self.lazy
+            .columns()
+            .into_iter()
+            .filter(|name| !self.by.contains(name))
+            .collect()
+    }
+
+    // Aggregates each group with the expressions, into a frame with a row per group
+    pub fn agg(self, aggs: Vec<Expr>) -> LazyFrame {
+        let by = self.by.iter().map(|name| col(name)).collect::<Vec<Expr>>();
+
+        self.lazy.into_polars().groupby(by).agg(aggs)
+    }
+}
@@ -0,0 +1,104 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+use super::values::{Column, NuDataFrame};
+
+#[derive(Clone)]
+pub struct WithColumn;
+
+impl Command for WithColumn {
+    fn name(&self) -> &str {
+        "dataframe with-column"
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a series to the dataframe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("series", SyntaxShape::Any, "series to be added")
+            .named(
+                "name",
+                SyntaxShape::String,
+                "column name, replacing the one of the series",
+                Some('n'),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Adds a series to the dataframe",
+            example: r#"let df = ([[a b]; [1 2] [3 4]] | dataframe to-df); $df | dataframe with-column ($df.a * 2) --name c"#,
+            result: Some(
+                NuDataFrame::try_from_columns(vec![
+                    Column::new("a".to_string(), vec![1.into(), 3.into()]),
+                    Column::new("b".to_string(), vec![2.into(), 4.into()]),
+                    Column::new("c".to_string(), vec![2.into(), 6.into()]),
+                ])
+                .expect("simple df for test should not fail")
+                .into_value(Span::unknown()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        command(engine_state, stack, call, input)
+    }
+}
+
+fn command(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let name: Option<String> = call.get_flag(engine_state, stack, "name")?;
+    let other_value: Value = call.req(engine_state, stack, 0)?;
+
+    let other_span = other_value.span()?;
+    let mut other = NuDataFrame::try_from_value(other_value)?.as_series(other_span)?;
+    if let Some(name) = name {
+        other.rename(&name);
+    }
+
+    let mut df = NuDataFrame::try_from_pipeline(input, call.head)?;
+
+    df.as_mut()
+        .with_column(other)
+        .map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Error adding column to dataframe".into(),
+                e.to_string(),
+                other_span,
+            )
+        })
+        .map(|df| {
+            PipelineData::Value(
+                NuDataFrame::dataframe_into_value(df.clone(), call.head),
+                None,
+            )
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_dataframe::test_dataframe;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        test_dataframe(WithColumn {})
+    }
+}
@@ -3,7 +3,7 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, Signature, Span, Spanned, SyntaxShape, Value,
+    Category, Example, PipelineData, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 use super::utils::{parse_date_from_string, unsupported_input_error};
@@ -23,6 +23,11 @@ impl Command for SubCommand {
                 SyntaxShape::String,
                 "the desired date format",
             )
+            .input_output_types(vec![
+                (Type::Date, Type::String),
+                (Type::String, Type::String),
+                (Type::Nothing, Type::String),
+            ])
             .category(Category::Date)
     }
 
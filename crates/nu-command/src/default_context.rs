@@ -24,23 +24,31 @@ pub fn create_default_context() -> EngineState {
         // Core
         bind_command! {
             Alias,
+            Break,
+            Continue,
             Debug,
             Def,
             Describe,
             Do,
             Echo,
+            ErrorMake,
             ExportCommand,
             ExportDef,
             ExportEnv,
+            Extern,
             For,
             Help,
             Hide,
             If,
             Let,
+            Loop,
             Module,
+            Return,
             Source,
+            Try,
             Use,
             Version,
+            While,
         };
 
         // Filters
@@ -54,22 +62,31 @@ pub fn create_default_context() -> EngineState {
             DropNth,
             Each,
             First,
+            Flatten,
             Get,
+            GroupBy,
+            Insert,
             Last,
             Length,
             Lines,
+            Merge,
             Nth,
             ParEach,
             Prepend,
             Range,
+            Reduce,
             Reject,
+            Rename,
             Reverse,
             Select,
             Shuffle,
             Skip,
             SkipUntil,
             SkipWhile,
+            SortBy,
+            Transpose,
             Uniq,
+            UniqBy,
             Update,
             Where,
             Wrap,
@@ -93,7 +110,9 @@ pub fn create_default_context() -> EngineState {
         // System
         bind_command! {
             Benchmark,
+            Complete,
             External,
+            History,
             Ps,
             Sys,
         };
@@ -140,7 +159,9 @@ pub fn create_default_context() -> EngineState {
             Ls,
             Mkdir,
             Mv,
+            Open,
             Rm,
+            Save,
             Touch,
         };
 
@@ -278,7 +299,7 @@ pub fn create_default_context() -> EngineState {
         };
 
         #[cfg(feature = "plugin")]
-        bind_command!(Register);
+        bind_command!(PluginList, PluginRescan, PluginStop, Register);
 
         // This is a WIP proof of concept
         // bind_command!(ListGitBranches, Git, GitCheckout, Source);
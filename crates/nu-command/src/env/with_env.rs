@@ -75,7 +75,7 @@ impl Command for WithEnv {
 
 #[derive(Debug, Clone)]
 pub enum EnvVar {
-    Proper(String),
+    Proper(Value),
     Nothing,
 }
 
@@ -83,20 +83,10 @@ impl TryFrom<&Value> for EnvVar {
     type Error = ShellError;
 
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
-        if matches!(value, Value::Nothing { .. }) {
-            Ok(EnvVar::Nothing)
-        } else if let Ok(s) = value.as_string() {
-            if s.is_empty() {
-                Ok(EnvVar::Nothing)
-            } else {
-                Ok(EnvVar::Proper(s))
-            }
-        } else {
-            Err(ShellError::CantConvert(
-                "string".into(),
-                value.get_type().to_string(),
-                value.span()?,
-            ))
+        match value {
+            Value::Nothing { .. } => Ok(EnvVar::Nothing),
+            Value::String { val, .. } if val.is_empty() => Ok(EnvVar::Nothing),
+            _ => Ok(EnvVar::Proper(value.clone())),
         }
     }
 }
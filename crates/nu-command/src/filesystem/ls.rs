@@ -4,7 +4,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, DataSource, IntoInterruptiblePipelineData, PipelineData, PipelineMetadata, Signature,
-    SyntaxShape, Value,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -27,6 +27,7 @@ impl Command for Ls {
                 SyntaxShape::GlobPattern,
                 "the glob pattern to use",
             )
+            .input_output_types(vec![(Type::Nothing, Type::Table)])
             .category(Category::FileSystem)
     }
 
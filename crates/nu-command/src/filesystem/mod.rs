@@ -3,7 +3,9 @@ mod cp;
 mod ls;
 mod mkdir;
 mod mv;
+mod open;
 mod rm;
+mod save;
 mod touch;
 mod util;
 
@@ -12,5 +14,7 @@ pub use cp::Cp;
 pub use ls::Ls;
 pub use mkdir::Mkdir;
 pub use mv::Mv;
+pub use open::Open;
 pub use rm::Rm;
+pub use save::Save;
 pub use touch::Touch;
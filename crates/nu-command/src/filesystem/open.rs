@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Open;
+
+impl Command for Open {
+    fn name(&self) -> &str {
+        "open"
+    }
+
+    fn usage(&self) -> &str {
+        "Opens a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "If the file extension matches a `from <ext>` command, the contents are parsed with it."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("open")
+            .required("filename", SyntaxShape::Filepath, "the filename to use")
+            .switch("raw", "open file as raw string or binary", Some('r'))
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let raw = call.has_flag("raw");
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let arg_span = path.span;
+        let path = nu_path::expand_path(&path.item);
+
+        if path.is_dir() {
+            return Err(ShellError::SpannedLabeledError(
+                "Is a directory".into(),
+                "cannot open a directory".into(),
+                arg_span,
+            ));
+        }
+
+        let bytes = std::fs::read(&path)
+            .map_err(|err| ShellError::FileNotFoundCustom(err.to_string(), arg_span))?;
+
+        let contents = match String::from_utf8(bytes) {
+            Ok(val) => Value::String {
+                val,
+                span: call.head,
+            },
+            Err(err) => Value::Binary {
+                val: err.into_bytes(),
+                span: call.head,
+            },
+        };
+
+        if raw {
+            return Ok(contents.into_pipeline_data());
+        }
+
+        match file_extension(&path)
+            .and_then(|ext| engine_state.find_decl(format!("from {}", ext).as_bytes()))
+        {
+            Some(converter_id) => {
+                let mut converter_call = Call::new();
+                converter_call.decl_id = converter_id;
+                converter_call.head = call.head;
+
+                engine_state.get_decl(converter_id).run(
+                    engine_state,
+                    stack,
+                    &converter_call,
+                    contents.into_pipeline_data(),
+                )
+            }
+            None => Ok(contents.into_pipeline_data()),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a file, with structure (based on file extension)",
+                example: "open myfile.json",
+                result: None,
+            },
+            Example {
+                description: "Open a file, as raw bytes or text",
+                example: "open myfile.json --raw",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The lowercased extension of the path, used to look up the matching `from <ext>` command
+pub(crate) fn file_extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+}
@@ -0,0 +1,151 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+
+use super::open::file_extension;
+
+#[derive(Clone)]
+pub struct Save;
+
+impl Command for Save {
+    fn name(&self) -> &str {
+        "save"
+    }
+
+    fn usage(&self) -> &str {
+        "Save a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "If the file extension matches a `to <ext>` command, the input is converted with it before saving."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("save")
+            .required("filename", SyntaxShape::Filepath, "the filename to use")
+            .switch("raw", "save file as raw string or binary", Some('r'))
+            .switch("append", "append input to the end of the file", Some('a'))
+            .switch("force", "overwrite the destination", Some('f'))
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let raw = call.has_flag("raw");
+        let append = call.has_flag("append");
+        let force = call.has_flag("force");
+
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let arg_span = path.span;
+        let path = nu_path::expand_path(&path.item);
+
+        if path.exists() && !force && !append {
+            return Err(ShellError::SpannedLabeledError(
+                "Destination file already exists".into(),
+                format!(
+                    "'{}' already exists, use --force to overwrite or --append to add to it",
+                    path.to_string_lossy()
+                ),
+                arg_span,
+            ));
+        }
+
+        let converter_id = if raw {
+            None
+        } else {
+            file_extension(&path)
+                .and_then(|ext| engine_state.find_decl(format!("to {}", ext).as_bytes()))
+        };
+
+        let output = match converter_id {
+            Some(converter_id) => {
+                let mut converter_call = Call::new();
+                converter_call.decl_id = converter_id;
+                converter_call.head = call.head;
+
+                engine_state.get_decl(converter_id).run(
+                    engine_state,
+                    stack,
+                    &converter_call,
+                    input,
+                )?
+            }
+            None => input,
+        };
+
+        let bytes = value_to_bytes(output.into_value(call.head), call.head)?;
+
+        let mut file = if append {
+            OpenOptions::new().append(true).create(true).open(&path)
+        } else {
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&path)
+        }
+        .map_err(|err| {
+            ShellError::CreateNotPossible(format!("Failed to open file: {}", err), arg_span)
+        })?;
+
+        file.write_all(&bytes)
+            .map_err(|err| ShellError::IOError(err.to_string()))?;
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save a table as json (based on file extension)",
+                example: "[[a b]; [1 2]] | save foo.json",
+                result: None,
+            },
+            Example {
+                description: "Save a string to a file without converting it",
+                example: "'hello' | save foo.txt --raw",
+                result: None,
+            },
+            Example {
+                description: "Append a line to the end of a log file",
+                example: "'new entry' | save log.txt --append",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn value_to_bytes(value: Value, head: Span) -> Result<Vec<u8>, ShellError> {
+    match value {
+        Value::String { val, .. } => Ok(val.into_bytes()),
+        Value::Binary { val, .. } => Ok(val),
+        Value::Nothing { .. } => Ok(vec![]),
+        // Streams from externals arrive as lists of strings or binary chunks
+        Value::List { vals, .. } => {
+            let mut output = vec![];
+            for val in vals {
+                output.extend(value_to_bytes(val, head)?);
+            }
+            Ok(output)
+        }
+        Value::Error { error } => Err(error),
+        other => Err(ShellError::UnsupportedInput(
+            format!(
+                "can't save {} without converting it first, use a known extension or a `to` command",
+                other.get_type()
+            ),
+            head,
+        )),
+    }
+}
@@ -2,7 +2,7 @@ use nu_engine::eval_block;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
 };
 
 #[derive(Clone)]
@@ -20,6 +20,7 @@ impl Command for All {
                 SyntaxShape::RowCondition,
                 "the predicate that must match",
             )
+            .input_output_types(vec![(Type::Unknown, Type::Bool)])
             .category(Category::Filters)
     }
 
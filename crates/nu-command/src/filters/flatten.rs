@@ -0,0 +1,213 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Flatten;
+
+impl Command for Flatten {
+    fn name(&self) -> &str {
+        "flatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("flatten")
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "optionally flatten data by column",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Flatten the table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Nested lists are flattened by one level. Record columns are inlined into their parent row, and table columns are expanded into one row per nested row."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Flatten a nested list by one level",
+                example: "[[1 2] [3 [4]]] | flatten",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::List {
+                            vals: vec![Value::test_int(4)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Inline the columns of a nested record",
+                example: "{'name': 'nu', 'meta': {'stars': 5}} | flatten",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["name".to_string(), "stars".to_string()],
+                        vals: vec![Value::test_string("nu"), Value::test_int(5)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Expand a table column into one row per nested row",
+                example: "{'name': 'nu', 'langs': [[lang]; [rust] [nu]]} | flatten langs",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["name".to_string(), "lang".to_string()],
+                            vals: vec![Value::test_string("nu"), Value::test_string("rust")],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["name".to_string(), "lang".to_string()],
+                            vals: vec![Value::test_string("nu"), Value::test_string("nu")],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+
+        input.flat_map(
+            move |item| flat_value(&columns, item),
+            engine_state.ctrlc.clone(),
+        )
+    }
+}
+
+fn flat_value(columns: &[String], item: Value) -> Vec<Value> {
+    match item {
+        Value::List { vals, .. } => vals,
+        Value::Record { cols, vals, span } => {
+            let mut out_cols: Vec<String> = vec![];
+            let mut out_vals: Vec<Value> = vec![];
+
+            // Only the first table column is expanded, the others stay nested until the next flatten
+            let mut table_column: Option<(usize, String, Vec<Value>)> = None;
+
+            for (col, val) in cols.iter().zip(vals) {
+                let selected = columns.is_empty() || columns.contains(col);
+
+                match val {
+                    Value::Record {
+                        cols: inner_cols,
+                        vals: inner_vals,
+                        ..
+                    } if selected => {
+                        for (inner_col, inner_val) in inner_cols.into_iter().zip(inner_vals) {
+                            let name = flattened_name(&cols, &out_cols, col, inner_col);
+                            out_cols.push(name);
+                            out_vals.push(inner_val);
+                        }
+                    }
+                    Value::List { vals, .. } if selected && table_column.is_none() => {
+                        table_column = Some((out_cols.len(), col.clone(), vals));
+                    }
+                    other => {
+                        out_cols.push(col.clone());
+                        out_vals.push(other);
+                    }
+                }
+            }
+
+            match table_column {
+                Some((idx, parent, rows)) if !rows.is_empty() => rows
+                    .into_iter()
+                    .map(|row| {
+                        let mut cols = out_cols.clone();
+                        let mut vals = out_vals.clone();
+
+                        match row {
+                            Value::Record {
+                                cols: inner_cols,
+                                vals: inner_vals,
+                                ..
+                            } => {
+                                let mut at = idx;
+                                for (inner_col, inner_val) in inner_cols.into_iter().zip(inner_vals)
+                                {
+                                    let name = flattened_name(&out_cols, &cols, &parent, inner_col);
+                                    cols.insert(at, name);
+                                    vals.insert(at, inner_val);
+                                    at += 1;
+                                }
+                            }
+                            other => {
+                                cols.insert(idx, parent.clone());
+                                vals.insert(idx, other);
+                            }
+                        }
+
+                        Value::Record { cols, vals, span }
+                    })
+                    .collect(),
+                Some((idx, parent, _)) => {
+                    out_cols.insert(idx, parent);
+                    out_vals.insert(idx, Value::Nothing { span });
+
+                    vec![Value::Record {
+                        cols: out_cols,
+                        vals: out_vals,
+                        span,
+                    }]
+                }
+                None => vec![Value::Record {
+                    cols: out_cols,
+                    vals: out_vals,
+                    span,
+                }],
+            }
+        }
+        other => vec![other],
+    }
+}
+
+/// Prefix the nested column with its parent's name when it would clash with an existing column
+fn flattened_name(
+    outer_cols: &[String],
+    taken_cols: &[String],
+    parent: &str,
+    inner_col: String,
+) -> String {
+    if inner_col != parent && (outer_cols.contains(&inner_col) || taken_cols.contains(&inner_col)) {
+        format!("{}_{}", parent, inner_col)
+    } else {
+        inner_col
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Flatten {})
+    }
+}
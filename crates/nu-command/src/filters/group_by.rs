@@ -0,0 +1,221 @@
+use indexmap::IndexMap;
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    BlockId, Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature,
+    Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct GroupBy;
+
+impl Command for GroupBy {
+    fn name(&self) -> &str {
+        "group-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("group-by")
+            .optional(
+                "grouper",
+                SyntaxShape::Any,
+                "the column name or block to group by",
+            )
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Unknown)), Type::Record(vec![])),
+                (Type::Table, Type::Record(vec![])),
+            ])
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Create a new table grouped."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The result is a record with one column per group, each holding the list of rows in that group."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Group items by their value",
+                example: "['1' '3' '1' '3' '2' '1' '1'] | group-by",
+                result: Some(Value::Record {
+                    cols: vec!["1".to_string(), "3".to_string(), "2".to_string()],
+                    vals: vec![
+                        Value::List {
+                            vals: vec![
+                                Value::test_string("1"),
+                                Value::test_string("1"),
+                                Value::test_string("1"),
+                                Value::test_string("1"),
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_string("3"), Value::test_string("3")],
+                            span: Span::unknown(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_string("2")],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Group a table by a column",
+                example: "[[name lang]; [andres rb] [jt rs] [yehuda rb]] | group-by lang",
+                result: Some(Value::Record {
+                    cols: vec!["rb".to_string(), "rs".to_string()],
+                    vals: vec![
+                        Value::List {
+                            vals: vec![
+                                Value::Record {
+                                    cols: vec!["name".to_string(), "lang".to_string()],
+                                    vals: vec![
+                                        Value::test_string("andres"),
+                                        Value::test_string("rb"),
+                                    ],
+                                    span: Span::unknown(),
+                                },
+                                Value::Record {
+                                    cols: vec!["name".to_string(), "lang".to_string()],
+                                    vals: vec![
+                                        Value::test_string("yehuda"),
+                                        Value::test_string("rb"),
+                                    ],
+                                    span: Span::unknown(),
+                                },
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::List {
+                            vals: vec![Value::Record {
+                                cols: vec!["name".to_string(), "lang".to_string()],
+                                vals: vec![Value::test_string("jt"), Value::test_string("rs")],
+                                span: Span::unknown(),
+                            }],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Group items using a block to compute the grouping key",
+                example: "[1 2 3 4] | group-by { |x| if $x mod 2 == 0 { 'even' } else { 'odd' } }",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        group_by(engine_state, stack, call, input)
+    }
+}
+
+enum Grouper {
+    ByValue,
+    ByColumn(Spanned<String>),
+    ByBlock(BlockId),
+}
+
+pub fn group_by(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let config = stack.get_config().unwrap_or_default();
+
+    let grouper = match call.opt::<Value>(engine_state, stack, 0)? {
+        None => Grouper::ByValue,
+        Some(Value::String { val, span }) => Grouper::ByColumn(Spanned { item: val, span }),
+        Some(Value::Block { val, .. }) => Grouper::ByBlock(val),
+        Some(other) => {
+            return Err(ShellError::TypeMismatch(
+                "expected a column name or a block".into(),
+                other.span()?,
+            ))
+        }
+    };
+
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+    for value in input.into_iter() {
+        if let Value::Error { error } = value {
+            return Err(error);
+        }
+
+        let key = match &grouper {
+            Grouper::ByValue => group_key(&value, &config),
+            Grouper::ByColumn(column) => match value.get_data_by_key(&column.item) {
+                Some(found) => group_key(&found, &config),
+                None => return Err(ShellError::CantFindColumn(column.span, value.span()?)),
+            },
+            Grouper::ByBlock(block_id) => {
+                let block = engine_state.get_block(*block_id);
+                let mut stack = stack.collect_captures(&block.captures);
+
+                if let Some(var) = block.signature.get_positional(0) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, value.clone());
+                    }
+                }
+
+                let output = eval_block(
+                    engine_state,
+                    &mut stack,
+                    block,
+                    value.clone().into_pipeline_data(),
+                )?
+                .into_value(span);
+
+                if let Value::Error { error } = output {
+                    return Err(error);
+                }
+
+                group_key(&output, &config)
+            }
+        };
+
+        groups.entry(key).or_insert_with(Vec::new).push(value);
+    }
+
+    let (cols, vals) = groups
+        .into_iter()
+        .map(|(key, vals)| (key, Value::List { vals, span }))
+        .unzip();
+
+    Ok(Value::Record { cols, vals, span }.into_pipeline_data())
+}
+
+fn group_key(value: &Value, config: &Config) -> String {
+    match value {
+        Value::String { val, .. } => val.clone(),
+        other => other.clone().into_string(", ", config),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(GroupBy {})
+    }
+}
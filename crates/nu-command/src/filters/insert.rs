@@ -0,0 +1,161 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Insert;
+
+impl Command for Insert {
+    fn name(&self) -> &str {
+        "insert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("insert")
+            .required(
+                "field",
+                SyntaxShape::CellPath,
+                "the name of the column to insert",
+            )
+            .required(
+                "new value",
+                SyntaxShape::Any,
+                "the new value to give the cell(s)",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Insert a new column."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        insert(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Insert a new column",
+                example: "echo {'name': 'nu', 'stars': 5} | insert alias 'Nushell'",
+                result: Some(Value::Record {
+                    cols: vec!["name".into(), "stars".into(), "alias".into()],
+                    vals: vec![
+                        Value::test_string("nu"),
+                        Value::test_int(5),
+                        Value::test_string("Nushell"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Insert a nested column, creating the missing parent column",
+                example: "echo {'name': 'nu'} | insert meta.stars 5",
+                result: Some(Value::Record {
+                    cols: vec!["name".into(), "meta".into()],
+                    vals: vec![
+                        Value::test_string("nu"),
+                        Value::Record {
+                            cols: vec!["stars".into()],
+                            vals: vec![Value::test_int(5)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Use in block form to compute the new column from each row",
+                example: "echo [[name stars]; [nu 5]] | insert doubled { |row| $row.stars * 2 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn insert(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+
+    let cell_path: CellPath = call.req(engine_state, stack, 0)?;
+    let replacement: Value = call.req(engine_state, stack, 1)?;
+    let engine_state = engine_state.clone();
+    let ctrlc = engine_state.ctrlc.clone();
+
+    // The new value is a block, so set it up and run it for each row instead of using it directly
+    if let Ok(block_id) = replacement.as_block() {
+        let block = engine_state.get_block(block_id).clone();
+
+        let mut stack = stack.collect_captures(&block.captures);
+
+        input.map(
+            move |mut input| {
+                if let Some(var) = block.signature.get_positional(0) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, input.clone())
+                    }
+                }
+
+                let output = eval_block(
+                    &engine_state,
+                    &mut stack,
+                    &block,
+                    input.clone().into_pipeline_data(),
+                );
+
+                match output {
+                    Ok(pd) => {
+                        if let Err(e) =
+                            input.insert_data_at_cell_path(&cell_path.members, pd.into_value(span))
+                        {
+                            return Value::Error { error: e };
+                        }
+
+                        input
+                    }
+                    Err(e) => Value::Error { error: e },
+                }
+            },
+            ctrlc,
+        )
+    } else {
+        input.map(
+            move |mut input| {
+                let replacement = replacement.clone();
+
+                if let Err(e) = input.insert_data_at_cell_path(&cell_path.members, replacement) {
+                    return Value::Error { error: e };
+                }
+
+                input
+            },
+            ctrlc,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Insert {})
+    }
+}
@@ -1,6 +1,6 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, IntoPipelineData, PipelineData, Signature, Value};
+use nu_protocol::{Category, IntoPipelineData, PipelineData, Signature, Type, Value};
 
 #[derive(Clone)]
 pub struct Length;
@@ -15,7 +15,9 @@ impl Command for Length {
     }
 
     fn signature(&self) -> nu_protocol::Signature {
-        Signature::build("length").category(Category::Filters)
+        Signature::build("length")
+            .input_output_types(vec![(Type::Unknown, Type::Int)])
+            .category(Category::Filters)
     }
 
     fn run(
@@ -0,0 +1,170 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Merge;
+
+impl Command for Merge {
+    fn name(&self) -> &str {
+        "merge"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("merge")
+            .required(
+                "record or block",
+                SyntaxShape::Any,
+                "the record, table, or block producing the values to merge in",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Merge a table or record into the input."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Columns present in both are overwritten by the merged values. When merging two tables, rows are merged pairwise."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Merge a record into another",
+                example: "{'a': 1, 'b': 2} | merge {'b': 3, 'c': 4}",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                    vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(4)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Merge two tables row by row",
+                example: "[[a]; [1] [2]] | merge { [[b]; [3] [4]] }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["a".to_string(), "b".to_string()],
+                            vals: vec![Value::test_int(1), Value::test_int(3)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["a".to_string(), "b".to_string()],
+                            vals: vec![Value::test_int(2), Value::test_int(4)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let other: Value = call.req(engine_state, stack, 0)?;
+
+        let other = match other {
+            Value::Block { val: block_id, .. } => {
+                let block = engine_state.get_block(block_id);
+                let mut stack = stack.collect_captures(&block.captures);
+
+                eval_block(engine_state, &mut stack, block, PipelineData::new(span))?
+                    .into_value(span)
+            }
+            other => other,
+        };
+
+        let ctrlc = engine_state.ctrlc.clone();
+
+        match (input, other) {
+            (PipelineData::Value(lhs @ Value::Record { .. }, ..), rhs @ Value::Record { .. }) => {
+                Ok(merge_records(&lhs, &rhs, span)?.into_pipeline_data())
+            }
+            (input, Value::List { vals, .. }) => {
+                let mut rhs_iter = vals.into_iter();
+
+                Ok(input
+                    .into_iter()
+                    .map(move |lhs| match rhs_iter.next() {
+                        Some(rhs) => merge_records(&lhs, &rhs, span)
+                            .unwrap_or_else(|error| Value::Error { error }),
+                        None => lhs,
+                    })
+                    .into_pipeline_data(ctrlc))
+            }
+            (input, rhs @ Value::Record { .. }) => Ok(input
+                .into_iter()
+                .map(move |lhs| {
+                    merge_records(&lhs, &rhs, span).unwrap_or_else(|error| Value::Error { error })
+                })
+                .into_pipeline_data(ctrlc)),
+            (_, other) => Err(ShellError::PipelineMismatch(
+                "record or table".into(),
+                span,
+                other.span()?,
+            )),
+        }
+    }
+}
+
+fn merge_records(lhs: &Value, rhs: &Value, span: Span) -> Result<Value, ShellError> {
+    match (lhs, rhs) {
+        (
+            Value::Record {
+                cols: lhs_cols,
+                vals: lhs_vals,
+                ..
+            },
+            Value::Record {
+                cols: rhs_cols,
+                vals: rhs_vals,
+                ..
+            },
+        ) => {
+            let mut cols = lhs_cols.clone();
+            let mut vals = lhs_vals.clone();
+
+            for (col, val) in rhs_cols.iter().zip(rhs_vals) {
+                match cols.iter().position(|c| c == col) {
+                    Some(pos) => vals[pos] = val.clone(),
+                    None => {
+                        cols.push(col.clone());
+                        vals.push(val.clone());
+                    }
+                }
+            }
+
+            Ok(Value::Record { cols, vals, span })
+        }
+        (Value::Error { error }, _) | (_, Value::Error { error }) => Err(error.clone()),
+        (Value::Record { .. }, other) | (other, _) => Err(ShellError::PipelineMismatch(
+            "record".into(),
+            span,
+            other.span()?,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Merge {})
+    }
+}
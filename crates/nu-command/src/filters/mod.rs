@@ -5,20 +5,29 @@ mod collect;
 mod drop;
 mod each;
 mod first;
+mod flatten;
 mod get;
+mod group_by;
+mod insert;
 mod last;
 mod length;
 mod lines;
+mod merge;
 mod nth;
 mod par_each;
 mod prepend;
 mod range;
+mod reduce;
 mod reject;
+mod rename;
 mod reverse;
 mod select;
 mod shuffle;
 mod skip;
+mod sort_by;
+mod transpose;
 mod uniq;
+mod uniq_by;
 mod update;
 mod where_;
 mod wrap;
@@ -31,20 +40,29 @@ pub use collect::Collect;
 pub use drop::*;
 pub use each::Each;
 pub use first::First;
+pub use flatten::Flatten;
 pub use get::Get;
+pub use group_by::GroupBy;
+pub use insert::Insert;
 pub use last::Last;
 pub use length::Length;
 pub use lines::Lines;
+pub use merge::Merge;
 pub use nth::Nth;
 pub use par_each::ParEach;
 pub use prepend::Prepend;
 pub use range::Range;
+pub use reduce::Reduce;
 pub use reject::Reject;
+pub use rename::Rename;
 pub use reverse::Reverse;
 pub use select::Select;
 pub use shuffle::Shuffle;
 pub use skip::*;
+pub use sort_by::SortBy;
+pub use transpose::Transpose;
 pub use uniq::*;
+pub use uniq_by::UniqBy;
 pub use update::Update;
 pub use where_::Where;
 pub use wrap::Wrap;
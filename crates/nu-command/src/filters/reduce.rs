@@ -0,0 +1,145 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Reduce;
+
+impl Command for Reduce {
+    fn name(&self) -> &str {
+        "reduce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("reduce")
+            .named(
+                "fold",
+                SyntaxShape::Any,
+                "reduce with initial value",
+                Some('f'),
+            )
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "reducing function, naming the current item and the accumulator: { |it, acc| ... }",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Aggregate a list table to a single value using an accumulator block."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Without --fold, the first item of the input is used as the initial accumulator."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[ 1 2 3 4 ] | reduce { |it, acc| $it + $acc }",
+                description: "Sum values of a list (same as 'math sum')",
+                result: Some(Value::test_int(10)),
+            },
+            Example {
+                example: "[ 1 2 3 ] | reduce -f 10 { |it, acc| $acc + $it }",
+                description: "Sum values with a starting value (fold)",
+                result: Some(Value::test_int(16)),
+            },
+            Example {
+                example: "[ i o t ] | reduce -f \"Arthur, King of the Britons\" { |it, acc| $acc | str find-replace -a $it \"X\" }",
+                description: "Replace selected characters in a string with 'X'",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let fold: Option<Value> = call.get_flag(engine_state, stack, "fold")?;
+        let block_id = call.positional[0]
+            .as_block()
+            .expect("internal error: expected block");
+
+        let block = engine_state.get_block(block_id);
+
+        // The block has no implicit variables, the item and the accumulator need a name
+        if block.signature.num_positionals() < 2 {
+            return Err(ShellError::SpannedLabeledError(
+                "Expected two block parameters".into(),
+                "name the item and the accumulator, like { |it, acc| ... }".into(),
+                call.positional[0].span,
+            ));
+        }
+
+        let mut stack = stack.collect_captures(&block.captures);
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let mut input_iter = input.into_iter();
+
+        let mut acc = match fold {
+            Some(fold) => fold,
+            None => match input_iter.next() {
+                Some(first) => first,
+                None => {
+                    return Err(ShellError::SpannedLabeledError(
+                        "Expected input".into(),
+                        "needs input when no --fold value is given".into(),
+                        span,
+                    ))
+                }
+            },
+        };
+
+        for item in input_iter {
+            if let Some(ctrlc) = &ctrlc {
+                if ctrlc.load(std::sync::atomic::Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            if let Value::Error { error } = item {
+                return Err(error);
+            }
+
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, item);
+                }
+            }
+
+            if let Some(var) = block.signature.get_positional(1) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, acc);
+                }
+            }
+
+            acc = eval_block(engine_state, &mut stack, block, PipelineData::new(span))?
+                .into_value(span);
+        }
+
+        Ok(acc.into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Reduce {})
+    }
+}
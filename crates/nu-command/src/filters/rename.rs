@@ -0,0 +1,145 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Rename;
+
+impl Command for Rename {
+    fn name(&self) -> &str {
+        "rename"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rename")
+            .named(
+                "column",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "column name to be changed, followed by its new name",
+                Some('c'),
+            )
+            .rest("rest", SyntaxShape::String, "the new names for the columns")
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a new table with columns renamed."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rename a column",
+                example: "[[a, b]; [1, 2]] | rename my_column",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["my_column".to_string(), "b".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Rename many columns",
+                example: "[[a, b, c]; [1, 2, 3]] | rename eggs ham bacon",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["eggs".to_string(), "ham".to_string(), "bacon".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Rename a specific column",
+                example: "[[a, b, c]; [1, 2, 3]] | rename -c [a ham]",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["ham".to_string(), "b".to_string(), "c".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let specific_column: Option<Vec<String>> = call.get_flag(engine_state, stack, "column")?;
+        let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+
+        let renamer = match specific_column {
+            Some(pair) if pair.len() == 2 => Renamer::Specific(pair[0].clone(), pair[1].clone()),
+            Some(_) => {
+                return Err(ShellError::UnsupportedInput(
+                    "The list must contain exactly two values: the column and its new name".into(),
+                    call.get_flag_expr("column")
+                        .map(|expr| expr.span)
+                        .unwrap_or(call.head),
+                ))
+            }
+            None if columns.is_empty() => {
+                return Err(ShellError::MissingParameter("rest".into(), call.head))
+            }
+            None => Renamer::Positional(columns),
+        };
+
+        input.map(
+            move |item| match item {
+                Value::Record {
+                    mut cols,
+                    vals,
+                    span,
+                } => {
+                    match &renamer {
+                        Renamer::Specific(from, to) => {
+                            for col in cols.iter_mut() {
+                                if col == from {
+                                    *col = to.clone();
+                                }
+                            }
+                        }
+                        Renamer::Positional(columns) => {
+                            for (col, new_name) in cols.iter_mut().zip(columns.iter()) {
+                                *col = new_name.clone();
+                            }
+                        }
+                    }
+
+                    Value::Record { cols, vals, span }
+                }
+                x => x,
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+}
+
+enum Renamer {
+    Specific(String, String),
+    Positional(Vec<String>),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Rename {})
+    }
+}
@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct SortBy;
+
+impl Command for SortBy {
+    fn name(&self) -> &str {
+        "sort-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sort-by")
+            .rest("columns", SyntaxShape::String, "the column(s) to sort by")
+            .switch("reverse", "Sort in reverse order", Some('r'))
+            .switch(
+                "insensitive",
+                "Sort string-based columns case-insensitively",
+                Some('i'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Sort by the given columns, in increasing order."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Sort a list of values",
+                example: "[2 0 1] | sort-by",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1), Value::test_int(2)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Sort a list of values in reverse order",
+                example: "[2 0 1] | sort-by -r",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(2), Value::test_int(1), Value::test_int(0)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Sort strings case-insensitively",
+                example: "['banana' 'Apple' 'cherry'] | sort-by -i",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("Apple"),
+                        Value::test_string("banana"),
+                        Value::test_string("cherry"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Sort a table by a column",
+                example: "[[name size]; [b 2] [a 3]] | sort-by size",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["name".to_string(), "size".to_string()],
+                            vals: vec![Value::test_string("b"), Value::test_int(2)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["name".to_string(), "size".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_int(3)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let columns: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+        let reverse = call.has_flag("reverse");
+        let insensitive = call.has_flag("insensitive");
+
+        let mut vec: Vec<_> = input.into_iter().collect();
+
+        sort(&mut vec, &columns, insensitive)?;
+
+        if reverse {
+            vec.reverse()
+        }
+
+        Ok(vec
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+/// Sort the values in place, either directly or by the given record columns
+pub fn sort(
+    vec: &mut [Value],
+    columns: &[Spanned<String>],
+    insensitive: bool,
+) -> Result<(), ShellError> {
+    // Make sure every row has the columns we are sorting by before doing any work
+    for column in columns {
+        for item in vec.iter() {
+            if let Value::Error { error } = item {
+                return Err(error.clone());
+            }
+
+            if !item.columns().contains(&column.item) {
+                return Err(ShellError::CantFindColumn(column.span, item.span()?));
+            }
+        }
+    }
+
+    // The sort is stable, so rows that compare as equal (including values of types that
+    // can't be compared to each other) keep their original order
+    vec.sort_by(|a, b| {
+        if columns.is_empty() {
+            compare_values(a, b, insensitive)
+        } else {
+            columns
+                .iter()
+                .map(|column| {
+                    let lhs = a.get_data_by_key(&column.item);
+                    let rhs = b.get_data_by_key(&column.item);
+
+                    match (lhs, rhs) {
+                        (Some(lhs), Some(rhs)) => compare_values(&lhs, &rhs, insensitive),
+                        _ => Ordering::Equal,
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        }
+    });
+
+    Ok(())
+}
+
+fn compare_values(lhs: &Value, rhs: &Value, insensitive: bool) -> Ordering {
+    match (lhs, rhs) {
+        (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) if insensitive => {
+            lhs.to_lowercase().cmp(&rhs.to_lowercase())
+        }
+        // Dates are sorted by the instant they point to, not only by their day
+        (Value::Date { val: lhs, .. }, Value::Date { val: rhs, .. }) => lhs.cmp(rhs),
+        // Records with the same columns are sorted by their values, from the first column on
+        (
+            Value::Record {
+                cols: lhs_cols,
+                vals: lhs,
+                ..
+            },
+            Value::Record {
+                cols: rhs_cols,
+                vals: rhs,
+                ..
+            },
+        ) if lhs_cols == rhs_cols => lhs
+            .iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| compare_values(lhs, rhs, insensitive))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal),
+        (lhs, rhs) => lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SortBy {})
+    }
+}
@@ -0,0 +1,225 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Transpose;
+
+impl Command for Transpose {
+    fn name(&self) -> &str {
+        "transpose"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("transpose")
+            .switch(
+                "header-row",
+                "treat the first column as the names of the new columns",
+                Some('r'),
+            )
+            .switch(
+                "ignore-titles",
+                "don't include the original column names as the first column",
+                Some('i'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "the names to give the new columns",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Transposes the table contents so rows become columns and columns become rows."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Transpose a table",
+                example: "[[a b]; [1 2] [3 4]] | transpose",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec![
+                                "Column0".to_string(),
+                                "Column1".to_string(),
+                                "Column2".to_string(),
+                            ],
+                            vals: vec![
+                                Value::test_string("a"),
+                                Value::test_int(1),
+                                Value::test_int(3),
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec![
+                                "Column0".to_string(),
+                                "Column1".to_string(),
+                                "Column2".to_string(),
+                            ],
+                            vals: vec![
+                                Value::test_string("b"),
+                                Value::test_int(2),
+                                Value::test_int(4),
+                            ],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Transpose a record into a key/value table with custom column names",
+                example: "{'a': 1, 'b': 2} | transpose key value",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["key".to_string(), "value".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_int(1)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["key".to_string(), "value".to_string()],
+                            vals: vec![Value::test_string("b"), Value::test_int(2)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Use the first column as the new headers",
+                example: "[[name value]; [x 1] [y 2]] | transpose -r",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["x".to_string(), "y".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        transpose(engine_state, stack, call, input)
+    }
+}
+
+pub fn transpose(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let header_row = call.has_flag("header-row");
+    let ignore_titles = call.has_flag("ignore-titles") || header_row;
+    let names: Vec<String> = call.rest(engine_state, stack, 0)?;
+    let config = stack.get_config().unwrap_or_default();
+
+    let rows: Vec<Value> = input.into_iter().collect();
+
+    // All the columns seen in the input, in the order they first appear
+    let mut descs: Vec<String> = vec![];
+    for row in &rows {
+        match row {
+            Value::Record { cols, .. } => {
+                for col in cols {
+                    if !descs.contains(col) {
+                        descs.push(col.clone());
+                    }
+                }
+            }
+            Value::Error { error } => return Err(error.clone()),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("expected a table or record, found {}", other.get_type()),
+                    other.span()?,
+                ))
+            }
+        }
+    }
+
+    let header_column = descs.first().cloned().unwrap_or_default();
+    let descs: Vec<String> = if header_row {
+        descs.into_iter().skip(1).collect()
+    } else {
+        descs
+    };
+
+    let mut headers = vec![];
+    if !ignore_titles {
+        headers.push("Column0".to_string());
+    }
+    for row in &rows {
+        if header_row {
+            let header = row
+                .get_data_by_key(&header_column)
+                .map(|header| header.into_string("", &config))
+                .unwrap_or_default();
+            headers.push(header);
+        } else {
+            headers.push(format!("Column{}", headers.len()));
+        }
+    }
+
+    for (header, name) in headers.iter_mut().zip(names) {
+        *header = name;
+    }
+
+    let output: Vec<Value> = descs
+        .into_iter()
+        .map(|desc| {
+            let mut vals = vec![];
+
+            if !ignore_titles {
+                vals.push(Value::String {
+                    val: desc.clone(),
+                    span,
+                });
+            }
+
+            for row in &rows {
+                vals.push(
+                    row.get_data_by_key(&desc)
+                        .unwrap_or(Value::Nothing { span }),
+                );
+            }
+
+            Value::Record {
+                cols: headers.clone(),
+                vals,
+                span,
+            }
+        })
+        .collect();
+
+    Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Transpose {})
+    }
+}
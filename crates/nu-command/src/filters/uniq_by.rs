@@ -0,0 +1,102 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct UniqBy;
+
+impl Command for UniqBy {
+    fn name(&self) -> &str {
+        "uniq-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("uniq-by")
+            .rest(
+                "columns",
+                SyntaxShape::String,
+                "the column(s) to compare rows by",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Return the rows that are distinct in the given columns, keeping the first of each."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Keep the first row for each distinct value of a column",
+            example: "[[fruit count]; [apple 9] [apple 2] [pear 3]] | uniq-by fruit",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::Record {
+                        cols: vec!["fruit".to_string(), "count".to_string()],
+                        vals: vec![Value::test_string("apple"), Value::test_int(9)],
+                        span: Span::unknown(),
+                    },
+                    Value::Record {
+                        cols: vec!["fruit".to_string(), "count".to_string()],
+                        vals: vec![Value::test_string("pear"), Value::test_int(3)],
+                        span: Span::unknown(),
+                    },
+                ],
+                span: Span::unknown(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let columns: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+
+        if columns.is_empty() {
+            return Err(ShellError::MissingParameter("columns".into(), call.head));
+        }
+
+        let mut seen: Vec<Vec<Value>> = vec![];
+        let mut output = vec![];
+
+        for value in input.into_iter() {
+            if let Value::Error { error } = value {
+                return Err(error);
+            }
+
+            let mut key = vec![];
+            for column in &columns {
+                match value.get_data_by_key(&column.item) {
+                    Some(found) => key.push(found),
+                    None => return Err(ShellError::CantFindColumn(column.span, value.span()?)),
+                }
+            }
+
+            if !seen.contains(&key) {
+                seen.push(key);
+                output.push(value);
+            }
+        }
+
+        Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(UniqBy {})
+    }
+}
@@ -3,7 +3,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, Signature,
-    SyntaxShape, Value,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -21,6 +21,11 @@ impl Command for Wrap {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("wrap")
             .required("name", SyntaxShape::String, "the name of the column")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Unknown)), Type::Table),
+                (Type::Table, Type::Table),
+                (Type::Unknown, Type::Record(vec![])),
+            ])
             .category(Category::Filters)
     }
 
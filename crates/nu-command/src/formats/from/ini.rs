@@ -2,7 +2,8 @@ use indexmap::map::IndexMap;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type,
+    Value,
 };
 
 #[derive(Clone)]
@@ -14,7 +15,9 @@ impl Command for FromIni {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from ini").category(Category::Formats)
+        Signature::build("from ini")
+            .category(Category::Formats)
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
     }
 
     fn usage(&self) -> &str {
@@ -4,7 +4,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
-    SyntaxShape, Value,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -31,6 +31,7 @@ impl Command for FromSsv {
                 "the minimum spaces to separate columns",
                 Some('m'),
             )
+            .input_output_types(vec![(Type::String, Type::Table)])
             .category(Category::Formats)
     }
 
@@ -2,7 +2,7 @@ use super::delimited::from_delimited_data;
 
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Config, PipelineData, ShellError, Signature};
+use nu_protocol::{Category, Config, PipelineData, ShellError, Signature, Type};
 
 #[derive(Clone)]
 pub struct FromTsv;
@@ -19,6 +19,7 @@ impl Command for FromTsv {
                 "don't treat the first row as column names",
                 Some('n'),
             )
+            .input_output_types(vec![(Type::String, Type::Table)])
             .category(Category::Formats)
     }
 
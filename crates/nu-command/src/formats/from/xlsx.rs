@@ -4,7 +4,7 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use std::io::Cursor;
 
@@ -24,6 +24,7 @@ impl Command for FromXlsx {
                 "Only convert specified sheets",
                 Some('s'),
             )
+            .input_output_types(vec![(Type::Binary, Type::Record(vec![]))])
             .category(Category::Formats)
     }
 
@@ -3,7 +3,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, Value,
+    Spanned, Type, Value,
 };
 
 #[derive(Clone)]
@@ -15,7 +15,9 @@ impl Command for FromXml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from xml").category(Category::Formats)
+        Signature::build("from xml")
+            .category(Category::Formats)
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
     }
 
     fn usage(&self) -> &str {
@@ -1,7 +1,7 @@
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
 
 #[derive(Clone)]
@@ -13,7 +13,9 @@ impl Command for ToJson {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to json").category(Category::Formats)
+        Signature::build("to json")
+            .input_output_types(vec![(Type::Unknown, Type::String)])
+            .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
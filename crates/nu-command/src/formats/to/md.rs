@@ -3,7 +3,8 @@ use indexmap::map::IndexMap;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type,
+    Value,
 };
 
 #[derive(Clone)]
@@ -26,6 +27,7 @@ impl Command for ToMd {
                 "treat each row as markdown syntax element",
                 Some('e'),
             )
+            .input_output_types(vec![(Type::Unknown, Type::String)])
             .category(Category::Formats)
     }
 
@@ -13,7 +13,12 @@ impl Command for ToToml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to toml").category(Category::Formats)
+        Signature::build("to toml")
+            .category(Category::Formats)
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::String),
+                (Type::Table, Type::String),
+            ])
     }
 
     fn usage(&self) -> &str {
@@ -1,7 +1,9 @@
 use crate::formats::to::delimited::to_delimited_data;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Config, Example, PipelineData, ShellError, Signature, Span, Value};
+use nu_protocol::{
+    Category, Config, Example, PipelineData, ShellError, Signature, Span, Type, Value,
+};
 
 #[derive(Clone)]
 pub struct ToTsv;
@@ -18,6 +20,10 @@ impl Command for ToTsv {
                 "do not output the column names as the first row",
                 Some('n'),
             )
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::String),
+                (Type::Table, Type::String),
+            ])
             .category(Category::Formats)
     }
 
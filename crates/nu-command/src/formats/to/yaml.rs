@@ -1,7 +1,7 @@
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 
 #[derive(Clone)]
@@ -13,7 +13,9 @@ impl Command for ToYaml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to yaml").category(Category::Formats)
+        Signature::build("to yaml")
+            .category(Category::Formats)
+            .input_output_types(vec![(Type::Unknown, Type::String)])
     }
 
     fn usage(&self) -> &str {
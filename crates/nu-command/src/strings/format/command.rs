@@ -2,7 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value, ValueStream,
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    ValueStream,
 };
 
 #[derive(Clone)]
@@ -20,6 +21,10 @@ impl Command for Format {
                 SyntaxShape::String,
                 "the pattern to output. e.g.) \"{foo}: {bar}\"",
             )
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::String),
+                (Type::Table, Type::List(Box::new(Type::String))),
+            ])
             .category(Category::Strings)
     }
 
@@ -4,7 +4,7 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Value};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
 
 #[derive(Clone)]
 pub struct Size;
@@ -15,7 +15,12 @@ impl Command for Size {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("size").category(Category::Strings)
+        Signature::build("size")
+            .input_output_types(vec![
+                (Type::String, Type::Record(vec![])),
+                (Type::List(Box::new(Type::String)), Type::Table),
+            ])
+            .category(Category::Strings)
     }
 
     fn usage(&self) -> &str {
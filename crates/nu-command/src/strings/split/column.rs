@@ -2,7 +2,7 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+    Category, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -26,6 +26,10 @@ impl Command for SubCommand {
                 SyntaxShape::String,
                 "column names to give the new columns",
             )
+            .input_output_types(vec![
+                (Type::String, Type::Table),
+                (Type::List(Box::new(Type::String)), Type::Table),
+            ])
             .category(Category::Strings)
     }
 
@@ -3,7 +3,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Value,
+    Type, Value,
 };
 
 #[derive(Clone)]
@@ -21,6 +21,7 @@ impl Command for StrCollect {
                 SyntaxShape::String,
                 "optional separator to use when creating string",
             )
+            .input_output_types(vec![(Type::Unknown, Type::String)])
             .category(Category::Strings)
     }
 
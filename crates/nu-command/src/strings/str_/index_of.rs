@@ -4,7 +4,7 @@ use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::Spanned;
-use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
 use std::sync::Arc;
 
 struct Arguments {
@@ -44,6 +44,13 @@ impl Command for SubCommand {
                 Some('r'),
             )
             .switch("end", "search from the end of the string", Some('e'))
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Int)),
+                ),
+            ])
             .category(Category::Strings)
     }
 
@@ -0,0 +1,100 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, DataSource, Example, ExternalExit, IntoPipelineData, PipelineData, PipelineMetadata,
+    ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Complete;
+
+impl Command for Complete {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("complete").category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Complete the external piped in, collecting outputs and exit code."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        match input {
+            PipelineData::Stream(
+                stream,
+                Some(PipelineMetadata {
+                    data_source: DataSource::External(exit),
+                }),
+            ) => {
+                let mut stdout = vec![];
+                for value in stream {
+                    match value {
+                        Value::String { val, .. } => stdout.extend(val.as_bytes()),
+                        Value::Binary { val, .. } => stdout.extend(val),
+                        Value::Error { error } => return Err(error),
+                        _ => {}
+                    }
+                }
+
+                // The exit status is sent once the stdout is drained
+                let ExternalExit { stderr, exit_code } = exit.recv().map_err(|_| {
+                    ShellError::ExternalCommand(
+                        "Failed to get the exit code of the external".into(),
+                        span,
+                    )
+                })?;
+
+                stack.add_env_var(
+                    "LAST_EXIT_CODE".into(),
+                    Value::Int {
+                        val: exit_code,
+                        span,
+                    },
+                );
+
+                Ok(Value::Record {
+                    cols: vec!["stdout".into(), "stderr".into(), "exit_code".into()],
+                    vals: vec![
+                        Value::String {
+                            val: String::from_utf8_lossy(&stdout).to_string(),
+                            span,
+                        },
+                        Value::String {
+                            val: stderr.unwrap_or_default(),
+                            span,
+                        },
+                        Value::Int {
+                            val: exit_code,
+                            span,
+                        },
+                    ],
+                    span,
+                }
+                .into_pipeline_data())
+            }
+            _ => Err(ShellError::UnsupportedInput(
+                "complete only works on the output of an external command".into(),
+                span,
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Run the external completion",
+            example: "^external arg1 | complete",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,117 @@
+use super::{history_path, CommandHistory, HistoryFilter};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape,
+};
+
+#[derive(Clone)]
+pub struct History;
+
+impl Command for History {
+    fn name(&self) -> &str {
+        "history"
+    }
+
+    fn usage(&self) -> &str {
+        "Get the command history."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each entry has the command, the directory it ran in, when it started, how long it took, the machine it ran on and its exit status. Entries imported from history.txt only have their command. The up arrow recalls from the same history; with `history_cwd_recall` set in the config it only recalls the commands run in the current directory, like `history --cwd` lists them."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("history")
+            .switch(
+                "cwd",
+                "only the commands run in the current directory",
+                Some('c'),
+            )
+            .named(
+                "search",
+                SyntaxShape::String,
+                "only the commands containing this text",
+                Some('s'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let cwd = if call.has_flag("cwd") {
+            Some(current_dir(stack)?)
+        } else {
+            None
+        };
+        let filter = HistoryFilter {
+            cwd,
+            search: call.get_flag(engine_state, stack, "search")?,
+        };
+
+        // Without a history file there is no history yet
+        let entries = match history_path() {
+            Some(path) if path.exists() => CommandHistory::open(&path)?.entries(&filter)?,
+            _ => vec![],
+        };
+
+        Ok(entries
+            .into_iter()
+            .map(move |entry| entry.into_value(head))
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the command history",
+                example: "history",
+                result: None,
+            },
+            Example {
+                description: "Get the commands run in the current directory",
+                example: "history --cwd",
+                result: None,
+            },
+            Example {
+                description: "Get the commands containing 'git'",
+                example: "history --search git",
+                result: None,
+            },
+            Example {
+                description: "Get the commands that failed",
+                example: "history | where exit_status != 0",
+                result: None,
+            },
+        ]
+    }
+}
+
+// The entries are saved with the PWD they ran in
+fn current_dir(stack: &Stack) -> Result<String, ShellError> {
+    match stack.get_env_var("PWD") {
+        Some(pwd) => pwd.as_string(),
+        None => Ok(std::env::current_dir()?.to_string_lossy().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(History {})
+    }
+}
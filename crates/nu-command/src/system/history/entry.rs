@@ -0,0 +1,71 @@
+use chrono::{DateTime, FixedOffset};
+use nu_protocol::{Span, Value};
+use serde::{Deserialize, Serialize};
+
+/// A line run in the REPL, with how and where it ran. Entries imported from the old text history
+/// only have their command.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub command: String,
+    pub cwd: Option<String>,
+    pub start_timestamp: Option<DateTime<FixedOffset>>,
+    pub duration_ms: Option<u64>,
+    pub hostname: Option<String>,
+    pub exit_status: Option<i64>,
+}
+
+impl HistoryEntry {
+    pub(super) fn into_value(self, span: Span) -> Value {
+        let or_nothing = |value: Option<Value>| value.unwrap_or(Value::Nothing { span });
+
+        Value::Record {
+            cols: vec![
+                "command".into(),
+                "cwd".into(),
+                "start_timestamp".into(),
+                "duration".into(),
+                "hostname".into(),
+                "exit_status".into(),
+            ],
+            vals: vec![
+                Value::String {
+                    val: self.command,
+                    span,
+                },
+                or_nothing(self.cwd.map(|val| Value::String { val, span })),
+                or_nothing(self.start_timestamp.map(|val| Value::Date { val, span })),
+                or_nothing(self.duration_ms.map(|duration_ms| Value::Duration {
+                    val: duration_ms as i64 * 1_000_000,
+                    span,
+                })),
+                or_nothing(self.hostname.map(|val| Value::String { val, span })),
+                or_nothing(self.exit_status.map(|val| Value::Int { val, span })),
+            ],
+            span,
+        }
+    }
+}
+
+/// Which entries to get from the history
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    /// Only the entries run in this directory
+    pub cwd: Option<String>,
+    /// Only the entries whose command contains this text
+    pub search: Option<String>,
+}
+
+impl HistoryFilter {
+    pub(super) fn matches(&self, entry: &HistoryEntry) -> bool {
+        let in_cwd = match &self.cwd {
+            Some(cwd) => entry.cwd.as_ref() == Some(cwd),
+            None => true,
+        };
+        let found = match &self.search {
+            Some(search) => entry.command.contains(search.as_str()),
+            None => true,
+        };
+
+        in_cwd && found
+    }
+}
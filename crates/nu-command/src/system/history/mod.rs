@@ -0,0 +1,41 @@
+mod command;
+mod entry;
+#[cfg(feature = "sqlite-history")]
+mod sqlite_history;
+mod text_history;
+
+pub use command::History;
+pub use entry::{HistoryEntry, HistoryFilter};
+#[cfg(feature = "sqlite-history")]
+pub use sqlite_history::SqliteHistory;
+pub use text_history::TextHistory;
+
+use std::path::PathBuf;
+
+/// Where the history is kept, which is a SQLite database unless nushell is built without the
+/// `sqlite-history` feature
+#[cfg(feature = "sqlite-history")]
+pub type CommandHistory = SqliteHistory;
+#[cfg(not(feature = "sqlite-history"))]
+pub type CommandHistory = TextHistory;
+
+#[cfg(feature = "sqlite-history")]
+const HISTORY_FILE: &str = "history.sqlite3";
+#[cfg(not(feature = "sqlite-history"))]
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// The file the history is kept in
+pub fn history_path() -> Option<PathBuf> {
+    nu_path::config_dir().map(|mut path| {
+        path.push("nushell");
+        path.push(HISTORY_FILE);
+        path
+    })
+}
+
+/// The name of this machine, saved with each history entry
+pub fn hostname() -> Option<String> {
+    use sysinfo::{System, SystemExt};
+
+    System::new().host_name()
+}
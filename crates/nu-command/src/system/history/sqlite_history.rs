@@ -0,0 +1,316 @@
+use super::entry::{HistoryEntry, HistoryFilter};
+use super::text_history::read_json_history;
+use chrono::DateTime;
+use nu_protocol::ShellError;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+// reedline kept multi-line entries on one line of history.txt, with this in place of newlines
+const TEXT_HISTORY_NEWLINE: &str = "<\\n>";
+
+// The metadata key recording when the old text history was imported
+const OLD_HISTORY_IMPORTED: &str = "old_history_imported";
+
+/// The command history, kept in a SQLite database
+pub struct SqliteHistory {
+    connection: Connection,
+}
+
+impl SqliteHistory {
+    /// Open the history file, creating it if needed. A new history starts with the entries of
+    /// the old text history in the same directory, which are only ever imported once.
+    pub fn open(path: &Path) -> Result<Self, ShellError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let connection = Connection::open(path).map_err(history_error)?;
+        let mut history = Self::with_connection(connection)?;
+
+        if history.metadata(OLD_HISTORY_IMPORTED)?.is_none() {
+            if let Some(dir) = path.parent() {
+                history.import_old_history(dir)?;
+            }
+        }
+
+        Ok(history)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self, ShellError> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS history (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    command TEXT NOT NULL,
+                    cwd TEXT,
+                    start_timestamp TEXT,
+                    duration_ms INTEGER,
+                    hostname TEXT,
+                    exit_status INTEGER
+                );
+                CREATE INDEX IF NOT EXISTS history_cwd ON history (cwd);
+                CREATE TABLE IF NOT EXISTS metadata (
+                    key TEXT PRIMARY KEY,
+                    value TEXT NOT NULL
+                );",
+            )
+            .map_err(history_error)?;
+
+        Ok(Self { connection })
+    }
+
+    fn metadata(&self, key: &str) -> Result<Option<String>, ShellError> {
+        self.connection
+            .query_row("SELECT value FROM metadata WHERE key = ?1", [key], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(history_error)
+    }
+
+    pub fn append(&self, entry: &HistoryEntry) -> Result<(), ShellError> {
+        append_entry(&self.connection, entry)
+    }
+
+    /// The entries matching the filter, oldest first
+    pub fn entries(&self, filter: &HistoryFilter) -> Result<Vec<HistoryEntry>, ShellError> {
+        let mut conditions = vec![];
+        let mut values = vec![];
+
+        if let Some(cwd) = &filter.cwd {
+            conditions.push("cwd = ?");
+            values.push(cwd.as_str());
+        }
+        if let Some(search) = &filter.search {
+            conditions.push("instr(command, ?) > 0");
+            values.push(search.as_str());
+        }
+
+        let mut sql = "SELECT command, cwd, start_timestamp, duration_ms, hostname, exit_status \
+                       FROM history"
+            .to_string();
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY id");
+
+        let mut statement = self.connection.prepare(&sql).map_err(history_error)?;
+        let entries = statement
+            .query_map(params_from_iter(values), |row| {
+                let start_timestamp: Option<String> = row.get(2)?;
+                let duration_ms: Option<i64> = row.get(3)?;
+
+                Ok(HistoryEntry {
+                    command: row.get(0)?,
+                    cwd: row.get(1)?,
+                    start_timestamp: start_timestamp
+                        .and_then(|timestamp| DateTime::parse_from_rfc3339(&timestamp).ok()),
+                    duration_ms: duration_ms.map(|duration_ms| duration_ms as u64),
+                    hostname: row.get(4)?,
+                    exit_status: row.get(5)?,
+                })
+            })
+            .map_err(history_error)?;
+
+        entries
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(history_error)
+    }
+
+    /// The last `count` commands, oldest first, for the line editor to recall. With a `cwd`, only
+    /// the commands run in that directory.
+    pub fn recent_commands(
+        &self,
+        cwd: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<String>, ShellError> {
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT command FROM history
+                 WHERE ?1 IS NULL OR cwd = ?1
+                 ORDER BY id DESC LIMIT ?2",
+            )
+            .map_err(history_error)?;
+
+        let mut commands = statement
+            .query_map(params![cwd, count as i64], |row| row.get(0))
+            .map_err(history_error)?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(history_error)?;
+
+        commands.reverse();
+        Ok(commands)
+    }
+
+    // Brings in the plain lines of the history.txt the line editor used to keep, and the entries
+    // of the history.jsonl file the history was kept in before, or is kept in without SQLite
+    fn import_old_history(&mut self, dir: &Path) -> Result<(), ShellError> {
+        let mut entries = vec![];
+
+        if let Ok(file) = std::fs::File::open(dir.join("history.txt")) {
+            entries.extend(read_text_history(BufReader::new(file)));
+        }
+        if let Ok(file) = std::fs::File::open(dir.join("history.jsonl")) {
+            entries.extend(read_json_history(BufReader::new(file)));
+        }
+
+        self.import(&entries)
+    }
+
+    // The import is recorded with the entries, so clearing the history doesn't bring them back
+    fn import(&mut self, entries: &[HistoryEntry]) -> Result<(), ShellError> {
+        let transaction = self.connection.transaction().map_err(history_error)?;
+        for entry in entries {
+            append_entry(&transaction, entry)?;
+        }
+        transaction
+            .execute(
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES (?1, ?2)",
+                params![OLD_HISTORY_IMPORTED, chrono::Local::now().to_rfc3339()],
+            )
+            .map_err(history_error)?;
+        transaction.commit().map_err(history_error)
+    }
+}
+
+fn append_entry(connection: &Connection, entry: &HistoryEntry) -> Result<(), ShellError> {
+    connection
+        .execute(
+            "INSERT INTO history (command, cwd, start_timestamp, duration_ms, hostname, exit_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                entry.command,
+                entry.cwd,
+                entry
+                    .start_timestamp
+                    .map(|timestamp| timestamp.to_rfc3339()),
+                entry.duration_ms.map(|duration_ms| duration_ms as i64),
+                entry.hostname,
+                entry.exit_status,
+            ],
+        )
+        .map(|_| ())
+        .map_err(history_error)
+}
+
+fn read_text_history(reader: impl BufRead) -> Vec<HistoryEntry> {
+    reader
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| HistoryEntry {
+            command: line.replace(TEXT_HISTORY_NEWLINE, "\n"),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn history_error(err: rusqlite::Error) -> ShellError {
+    ShellError::LabeledError("History error".into(), err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn in_memory_history() -> SqliteHistory {
+        let connection = Connection::open_in_memory().expect("unable to open database");
+        SqliteHistory::with_connection(connection).expect("unable to create history")
+    }
+
+    fn entry(command: &str, cwd: &str) -> HistoryEntry {
+        HistoryEntry {
+            command: command.into(),
+            cwd: Some(cwd.into()),
+            start_timestamp: Some(
+                DateTime::parse_from_rfc3339("2021-12-01T10:00:00+01:00").expect("valid timestamp"),
+            ),
+            duration_ms: Some(12),
+            hostname: Some("nu-host".into()),
+            exit_status: Some(0),
+        }
+    }
+
+    #[test]
+    fn filters_entries() {
+        let history = in_memory_history();
+        let entries = vec![
+            entry("ls | length", "/home/nu"),
+            entry("cd projects", "/home/nu"),
+            entry("ls", "/home/nu/projects"),
+        ];
+        for entry in &entries {
+            history.append(entry).expect("unable to append entry");
+        }
+
+        assert_eq!(history.entries(&HistoryFilter::default()).unwrap(), entries);
+
+        let in_home = HistoryFilter {
+            cwd: Some("/home/nu".into()),
+            ..Default::default()
+        };
+        assert_eq!(history.entries(&in_home).unwrap(), entries[0..2].to_vec());
+
+        let ls_in_home = HistoryFilter {
+            cwd: Some("/home/nu".into()),
+            search: Some("ls".into()),
+        };
+        assert_eq!(
+            history.entries(&ls_in_home).unwrap(),
+            entries[0..1].to_vec()
+        );
+    }
+
+    #[test]
+    fn recalls_recent_commands() {
+        let history = in_memory_history();
+        for entry in &[
+            entry("ls | length", "/home/nu"),
+            entry("cd projects", "/home/nu"),
+            entry("ls", "/home/nu/projects"),
+            entry("git status", "/home/nu/projects"),
+        ] {
+            history.append(entry).expect("unable to append entry");
+        }
+
+        assert_eq!(
+            history.recent_commands(None, 3).unwrap(),
+            vec!["cd projects", "ls", "git status"]
+        );
+        assert_eq!(
+            history.recent_commands(Some("/home/nu"), 10).unwrap(),
+            vec!["ls | length", "cd projects"]
+        );
+    }
+
+    #[test]
+    fn imports_text_history() {
+        let mut history = in_memory_history();
+        let text = "ls\n\nif true {<\\n>  echo 1<\\n>}\n";
+
+        history
+            .import(&read_text_history(text.as_bytes()))
+            .expect("unable to import history");
+
+        let commands: Vec<String> = history
+            .entries(&HistoryFilter::default())
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.command)
+            .collect();
+        assert_eq!(commands, vec!["ls", "if true {\n  echo 1\n}"]);
+    }
+
+    #[test]
+    fn records_the_import() {
+        let mut history = in_memory_history();
+        assert_eq!(history.metadata(OLD_HISTORY_IMPORTED).unwrap(), None);
+
+        history.import(&[]).expect("unable to import history");
+
+        assert!(history.metadata(OLD_HISTORY_IMPORTED).unwrap().is_some());
+    }
+}
@@ -0,0 +1,117 @@
+use super::entry::{HistoryEntry, HistoryFilter};
+use nu_protocol::ShellError;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// The command history as plain text, one json object per line, for when nushell is built
+/// without SQLite
+pub struct TextHistory {
+    path: PathBuf,
+}
+
+impl TextHistory {
+    /// Open the history file, which is created when the first entry is added
+    pub fn open(path: &Path) -> Result<Self, ShellError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub fn append(&self, entry: &HistoryEntry) -> Result<(), ShellError> {
+        let line = serde_json::to_string(entry).map_err(|err| {
+            ShellError::LabeledError("Unable to save history".into(), err.to_string())
+        })?;
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|err| {
+                ShellError::LabeledError("Unable to save history".into(), err.to_string())
+            })
+    }
+
+    /// The entries matching the filter, oldest first
+    pub fn entries(&self, filter: &HistoryFilter) -> Result<Vec<HistoryEntry>, ShellError> {
+        Ok(self
+            .read_entries()
+            .into_iter()
+            .filter(|entry| filter.matches(entry))
+            .collect())
+    }
+
+    /// The last `count` commands, oldest first, for the line editor to recall. With a `cwd`, only
+    /// the commands run in that directory.
+    pub fn recent_commands(
+        &self,
+        cwd: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<String>, ShellError> {
+        let filter = HistoryFilter {
+            cwd: cwd.map(String::from),
+            search: None,
+        };
+
+        let mut commands: Vec<String> = self
+            .entries(&filter)?
+            .into_iter()
+            .rev()
+            .take(count)
+            .map(|entry| entry.command)
+            .collect();
+
+        commands.reverse();
+        Ok(commands)
+    }
+
+    // Without a history file there is no history yet
+    fn read_entries(&self) -> Vec<HistoryEntry> {
+        match std::fs::File::open(&self.path) {
+            Ok(file) => read_json_history(BufReader::new(file)),
+            Err(_) => vec![],
+        }
+    }
+}
+
+// Lines that can't be read, eg) one cut short by a crash, are left out
+pub(super) fn read_json_history(reader: impl BufRead) -> Vec<HistoryEntry> {
+    reader
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::DateTime;
+
+    #[test]
+    fn reads_json_history() {
+        let entry = HistoryEntry {
+            command: "ls | length".into(),
+            cwd: Some("/home/nu".into()),
+            start_timestamp: Some(
+                DateTime::parse_from_rfc3339("2021-12-01T10:00:00+01:00").expect("valid timestamp"),
+            ),
+            duration_ms: Some(12),
+            hostname: None,
+            exit_status: Some(0),
+        };
+
+        let line = serde_json::to_string(&entry).expect("unable to serialize entry");
+        let file = format!("{}\nnot an entry\n{}\n", line, line);
+
+        assert_eq!(
+            read_json_history(file.as_bytes()),
+            vec![entry.clone(), entry]
+        );
+    }
+}
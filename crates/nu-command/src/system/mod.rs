@@ -1,9 +1,17 @@
 mod benchmark;
+mod complete;
+mod history;
 mod ps;
 mod run_external;
 mod sys;
 
 pub use benchmark::Benchmark;
+pub use complete::Complete;
+#[cfg(feature = "sqlite-history")]
+pub use history::SqliteHistory;
+pub use history::{
+    history_path, hostname, CommandHistory, History, HistoryEntry, HistoryFilter, TextHistory,
+};
 pub use ps::Ps;
 pub use run_external::{External, ExternalCommand};
 pub use sys::Sys;
@@ -1,7 +1,8 @@
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Type,
+    Value,
 };
 use sysinfo::{ProcessExt, System, SystemExt};
 
@@ -22,6 +23,7 @@ impl Command for Ps {
                 Some('l'),
             )
             .filter()
+            .input_output_types(vec![(Type::Nothing, Type::Table)])
             .category(Category::System)
     }
 
@@ -1,19 +1,24 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::env;
-use std::io::{BufRead, BufReader, Write};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
-use std::process::{Command as CommandSys, Stdio};
-use std::sync::atomic::Ordering;
-use std::sync::mpsc;
+use std::process::{Child, ChildStdout, Command as CommandSys, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
 
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{ast::Call, engine::Command, ShellError, Signature, SyntaxShape, Value};
-use nu_protocol::{Category, Config, IntoInterruptiblePipelineData, PipelineData, Span, Spanned};
+use nu_protocol::{
+    Category, Config, DataSource, ExternalExit, PipelineData, PipelineMetadata, Span, Spanned,
+    ValueStream,
+};
 
 use itertools::Itertools;
 
-use nu_engine::CallExt;
+use nu_engine::{env_to_strings, CallExt};
 use regex::Regex;
 
 const OUTPUT_BUFFER_SIZE: usize = 8192;
@@ -37,6 +42,23 @@ impl Command for External {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("run_external")
             .switch("last_expression", "last_expression", None)
+            .switch(
+                "capture-stderr",
+                "capture stderr so it can be collected, eg) by complete",
+                None,
+            )
+            .named(
+                "out-file",
+                SyntaxShape::Filepath,
+                "redirect stdout to a file",
+                None,
+            )
+            .named(
+                "err-file",
+                SyntaxShape::Filepath,
+                "redirect stderr to a file",
+                None,
+            )
             .rest("rest", SyntaxShape::Any, "external command to run")
             .category(Category::System)
     }
@@ -51,9 +73,11 @@ impl Command for External {
         let mut name: Spanned<String> = call.req(engine_state, stack, 0)?;
         let args: Vec<String> = call.rest(engine_state, stack, 1)?;
         let last_expression = call.has_flag("last_expression");
-        let env_vars = stack.get_env_vars();
-
+        let capture_stderr = call.has_flag("capture-stderr");
+        let out_file: Option<Spanned<String>> = call.get_flag(engine_state, stack, "out-file")?;
+        let err_file: Option<Spanned<String>> = call.get_flag(engine_state, stack, "err-file")?;
         let config = stack.get_config().unwrap_or_default();
+        let env_vars = env_to_strings(engine_state, stack, &config)?;
 
         // Check if this is a single call to a directory, if so auto-cd
         let path = nu_path::expand_path(&name.item);
@@ -73,7 +97,13 @@ impl Command for External {
 
             //FIXME: this only changes the current scope, but instead this environment variable
             //should probably be a block that loads the information from the state in the overlay
-            stack.add_env_var("PWD".into(), name.item.clone());
+            stack.add_env_var(
+                "PWD".into(),
+                Value::String {
+                    val: name.item.clone(),
+                    span: call.head,
+                },
+            );
             return Ok(PipelineData::new(call.head));
         }
 
@@ -81,10 +111,13 @@ impl Command for External {
             name,
             args,
             last_expression,
+            capture_stderr,
+            out_file,
+            err_file,
             env_vars,
             call,
         };
-        command.run_with_input(engine_state, input, config)
+        command.run_with_input(engine_state, stack, input, config)
     }
 }
 
@@ -92,6 +125,9 @@ pub struct ExternalCommand<'call> {
     pub name: Spanned<String>,
     pub args: Vec<String>,
     pub last_expression: bool,
+    pub capture_stderr: bool,
+    pub out_file: Option<Spanned<String>>,
+    pub err_file: Option<Spanned<String>>,
     pub env_vars: HashMap<String, String>,
     pub call: &'call Call,
 }
@@ -100,6 +136,7 @@ impl<'call> ExternalCommand<'call> {
     pub fn run_with_input(
         &self,
         engine_state: &EngineState,
+        stack: &mut Stack,
         input: PipelineData,
         config: Config,
     ) -> Result<PipelineData, ShellError> {
@@ -115,12 +152,35 @@ impl<'call> ExternalCommand<'call> {
 
         process.envs(&self.env_vars);
 
+        // Redirecting to a file takes priority over piping the output. When both outputs go to the
+        // same file, they share the handle so that they don't overwrite each other
+        let out_file = match &self.out_file {
+            Some(out_file) => Some(open_redirection(out_file)?),
+            None => None,
+        };
+        let err_file = match (&self.err_file, &self.out_file, &out_file) {
+            (Some(err_name), Some(out_name), Some(out_file)) if err_name.item == out_name.item => {
+                Some(out_file.try_clone()?)
+            }
+            (Some(err_name), ..) => Some(open_redirection(err_name)?),
+            _ => None,
+        };
+        let stdout_piped = out_file.is_none() && !self.last_expression;
+
         // If the external is not the last command, its output will get piped
         // either as a string or binary
-        if !self.last_expression {
+        if let Some(out_file) = out_file {
+            process.stdout(out_file);
+        } else if stdout_piped {
             process.stdout(Stdio::piped());
         }
 
+        if let Some(err_file) = err_file {
+            process.stderr(err_file);
+        } else if self.capture_stderr {
+            process.stderr(Stdio::piped());
+        }
+
         // If there is an input from the pipeline. The stdin from the process
         // is piped so it can be used to send the input information
         if !matches!(input, PipelineData::Value(Value::Nothing { .. }, ..)) {
@@ -162,65 +222,72 @@ impl<'call> ExternalCommand<'call> {
                     });
                 }
 
-                let last_expression = self.last_expression;
                 let span = self.name.span;
-                let output_ctrlc = ctrlc.clone();
-                let (tx, rx) = mpsc::channel();
-
-                std::thread::spawn(move || {
-                    // If this external is not the last expression, then its output is piped to a channel
-                    // and we create a ValueStream that can be consumed
-                    if !last_expression {
-                        let stdout = child.stdout.take().ok_or_else(|| {
-                            ShellError::ExternalCommand(
-                                "Error taking stdout from external".to_string(),
-                                span,
-                            )
-                        })?;
-
-                        // Stdout is read using the Buffer reader. It will do so until there is an
-                        // error or there are no more bytes to read
-                        let mut buf_read = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, stdout);
-                        while let Ok(bytes) = buf_read.fill_buf() {
-                            if bytes.is_empty() {
-                                break;
-                            }
-
-                            // The Cow generated from the function represents the conversion
-                            // from bytes to String. If no replacements are required, then the
-                            // borrowed value is a proper UTF-8 string. The Owned option represents
-                            // a string where the values had to be replaced, thus marking it as bytes
-                            let data = match String::from_utf8_lossy(bytes) {
-                                Cow::Borrowed(s) => Data::String(s.into()),
-                                Cow::Owned(_) => Data::Bytes(bytes.to_vec()),
-                            };
-
-                            let length = bytes.len();
-                            buf_read.consume(length);
-
-                            if let Some(ctrlc) = &ctrlc {
-                                if ctrlc.load(Ordering::SeqCst) {
-                                    break;
-                                }
-                            }
 
-                            match tx.send(data) {
-                                Ok(_) => continue,
-                                Err(_) => break,
-                            }
+                // The output goes straight to the terminal or a file, so there is nothing to
+                // stream and we can wait for the exit code here
+                if !stdout_piped && !self.capture_stderr {
+                    let exit_code = match child.wait() {
+                        Err(err) => {
+                            return Err(ShellError::ExternalCommand(format!("{}", err), span))
                         }
-                    }
+                        Ok(status) => status.code().unwrap_or(-1) as i64,
+                    };
+
+                    stack.add_env_var(
+                        "LAST_EXIT_CODE".into(),
+                        Value::Int {
+                            val: exit_code,
+                            span,
+                        },
+                    );
+
+                    return Ok(PipelineData::new(span));
+                }
 
-                    match child.wait() {
-                        Err(err) => Err(ShellError::ExternalCommand(format!("{}", err), span)),
-                        Ok(_) => Ok(()),
-                    }
+                // Stderr is read on its own thread so a full stderr pipe can't block the stdout
+                let stderr_reader = child.stderr.take().map(|mut stderr| {
+                    std::thread::spawn(move || {
+                        let mut buf = vec![];
+                        let _ = stderr.read_to_end(&mut buf);
+                        String::from_utf8_lossy(&buf).to_string()
+                    })
                 });
-                // The ValueStream is consumed by the next expression in the pipeline
-                let value =
-                    ChannelReceiver::new(rx, self.name.span).into_pipeline_data(output_ctrlc);
 
-                Ok(value)
+                let (exit_tx, exit_rx) = mpsc::channel();
+
+                // If this external is not the last expression, then its output is read as the
+                // ValueStream is consumed by the next expression in the pipeline
+                let stdout = if stdout_piped {
+                    let stdout = child.stdout.take().ok_or_else(|| {
+                        ShellError::ExternalCommand(
+                            "Error taking stdout from external".to_string(),
+                            span,
+                        )
+                    })?;
+
+                    Some(BufReader::with_capacity(OUTPUT_BUFFER_SIZE, stdout))
+                } else {
+                    None
+                };
+
+                let output = ExternalOutput {
+                    stdout,
+                    running: Some(RunningExternal {
+                        child,
+                        stderr_reader,
+                        exit_tx,
+                    }),
+                    ctrlc: ctrlc.clone(),
+                    span,
+                };
+
+                Ok(PipelineData::Stream(
+                    ValueStream::from_stream(output, ctrlc),
+                    Some(PipelineMetadata {
+                        data_source: DataSource::External(exit_rx),
+                    }),
+                ))
             }
         }
     }
@@ -285,6 +352,16 @@ impl<'call> ExternalCommand<'call> {
     }
 }
 
+fn open_redirection(path: &Spanned<String>) -> Result<File, ShellError> {
+    File::create(nu_path::expand_path(&path.item)).map_err(|err| {
+        ShellError::SpannedLabeledError(
+            "Failed to open file for redirection".into(),
+            err.to_string(),
+            path.span,
+        )
+    })
+}
+
 fn has_unsafe_shell_characters(arg: &str) -> bool {
     let re: Regex = Regex::new(r"[^\w@%+=:,./-]").expect("regex to be valid");
 
@@ -312,43 +389,105 @@ fn trim_enclosing_quotes(input: &str) -> String {
     }
 }
 
-// The piped data from stdout from the external command can be either String
-// or binary. We use this enum to pass the data from the spawned process
-#[derive(Debug)]
-enum Data {
-    String(String),
-    Bytes(Vec<u8>),
+// An external whose exit code hasn't been sent yet
+struct RunningExternal {
+    child: Child,
+    stderr_reader: Option<JoinHandle<String>>,
+    exit_tx: mpsc::Sender<ExternalExit>,
+}
+
+impl RunningExternal {
+    // The wait happens on its own thread, so only whoever reads the exit code waits for it
+    fn send_exit(self) {
+        let RunningExternal {
+            mut child,
+            stderr_reader,
+            exit_tx,
+        } = self;
+
+        std::thread::spawn(move || {
+            let stderr = stderr_reader.and_then(|reader| reader.join().ok());
+
+            let exit_code = match child.wait() {
+                Ok(status) => status.code().unwrap_or(-1) as i64,
+                Err(_) => -1,
+            };
+
+            let _ = exit_tx.send(ExternalExit { stderr, exit_code });
+        });
+    }
 }
 
-// Receiver used for the ValueStream
-// It implements iterator so it can be used as a ValueStream
-struct ChannelReceiver {
-    rx: mpsc::Receiver<Data>,
+// The stdout of an external, read as the ValueStream is consumed. Once it is dropped, eg) by
+// `first`, the process sees its stdout closed like it would in a pipe between two processes, so
+// one that keeps writing ends instead of leaving whoever waits for its exit code hanging
+struct ExternalOutput {
+    stdout: Option<BufReader<ChildStdout>>,
+    running: Option<RunningExternal>,
+    ctrlc: Option<Arc<AtomicBool>>,
     span: Span,
 }
 
-impl ChannelReceiver {
-    pub fn new(rx: mpsc::Receiver<Data>, span: Span) -> Self {
-        Self { rx, span }
+impl ExternalOutput {
+    fn finish(&mut self) {
+        // The stdout is closed before waiting for the process
+        self.stdout = None;
+
+        if let Some(running) = self.running.take() {
+            running.send_exit();
+        }
+    }
+
+    fn read(&mut self) -> Option<Value> {
+        if let Some(ctrlc) = &self.ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                return None;
+            }
+        }
+
+        let stdout = self.stdout.as_mut()?;
+        let bytes = match stdout.fill_buf() {
+            Ok(bytes) if !bytes.is_empty() => bytes,
+            _ => return None,
+        };
+
+        // The Cow generated from the function represents the conversion from bytes to String.
+        // If no replacements are required, then the borrowed value is a proper UTF-8 string. The
+        // Owned option represents a string where the values had to be replaced, thus marking it
+        // as bytes
+        let value = match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => Value::String {
+                val: s.into(),
+                span: self.span,
+            },
+            Cow::Owned(_) => Value::Binary {
+                val: bytes.to_vec(),
+                span: self.span,
+            },
+        };
+
+        let length = bytes.len();
+        stdout.consume(length);
+
+        Some(value)
     }
 }
 
-impl Iterator for ChannelReceiver {
+impl Iterator for ExternalOutput {
     type Item = Value;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.rx.recv() {
-            Ok(v) => match v {
-                Data::String(s) => Some(Value::String {
-                    val: s,
-                    span: self.span,
-                }),
-                Data::Bytes(b) => Some(Value::Binary {
-                    val: b,
-                    span: self.span,
-                }),
-            },
-            Err(_) => None,
+        let value = self.read();
+        if value.is_none() {
+            self.finish();
         }
+
+        value
+    }
+}
+
+impl Drop for ExternalOutput {
+    fn drop(&mut self) {
+        self.finish();
     }
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use nu_protocol::{
-    engine::EngineState, Example, PositionalArg, Signature, Span, SyntaxShape, Value,
+    engine::EngineState, Config, Example, PositionalArg, Signature, Span, SyntaxShape, Value,
 };
 use std::collections::HashMap;
 
@@ -209,9 +209,14 @@ pub fn get_documentation(
             long_desc.push_str(&format!("  {}: {}\n", positional.name, positional.desc));
         }
         for positional in &sig.optional_positional {
+            let optional = match &positional.default_value {
+                Some(value) => format!("optional, default: {}", default_value_string(value)),
+                None => "optional".into(),
+            };
+
             long_desc.push_str(&format!(
-                "  (optional) {}: {}\n",
-                positional.name, positional.desc
+                "  ({}) {}: {}\n",
+                optional, positional.name, positional.desc
             ));
         }
 
@@ -269,6 +274,11 @@ fn get_flags_section(signature: &Signature) -> String {
     let mut long_desc = String::new();
     long_desc.push_str("\nFlags:\n");
     for flag in &signature.named {
+        let desc = match &flag.default_value {
+            Some(value) => format!("{} (default: {})", flag.desc, default_value_string(value)),
+            None => flag.desc.clone(),
+        };
+
         let msg = if let Some(arg) = &flag.arg {
             if let Some(short) = flag.short {
                 if flag.required {
@@ -281,7 +291,7 @@ fn get_flags_section(signature: &Signature) -> String {
                             "".into()
                         },
                         arg,
-                        flag.desc
+                        desc
                     )
                 } else {
                     format!(
@@ -293,16 +303,16 @@ fn get_flags_section(signature: &Signature) -> String {
                             "".into()
                         },
                         arg,
-                        flag.desc
+                        desc
                     )
                 }
             } else if flag.required {
                 format!(
                     "  --{} (required parameter) {:?} {}\n",
-                    flag.long, arg, flag.desc
+                    flag.long, arg, desc
                 )
             } else {
-                format!("  --{} {:?} {}\n", flag.long, arg, flag.desc)
+                format!("  --{} {:?} {}\n", flag.long, arg, desc)
             }
         } else if let Some(short) = flag.short {
             if flag.required {
@@ -314,7 +324,7 @@ fn get_flags_section(signature: &Signature) -> String {
                     } else {
                         "".into()
                     },
-                    flag.desc
+                    desc
                 )
             } else {
                 format!(
@@ -325,19 +335,27 @@ fn get_flags_section(signature: &Signature) -> String {
                     } else {
                         "".into()
                     },
-                    flag.desc
+                    desc
                 )
             }
         } else if flag.required {
-            format!("  --{} (required parameter) {}\n", flag.long, flag.desc)
+            format!("  --{} (required parameter) {}\n", flag.long, desc)
         } else {
-            format!("  --{} {}\n", flag.long, flag.desc)
+            format!("  --{} {}\n", flag.long, desc)
         };
         long_desc.push_str(&msg);
     }
     long_desc
 }
 
+// Strings are quoted, as they would be written in the signature
+fn default_value_string(value: &Value) -> String {
+    match value {
+        Value::String { val, .. } => format!("{:?}", val),
+        value => value.clone().into_string(", ", &Config::default()),
+    }
+}
+
 pub fn get_brief_help(sig: &Signature, examples: &[Example], engine_state: &EngineState) -> String {
     get_documentation(
         sig,
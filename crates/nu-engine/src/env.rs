@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{BlockId, Config, PipelineData, ShellError, Span, Value};
+
+use crate::eval_block;
+
+/// Translate environment variables from Strings to Values using the `from_string` conversions
+/// from the config. Requires that the config is already set up in the stack.
+///
+/// Conversions that fail are reported, but the remaining variables are still converted.
+pub fn convert_env_values(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    config: &Config,
+) -> Option<ShellError> {
+    let mut error = None;
+
+    let mut new_scope = HashMap::new();
+
+    for (name, val) in stack.get_env_vars() {
+        if let Some(env_conversion) = config.env_conversions.get(&name) {
+            if let Some((block_id, from_span)) = env_conversion.from_string {
+                let val_span = match val.span() {
+                    Ok(sp) => sp,
+                    Err(e) => {
+                        error = error.or(Some(e));
+                        continue;
+                    }
+                };
+
+                match run_conversion(engine_state, stack, block_id, val, val_span) {
+                    Ok(converted) => {
+                        let _ = new_scope.insert(name, converted);
+                    }
+                    Err(e) => {
+                        error = error.or(Some(ShellError::SpannedLabeledError(
+                            format!("Failed to convert environment variable '{}'", name),
+                            e.to_string(),
+                            from_span,
+                        )))
+                    }
+                }
+            }
+        }
+    }
+
+    for (name, val) in new_scope {
+        stack.add_env_var(name, val);
+    }
+
+    error
+}
+
+/// Translate one environment variable from Value to String, running its `to_string` conversion
+/// from the config if there is one. A conversion that doesn't give back a string is an error
+pub fn env_to_string(
+    env_name: &str,
+    value: Value,
+    engine_state: &EngineState,
+    stack: &Stack,
+    config: &Config,
+) -> Result<String, ShellError> {
+    if let Some(env_conversion) = config.env_conversions.get(env_name) {
+        if let Some((block_id, to_span)) = env_conversion.to_string {
+            let val_span = value.span()?;
+
+            let converted = run_conversion(engine_state, stack, block_id, value, val_span)?;
+
+            return match converted.as_string() {
+                Ok(s) => Ok(s),
+                Err(_) => Err(ShellError::SpannedLabeledError(
+                    format!("Failed to convert environment variable '{}'", env_name),
+                    "the to_string conversion does not return a string".into(),
+                    to_span,
+                )),
+            };
+        }
+    }
+
+    match value.as_string() {
+        Ok(s) => Ok(s),
+        Err(_) => Err(ShellError::EnvVarNotAString(value.span()?)),
+    }
+}
+
+/// Translate all environment variables from Values to Strings, eg) before passing them to an
+/// external command. Variables without a conversion that aren't strings are left out, while a
+/// failing conversion is reported.
+pub fn env_to_strings(
+    engine_state: &EngineState,
+    stack: &Stack,
+    config: &Config,
+) -> Result<HashMap<String, String>, ShellError> {
+    let env_vars = stack.get_env_vars();
+    let mut env_vars_str = HashMap::new();
+
+    for (env_name, val) in env_vars {
+        match env_to_string(&env_name, val, engine_state, stack, config) {
+            Ok(val_str) => {
+                env_vars_str.insert(env_name, val_str);
+            }
+            Err(ShellError::EnvVarNotAString(..)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(env_vars_str)
+}
+
+fn run_conversion(
+    engine_state: &EngineState,
+    stack: &Stack,
+    block_id: BlockId,
+    value: Value,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let block = engine_state.get_block(block_id);
+    let mut stack = stack.collect_captures(&block.captures);
+
+    if let Some(var) = block.signature.get_positional(0) {
+        if let Some(var_id) = &var.var_id {
+            stack.add_var(*var_id, value.clone());
+        }
+    }
+
+    let result = eval_block(
+        engine_state,
+        &mut stack,
+        block,
+        PipelineData::Value(value, None),
+    )?;
+
+    Ok(result.into_value(span))
+}
@@ -1,9 +1,10 @@
 use std::cmp::Ordering;
 
-use nu_protocol::ast::{Block, Call, Expr, Expression, Operator, Statement};
+use nu_protocol::ast::{Block, Call, Expr, Expression, Operator, Redirection, Statement};
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{
-    IntoPipelineData, PipelineData, Range, ShellError, Span, Spanned, Type, Unit, Value, VarId,
+    Config, DataSource, ExternalExit, IntoPipelineData, PipelineData, PipelineMetadata, Range,
+    ShellError, Span, Spanned, SyntaxShape, Type, Unit, Value, ValueStream, VarId,
 };
 
 use crate::get_full_help;
@@ -39,17 +40,28 @@ fn eval_call(
         let block = engine_state.get_block(block_id);
 
         let mut stack = stack.collect_captures(&block.captures);
-        for (arg, param) in call.positional.iter().zip(
-            decl.signature()
-                .required_positional
-                .iter()
-                .chain(decl.signature().optional_positional.iter()),
-        ) {
-            let result = eval_expression(engine_state, &mut stack, arg)?;
+        for (param_idx, param) in decl
+            .signature()
+            .required_positional
+            .iter()
+            .chain(decl.signature().optional_positional.iter())
+            .enumerate()
+        {
             let var_id = param
                 .var_id
                 .expect("internal error: all custom parameters must have var_ids");
 
+            // An optional parameter that isn't given gets its default, or nothing
+            let result = if let Some(arg) = call.positional.get(param_idx) {
+                let result = eval_expression(engine_state, &mut stack, arg)?;
+                check_param_type(&param.name, &param.shape, &result, arg.span)?;
+                result
+            } else if let Some(default_value) = &param.default_value {
+                default_value.clone()
+            } else {
+                Value::Nothing { span: call.head }
+            };
+
             stack.add_var(var_id, result);
         }
 
@@ -88,6 +100,9 @@ fn eval_call(
                     if call_named.0.item == named.long {
                         if let Some(arg) = &call_named.1 {
                             let result = eval_expression(engine_state, &mut stack, arg)?;
+                            if let Some(shape) = &named.arg {
+                                check_param_type(&named.long, shape, &result, arg.span)?;
+                            }
 
                             stack.add_var(var_id, result);
                         } else {
@@ -103,31 +118,224 @@ fn eval_call(
                     }
                 }
 
-                if !found && named.arg.is_none() {
-                    stack.add_var(
-                        var_id,
-                        Value::Bool {
+                if !found {
+                    let value = match (&named.arg, named.default_value) {
+                        (None, _) => Value::Bool {
                             val: false,
                             span: call.head,
                         },
-                    )
+                        (Some(_), Some(default_value)) => default_value,
+                        (Some(_), None) => Value::Nothing { span: call.head },
+                    };
+
+                    stack.add_var(var_id, value)
                 }
             }
         }
-        eval_block(engine_state, &mut stack, block, input)
+
+        match eval_block(engine_state, &mut stack, block, input) {
+            Err(ShellError::Return(_, value)) => Ok(value.into_pipeline_data()),
+            Err(error) => Err(stop_at_command_boundary(error)),
+            Ok(PipelineData::Stream(stream, metadata)) => {
+                let ctrlc = stream.ctrlc.clone();
+
+                // A loop at the end of the command streams its control flow along with its values
+                let stream = stream.scan(false, |returned, value| {
+                    if *returned {
+                        return None;
+                    }
+
+                    match value {
+                        Value::Error {
+                            error: ShellError::Return(_, value),
+                        } => {
+                            *returned = true;
+                            Some(*value)
+                        }
+                        Value::Error { error } => Some(Value::Error {
+                            error: stop_at_command_boundary(error),
+                        }),
+                        value => Some(value),
+                    }
+                });
+
+                Ok(PipelineData::Stream(
+                    ValueStream::from_stream(stream, ctrlc),
+                    metadata,
+                ))
+            }
+            result => result,
+        }
+    } else if decl.is_known_external() {
+        eval_known_external(engine_state, stack, call, input, false, false)
     } else {
         decl.run(engine_state, stack, call, input)
     }
 }
 
+/// A `break` or `continue` can't leave the custom command it's used in
+fn stop_at_command_boundary(error: ShellError) -> ShellError {
+    match error {
+        ShellError::Break(span) => ShellError::SpannedLabeledError(
+            "Break used outside of loop".into(),
+            "break can't leave the custom command it's used in".into(),
+            span,
+        ),
+        ShellError::Continue(span) => ShellError::SpannedLabeledError(
+            "Continue used outside of loop".into(),
+            "continue can't leave the custom command it's used in".into(),
+            span,
+        ),
+        error => error,
+    }
+}
+
+/// Check that a value given to a custom command has the type its parameter was declared with
+fn check_param_type(
+    name: &str,
+    shape: &SyntaxShape,
+    value: &Value,
+    span: Span,
+) -> Result<(), ShellError> {
+    // A row condition is given as the block it was parsed into
+    if let SyntaxShape::RowCondition = shape {
+        return Ok(());
+    }
+
+    let expected = shape.to_type();
+
+    let matches = match (&expected, value) {
+        (Type::Unknown, _) => true,
+        (Type::Number, Value::Int { .. } | Value::Float { .. }) => true,
+        (Type::List(_), Value::List { .. }) => true,
+        (expected, value) => value.get_type() == *expected,
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(ShellError::TypeMismatch(
+            format!(
+                "expected {} for {}, got {}",
+                expected,
+                name,
+                value.get_type()
+            ),
+            span,
+        ))
+    }
+}
+
+/// Run a command declared with extern as the external it describes. The arguments are passed on
+/// in the order they were written, with the flags as they were written, eg) `-b` or `--branch`.
+fn eval_known_external(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    last_expression: bool,
+    capture_stderr: bool,
+) -> Result<PipelineData, ShellError> {
+    let decl = engine_state.get_decl(call.decl_id);
+    let config = stack.get_config().unwrap_or_default();
+
+    // An extern like "git clone" runs git with clone as its first argument
+    let mut words = decl.name().split(' ');
+    let name = words.next().unwrap_or_default().to_string();
+    let mut args: Vec<(usize, String)> = words
+        .map(|word| (call.head.start, word.to_string()))
+        .collect();
+
+    for positional in &call.positional {
+        let value = eval_expression(engine_state, stack, positional)?;
+        args.extend(
+            external_arg_strings(value, &config)
+                .into_iter()
+                .map(|arg| (positional.span.start, arg)),
+        );
+    }
+
+    let mut last_flag_span = None;
+    for (flag, arg) in &call.named {
+        // A group of short flags, eg) `-abc`, is one argument for all of them
+        if last_flag_span == Some(flag.span) {
+            continue;
+        }
+        last_flag_span = Some(flag.span);
+
+        let written = String::from_utf8_lossy(engine_state.get_span_contents(&flag.span));
+
+        match arg {
+            // The value was written as part of the flag, eg) `--branch=main`
+            Some(arg) if arg.span.start > flag.span.start && arg.span.end <= flag.span.end => {
+                let value = eval_expression(engine_state, stack, arg)?.into_string(" ", &config);
+                let flag_name = written.split('=').next().unwrap_or_default();
+
+                args.push((flag.span.start, format!("{}={}", flag_name, value)));
+            }
+            Some(arg) => {
+                args.push((flag.span.start, written.to_string()));
+
+                let value = eval_expression(engine_state, stack, arg)?;
+                args.extend(
+                    external_arg_strings(value, &config)
+                        .into_iter()
+                        .map(|value| (arg.span.start, value)),
+                );
+            }
+            None => args.push((flag.span.start, written.to_string())),
+        }
+    }
+
+    // The sort is stable, so the arguments from the same place keep their order
+    args.sort_by_key(|(start, _)| *start);
+
+    let args: Vec<Expression> = args
+        .into_iter()
+        .map(|(_, arg)| Expression {
+            expr: Expr::String(arg),
+            span: call.head,
+            ty: Type::String,
+            custom_completion: None,
+        })
+        .collect();
+
+    eval_external(
+        engine_state,
+        stack,
+        &name,
+        &call.head,
+        &args,
+        &[],
+        input,
+        last_expression,
+        capture_stderr,
+    )
+}
+
+// A list, eg) the rest of the arguments, is passed on as one argument per item
+fn external_arg_strings(value: Value, config: &Config) -> Vec<String> {
+    match value {
+        Value::List { vals, .. } => vals
+            .into_iter()
+            .map(|val| val.into_string(" ", config))
+            .collect(),
+        Value::String { val, .. } => vec![val],
+        value => vec![value.into_string(" ", config)],
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn eval_external(
     engine_state: &EngineState,
     stack: &mut Stack,
     name: &str,
     name_span: &Span,
     args: &[Expression],
+    redirections: &[(Redirection, Expression)],
     input: PipelineData,
     last_expression: bool,
+    capture_stderr: bool,
 ) -> Result<PipelineData, ShellError> {
     let decl_id = engine_state
         .find_decl("run_external".as_bytes())
@@ -158,6 +366,42 @@ fn eval_external(
         ))
     }
 
+    if capture_stderr {
+        call.named.push((
+            Spanned {
+                item: "capture-stderr".into(),
+                span: Span::unknown(),
+            },
+            None,
+        ))
+    }
+
+    for (redirection, target) in redirections {
+        let path = eval_expression(engine_state, stack, target)?.as_string()?;
+        let path = Expression {
+            expr: Expr::String(path),
+            span: target.span,
+            ty: Type::String,
+            custom_completion: None,
+        };
+
+        let flags: &[&str] = match redirection {
+            Redirection::Stdout => &["out-file"],
+            Redirection::Stderr => &["err-file"],
+            Redirection::StdoutAndStderr => &["out-file", "err-file"],
+        };
+
+        for flag in flags {
+            call.named.push((
+                Spanned {
+                    item: flag.to_string(),
+                    span: target.span,
+                },
+                Some(path.clone()),
+            ))
+        }
+    }
+
     command.run(engine_state, stack, &call, input)
 }
 
@@ -238,7 +482,7 @@ pub fn eval_expression(
                     .into_value(call.head),
             )
         }
-        Expr::ExternalCall(name, span, args) => {
+        Expr::ExternalCall(name, span, args, redirections) => {
             // FIXME: protect this collect with ctrl-c
             Ok(eval_external(
                 engine_state,
@@ -246,8 +490,10 @@ pub fn eval_expression(
                 name,
                 span,
                 args,
+                redirections,
                 PipelineData::new(*span),
                 true,
+                false,
             )?
             .into_value(*span))
         }
@@ -357,16 +603,51 @@ pub fn eval_expression(
     }
 }
 
+/// The stderr of an external is only captured when the next command in the pipeline collects it
+fn is_piped_into_complete(engine_state: &EngineState, next: Option<&Expression>) -> bool {
+    match next {
+        Some(Expression {
+            expr: Expr::Call(call),
+            ..
+        }) => engine_state.get_decl(call.decl_id).name() == "complete",
+        _ => false,
+    }
+}
+
 pub fn eval_block(
     engine_state: &EngineState,
     stack: &mut Stack,
     block: &Block,
     mut input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
-    for stmt in block.stmts.iter() {
+    let num_stmts = block.stmts.len();
+    for (stmt_idx, stmt) in block.stmts.iter().enumerate() {
+        // The exit of the last external in the pipeline whose output is streamed onwards
+        let mut pending_exit = None;
+
         if let Statement::Pipeline(pipeline) = stmt {
             for (i, elem) in pipeline.expressions.iter().enumerate() {
                 match elem {
+                    Expression {
+                        expr: Expr::Call(call),
+                        ..
+                    } if engine_state.get_decl(call.decl_id).is_known_external()
+                        && !call.named.iter().any(|(flag, _)| flag.item == "help") =>
+                    {
+                        let piped_into_complete =
+                            is_piped_into_complete(engine_state, pipeline.expressions.get(i + 1));
+                        input = eval_known_external(
+                            engine_state,
+                            stack,
+                            call,
+                            input,
+                            i == pipeline.expressions.len() - 1,
+                            piped_into_complete,
+                        )?;
+                        if !piped_into_complete {
+                            pending_exit = input.take_external_exit();
+                        }
+                    }
                     Expression {
                         expr: Expr::Call(call),
                         ..
@@ -374,18 +655,25 @@ pub fn eval_block(
                         input = eval_call(engine_state, stack, call, input)?;
                     }
                     Expression {
-                        expr: Expr::ExternalCall(name, name_span, args),
+                        expr: Expr::ExternalCall(name, name_span, args, redirections),
                         ..
                     } => {
+                        let piped_into_complete =
+                            is_piped_into_complete(engine_state, pipeline.expressions.get(i + 1));
                         input = eval_external(
                             engine_state,
                             stack,
                             name,
                             name_span,
                             args,
+                            redirections,
                             input,
                             i == pipeline.expressions.len() - 1,
+                            piped_into_complete,
                         )?;
+                        if !piped_into_complete {
+                            pending_exit = input.take_external_exit();
+                        }
                     }
 
                     elem => {
@@ -394,6 +682,42 @@ pub fn eval_block(
                 }
             }
         }
+
+        if stmt_idx < num_stmts - 1 {
+            // Only the last statement is the output of the block, the output of the others is
+            // dropped. The output of an external is dropped before waiting for its exit code, so
+            // one that is still writing sees its stdout closed instead of hanging
+            input = PipelineData::new(Span::unknown());
+
+            if let Some(exit) = pending_exit {
+                if let Ok(ExternalExit { exit_code, .. }) = exit.recv() {
+                    stack.add_env_var(
+                        "LAST_EXIT_CODE".into(),
+                        Value::Int {
+                            val: exit_code,
+                            span: Span::unknown(),
+                        },
+                    );
+                }
+            }
+        } else if let Some(exit) = pending_exit {
+            // Whoever drains the output of the block reads the exit code
+            input = match input {
+                PipelineData::Value(value, None) => PipelineData::Value(
+                    value,
+                    Some(PipelineMetadata {
+                        data_source: DataSource::External(exit),
+                    }),
+                ),
+                PipelineData::Stream(stream, None) => PipelineData::Stream(
+                    stream,
+                    Some(PipelineMetadata {
+                        data_source: DataSource::External(exit),
+                    }),
+                ),
+                input => input,
+            };
+        }
     }
 
     Ok(input)
@@ -409,6 +733,21 @@ pub fn eval_subexpression(
         if let Statement::Pipeline(pipeline) = stmt {
             for (i, elem) in pipeline.expressions.iter().enumerate() {
                 match elem {
+                    Expression {
+                        expr: Expr::Call(call),
+                        ..
+                    } if engine_state.get_decl(call.decl_id).is_known_external()
+                        && !call.named.iter().any(|(flag, _)| flag.item == "help") =>
+                    {
+                        input = eval_known_external(
+                            engine_state,
+                            stack,
+                            call,
+                            input,
+                            i == pipeline.expressions.len() - 1,
+                            is_piped_into_complete(engine_state, pipeline.expressions.get(i + 1)),
+                        )?;
+                    }
                     Expression {
                         expr: Expr::Call(call),
                         ..
@@ -416,7 +755,7 @@ pub fn eval_subexpression(
                         input = eval_call(engine_state, stack, call, input)?;
                     }
                     Expression {
-                        expr: Expr::ExternalCall(name, name_span, args),
+                        expr: Expr::ExternalCall(name, name_span, args, redirections),
                         ..
                     } => {
                         input = eval_external(
@@ -425,8 +764,10 @@ pub fn eval_subexpression(
                             name,
                             name_span,
                             args,
+                            redirections,
                             input,
                             false,
+                            is_piped_into_complete(engine_state, pipeline.expressions.get(i + 1)),
                         )?;
 
                         if i == pipeline.expressions.len() - 1 {
@@ -459,6 +800,17 @@ pub fn eval_subexpression(
     Ok(input)
 }
 
+// The environment variables, as `$env` and `$nu.env`
+fn env_record(stack: &Stack, span: Span) -> Value {
+    let env_vars = stack.get_env_vars();
+
+    Value::Record {
+        cols: env_vars.keys().map(|x| x.to_string()).collect(),
+        vals: env_vars.values().cloned().collect(),
+        span,
+    }
+}
+
 pub fn eval_variable(
     engine_state: &EngineState,
     stack: &Stack,
@@ -470,22 +822,8 @@ pub fn eval_variable(
         let mut output_cols = vec![];
         let mut output_vals = vec![];
 
-        let env_vars = stack.get_env_vars();
-        let env_columns: Vec<_> = env_vars.keys().map(|x| x.to_string()).collect();
-        let env_values: Vec<_> = env_vars
-            .values()
-            .map(|x| Value::String {
-                val: x.to_string(),
-                span,
-            })
-            .collect();
-
         output_cols.push("env".into());
-        output_vals.push(Value::Record {
-            cols: env_columns,
-            vals: env_values,
-            span,
-        });
+        output_vals.push(env_record(stack, span));
 
         if let Some(mut config_path) = nu_path::config_dir() {
             config_path.push("nushell");
@@ -493,7 +831,10 @@ pub fn eval_variable(
             let mut history_path = config_path.clone();
             let mut keybinding_path = config_path.clone();
 
-            history_path.push("history.txt");
+            #[cfg(feature = "sqlite-history")]
+            history_path.push("history.sqlite3");
+            #[cfg(not(feature = "sqlite-history"))]
+            history_path.push("history.jsonl");
 
             output_cols.push("history-path".into());
             output_vals.push(Value::String {
@@ -566,6 +907,8 @@ pub fn eval_variable(
             vals: output_vals,
             span,
         })
+    } else if var_id == nu_protocol::ENV_VARIABLE_ID {
+        Ok(env_record(stack, span))
     } else if var_id == nu_protocol::SCOPE_VARIABLE_ID {
         let mut output_cols = vec![];
         let mut output_vals = vec![];
@@ -778,6 +1121,29 @@ pub fn eval_variable(
                     span,
                 });
 
+                cols.push("input_output_types".to_string());
+                vals.push(Value::List {
+                    vals: decl
+                        .input_output_types()
+                        .into_iter()
+                        .map(|(input, output)| Value::Record {
+                            cols: vec!["input".into(), "output".into()],
+                            vals: vec![
+                                Value::String {
+                                    val: input.to_string(),
+                                    span,
+                                },
+                                Value::String {
+                                    val: output.to_string(),
+                                    span,
+                                },
+                            ],
+                            span,
+                        })
+                        .collect(),
+                    span,
+                });
+
                 commands.push(Value::Record { cols, vals, span })
             }
 
@@ -1,7 +1,9 @@
 mod call_ext;
 mod documentation;
+mod env;
 mod eval;
 
 pub use call_ext::CallExt;
 pub use documentation::{generate_docs, get_brief_help, get_documentation, get_full_help};
-pub use eval::{eval_block, eval_expression, eval_operator};
+pub use env::{convert_env_values, env_to_string, env_to_strings};
+pub use eval::{eval_block, eval_expression, eval_operator, eval_variable};
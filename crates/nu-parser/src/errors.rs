@@ -73,6 +73,35 @@ pub enum ParseError {
     #[diagnostic(code(nu::parser::multiple_rest_params), url(docsrs))]
     MultipleRestParams(#[label = "multiple rest params"] Span),
 
+    #[error("Default value is not a constant.")]
+    #[diagnostic(
+        code(nu::parser::non_constant_default_value),
+        url(docsrs),
+        help("Default values are used as they are written, without being evaluated.")
+    )]
+    NonConstantDefaultValue(#[label = "expected a constant value"] Span),
+
+    #[error("Default value on a switch.")]
+    #[diagnostic(
+        code(nu::parser::default_value_on_switch),
+        url(docsrs),
+        help("Give the flag a type, eg) --name: string = \"nu\".")
+    )]
+    DefaultValueOnSwitch(#[label = "switches can't have a default value"] Span),
+
+    #[error("Command doesn't support the input it's given.")]
+    #[diagnostic(
+        code(nu::parser::input_type_mismatch),
+        url(docsrs),
+        help("The second command takes {2} as input, but the first gives {0}.")
+    )]
+    InputMismatch(
+        String,
+        #[label("gives {0}")] Span,
+        String,
+        #[label("takes {2}")] Span,
+    ),
+
     #[error("Variable not found.")]
     #[diagnostic(code(nu::parser::variable_not_found), url(docsrs))]
     VariableNotFound(#[label = "variable not found"] Span),
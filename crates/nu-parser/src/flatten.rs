@@ -103,10 +103,13 @@ pub fn flatten_expression(
             output.extend(args);
             output
         }
-        Expr::ExternalCall(_, name_span, args) => {
+        Expr::ExternalCall(_, name_span, args, redirections) => {
             let mut output = vec![(*name_span, FlatShape::External)];
 
-            for arg in args {
+            for arg in args
+                .iter()
+                .chain(redirections.iter().map(|(_, target)| target))
+            {
                 //output.push((*arg, FlatShape::ExternalArg));
                 match arg {
                     Expression {
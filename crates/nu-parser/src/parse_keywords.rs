@@ -14,7 +14,7 @@ use crate::{
     parser::{
         check_call, check_name, garbage, garbage_statement, parse, parse_block_expression,
         parse_import_pattern, parse_internal_call, parse_multispan_value, parse_signature,
-        parse_string, parse_var_with_opt_type, trim_quotes,
+        parse_string, parse_type, parse_var_with_opt_type, trim_quotes,
     },
     ParseError,
 };
@@ -22,6 +22,10 @@ use crate::{
 pub fn parse_def_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> Option<ParseError> {
     let name = working_set.get_span_contents(spans[0]);
 
+    if name == b"extern" && spans.len() >= 3 {
+        return parse_extern_predecl(working_set, spans);
+    }
+
     // handle "export def" same as "def"
     let (name, spans) = if name == b"export" && spans.len() >= 2 {
         (working_set.get_span_contents(spans[1]), &spans[1..])
@@ -30,6 +34,8 @@ pub fn parse_def_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> O
     };
 
     if name == b"def" && spans.len() >= 4 {
+        let (spans, input_output_types, ..) = split_input_output_types(working_set, spans);
+
         let (name_expr, ..) = parse_string(working_set, spans[1]);
         let name = name_expr.as_string();
 
@@ -46,6 +52,7 @@ pub fn parse_def_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> O
 
         if let (Some(name), Some(mut signature)) = (name, signature) {
             signature.name = name;
+            signature.input_output_types.extend(input_output_types);
             let decl = signature.predeclare();
 
             if working_set.add_predecl(decl).is_some() {
@@ -57,6 +64,118 @@ pub fn parse_def_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> O
     None
 }
 
+// The input and output types of a definition go between its parameters and its block, eg)
+// `def double [] : int -> int { $in * 2 }`. Gives back the spans of the definition without them,
+// and the types.
+fn split_input_output_types(
+    working_set: &StateWorkingSet,
+    spans: &[Span],
+) -> (Vec<Span>, Option<(Type, Type)>, Option<ParseError>) {
+    // def <name> <parameters> <block>
+    if spans.len() < 4 {
+        return (spans.to_vec(), None, None);
+    }
+
+    let mut def_spans = spans[0..3].to_vec();
+    let mut rest = &spans[3..];
+
+    // The colon can be written right after the parameters, eg) `def double []: int -> int {...}`
+    if working_set.get_span_contents(spans[2]).ends_with(b"]:") {
+        def_spans[2].end -= 1;
+    } else if working_set.get_span_contents(rest[0]) == b":" {
+        rest = &rest[1..];
+    } else {
+        return (spans.to_vec(), None, None);
+    }
+
+    if rest.len() < 3 || working_set.get_span_contents(rest[1]) != b"->" {
+        let err_span = if rest.is_empty() {
+            spans[2]
+        } else {
+            span(rest)
+        };
+        def_spans.extend(rest.last());
+
+        return (
+            def_spans,
+            None,
+            Some(ParseError::Expected(
+                "input and output types, eg) string -> int".into(),
+                err_span,
+            )),
+        );
+    }
+
+    let (input_type, input_err) = parse_input_output_type(working_set, rest[0]);
+    let (output_type, output_err) = parse_input_output_type(working_set, rest[2]);
+    def_spans.extend(&rest[3..]);
+
+    (
+        def_spans,
+        Some((input_type, output_type)),
+        input_err.or(output_err),
+    )
+}
+
+// Besides the types a variable can be declared with, a command can take and give any of the
+// structured types
+fn parse_input_output_type(
+    working_set: &StateWorkingSet,
+    span: Span,
+) -> (Type, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    let ty = match bytes {
+        b"any" => return (Type::Unknown, None),
+        b"duration" => Type::Duration,
+        b"list" => Type::List(Box::new(Type::Unknown)),
+        b"nothing" => Type::Nothing,
+        b"number" => Type::Number,
+        b"range" => Type::Range,
+        b"record" => Type::Record(vec![]),
+        b"table" => Type::Table,
+        _ if bytes.starts_with(b"list<") && bytes.ends_with(b">") => {
+            let item_span = Span {
+                start: span.start + 5,
+                end: span.end - 1,
+            };
+            let (item_type, err) = parse_input_output_type(working_set, item_span);
+
+            return (Type::List(Box::new(item_type)), err);
+        }
+        _ => parse_type(working_set, bytes),
+    };
+
+    if ty == Type::Unknown {
+        (ty, Some(ParseError::Expected("type".into(), span)))
+    } else {
+        (ty, None)
+    }
+}
+
+// An extern is declared before the block it is in is parsed, like a def, so that the calls to it
+// in that block are parsed with its signature
+fn parse_extern_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> Option<ParseError> {
+    let (name_expr, ..) = parse_string(working_set, spans[1]);
+    let name = name_expr.as_string();
+
+    working_set.enter_scope();
+    let (sig, ..) = parse_signature(working_set, spans[2]);
+    let signature = sig.as_signature();
+    working_set.exit_scope();
+
+    if let (Some(name), Some(mut signature)) = (name, signature) {
+        signature.name = name;
+        let decl = signature.predeclare();
+
+        if working_set.add_predecl(decl).is_some() {
+            return Some(ParseError::DuplicateCommandDef(spans[1]));
+        }
+    }
+
+    None
+}
+
 pub fn parse_def(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -65,6 +184,10 @@ pub fn parse_def(
     let name = working_set.get_span_contents(spans[0]);
 
     if name == b"def" {
+        let def_span = span(spans);
+        let (spans, input_output_types, err) = split_input_output_types(working_set, spans);
+        error = error.or(err);
+
         // TODO: Convert all 'expect("internal error: ...")' to ParseError::InternalError
         let def_decl_id = working_set
             .find_decl(b"def")
@@ -112,6 +235,7 @@ pub fn parse_def(
                             let declaration = working_set.get_decl_mut(decl_id);
 
                             signature.name = name.clone();
+                            signature.input_output_types.extend(input_output_types);
 
                             *declaration = signature.into_block_command(block_id);
                         } else {
@@ -172,7 +296,7 @@ pub fn parse_def(
         (
             Statement::Pipeline(Pipeline::from_vec(vec![Expression {
                 expr: Expr::Call(call),
-                span: span(spans),
+                span: def_span,
                 ty: Type::Unknown,
                 custom_completion: None,
             }])),
@@ -189,6 +313,114 @@ pub fn parse_def(
     }
 }
 
+pub fn parse_extern(
+    working_set: &mut StateWorkingSet,
+    spans: &[Span],
+) -> (Statement, Option<ParseError>) {
+    let mut error = None;
+
+    let extern_decl_id = if let Some(id) = working_set.find_decl(b"extern") {
+        id
+    } else {
+        return (
+            garbage_statement(spans),
+            Some(ParseError::InternalError(
+                "missing extern command".into(),
+                spans[0],
+            )),
+        );
+    };
+
+    let mut call = Box::new(Call {
+        head: spans[0],
+        decl_id: extern_decl_id,
+        positional: vec![],
+        named: vec![],
+    });
+
+    if let Some(name_span) = spans.get(1) {
+        let (name_expr, err) = parse_string(working_set, *name_span);
+        error = error.or(err);
+
+        let name = name_expr.as_string();
+        call.positional.push(name_expr);
+
+        if let Some(sig_span) = spans.get(2) {
+            working_set.enter_scope();
+            let (sig, err) = parse_signature(working_set, *sig_span);
+            error = error.or(err);
+            working_set.exit_scope();
+
+            let signature = sig.as_signature();
+            call.positional.push(sig);
+
+            if let (Some(name), Some(mut signature)) = (&name, signature) {
+                if let Some(decl_id) = working_set.find_decl(name.as_bytes()) {
+                    let declaration = working_set.get_decl_mut(decl_id);
+
+                    signature.name = name.clone();
+
+                    *declaration = signature.into_known_external();
+                } else {
+                    error = error.or_else(|| {
+                        Some(ParseError::InternalError(
+                            "Predeclaration failed to add declaration".into(),
+                            spans[1],
+                        ))
+                    });
+                };
+            }
+
+            if let Some(name) = name {
+                // It's OK if it returns None: The decl was already merged in previous parse
+                // pass.
+                working_set.merge_predecl(name.as_bytes());
+            } else {
+                error = error.or_else(|| {
+                    Some(ParseError::UnknownState(
+                        "Could not get string from string expression".into(),
+                        *name_span,
+                    ))
+                });
+            }
+
+            if spans.len() > 3 {
+                error = error.or_else(|| Some(ParseError::ExtraPositional(span(&spans[3..]))));
+            }
+        } else {
+            let err_span = Span {
+                start: name_span.end,
+                end: name_span.end,
+            };
+
+            error = error
+                .or_else(|| Some(ParseError::MissingPositional("parameters".into(), err_span)));
+        }
+    } else {
+        let err_span = Span {
+            start: spans[0].end,
+            end: spans[0].end,
+        };
+
+        error = error.or_else(|| {
+            Some(ParseError::MissingPositional(
+                "external command name".into(),
+                err_span,
+            ))
+        });
+    }
+
+    (
+        Statement::Pipeline(Pipeline::from_vec(vec![Expression {
+            expr: Expr::Call(call),
+            span: span(spans),
+            ty: Type::Unknown,
+            custom_completion: None,
+        }])),
+        error,
+    )
+}
+
 pub fn parse_alias(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -1130,12 +1362,62 @@ pub fn parse_source(
     )
 }
 
+// Asks the plugin for its signatures and adds a declaration for each of its commands
+#[cfg(feature = "plugin")]
+fn register_plugin(
+    working_set: &mut StateWorkingSet,
+    path: std::path::PathBuf,
+    encoding: nu_plugin::EncodingType,
+    span: Span,
+) -> Result<(), ParseError> {
+    use nu_plugin::{get_signature, plugin_hash, plugin_modified, PluginDeclaration};
+
+    // Read before the plugin is called, so a change made while it is running isn't missed
+    let modified = plugin_modified(&path);
+    let hash = plugin_hash(&path);
+
+    let signatures = get_signature(path.as_path(), &encoding).map_err(|err| {
+        ParseError::LabeledError("Error getting signatures".into(), err.to_string(), span)
+    })?;
+
+    for signature in signatures {
+        // create plugin command declaration (need struct impl Command)
+        // store declaration in working set
+        let plugin_decl = PluginDeclaration::new(
+            path.clone(),
+            signature,
+            encoding.clone(),
+            modified,
+            hash.clone(),
+        );
+
+        working_set.add_decl(Box::new(plugin_decl));
+    }
+
+    working_set.mark_plugins_file_dirty();
+
+    Ok(())
+}
+
+// Whether the plugin has already been registered by the code being parsed
+#[cfg(feature = "plugin")]
+fn plugin_added_in_working_set(working_set: &StateWorkingSet, path: &std::path::Path) -> bool {
+    let first_new_decl = working_set.num_decls() - working_set.delta.num_decls();
+
+    (first_new_decl..working_set.num_decls()).any(|decl_id| {
+        matches!(
+            working_set.get_decl(decl_id).is_plugin(),
+            Some((filename, _)) if filename == path
+        )
+    })
+}
+
 #[cfg(feature = "plugin")]
 pub fn parse_register(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
 ) -> (Statement, Option<ParseError>) {
-    use nu_plugin::{get_signature, EncodingType, PluginDeclaration};
+    use nu_plugin::{plugin_hash, plugin_modified, EncodingType, PluginDeclaration};
     use nu_protocol::Signature;
 
     // Checking that the function is used with the correct name
@@ -1236,36 +1518,61 @@ pub fn parse_register(
         })
     });
 
+    // The modification time the signature was saved with, if it was read from the plugin file
+    let saved_modified = call.get_flag_expr("modified").map(|expr| {
+        let modified = working_set.get_span_contents(expr.span);
+        String::from_utf8_lossy(modified)
+            .parse::<u64>()
+            .map_err(|_| {
+                ParseError::IncorrectValue(
+                    "wrong modification time".into(),
+                    expr.span,
+                    "The modification time is given in seconds since the unix epoch".into(),
+                )
+            })
+    });
+
+    // The hash of the plugin the signature was saved with
+    let saved_hash = call
+        .get_flag_expr("hash")
+        .map(|expr| String::from_utf8_lossy(working_set.get_span_contents(expr.span)).to_string());
+
     let error = match signature {
         Some(signature) => arguments.and_then(|(path, encoding)| {
-            signature.map(|signature| {
-                let plugin_decl = PluginDeclaration::new(path, signature, encoding);
-                working_set.add_decl(Box::new(plugin_decl));
-                working_set.mark_plugins_file_dirty();
-            })
-        }),
-        None => arguments.and_then(|(path, encoding)| {
-            get_signature(path.as_path(), &encoding)
-                .map_err(|err| {
-                    ParseError::LabeledError(
-                        "Error getting signatures".into(),
-                        err.to_string(),
-                        spans[0],
-                    )
-                })
-                .map(|signatures| {
-                    for signature in signatures {
-                        // create plugin command declaration (need struct impl Command)
-                        // store declaration in working set
-                        let plugin_decl =
-                            PluginDeclaration::new(path.clone(), signature, encoding.clone());
-
-                        working_set.add_decl(Box::new(plugin_decl));
-                    }
+            let modified = plugin_modified(&path);
+            let saved_modified = saved_modified.transpose()?;
+
+            // Hashing the plugin is only worth it once its modification time has changed, which
+            // happens without its contents changing too, eg) when the plugin is copied
+            let time_changed = matches!(saved_modified, Some(saved) if modified != Some(saved));
+            let hash = if time_changed {
+                plugin_hash(&path)
+            } else {
+                saved_hash.clone()
+            };
 
+            if time_changed && (saved_hash.is_none() || hash != saved_hash) {
+                // The plugin changed since the signature was saved, so it is asked for its
+                // signatures again. A plugin has a register line for each of its commands, but
+                // only the first of them needs to do that.
+                if plugin_added_in_working_set(working_set, &path) {
+                    Ok(())
+                } else {
+                    register_plugin(working_set, path, encoding, spans[0])
+                }
+            } else {
+                // Without a modification time the signature is taken to be that of the plugin
+                // as it is now. When only the time changed, the new one is saved with it.
+                signature.map(|signature| {
+                    let plugin_decl =
+                        PluginDeclaration::new(path, signature, encoding, modified, hash);
+                    working_set.add_decl(Box::new(plugin_decl));
                     working_set.mark_plugins_file_dirty();
                 })
+            }
         }),
+        None => arguments
+            .and_then(|(path, encoding)| register_plugin(working_set, path, encoding, spans[0])),
     }
     .err();
 
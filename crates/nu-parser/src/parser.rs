@@ -1,7 +1,7 @@
 use crate::{
     lex, lite_parse,
     parse_keywords::parse_source,
-    type_check::{math_result_type, type_compatible},
+    type_check::{check_pipeline_types, math_result_type, type_compatible},
     LiteBlock, ParseError, Token, TokenContents,
 };
 
@@ -9,15 +9,16 @@ use nu_protocol::{
     ast::{
         Block, Call, CellPath, Expr, Expression, FullCellPath, ImportPattern, ImportPatternHead,
         ImportPatternMember, Operator, PathMember, Pipeline, RangeInclusion, RangeOperator,
-        Statement,
+        Redirection, Statement,
     },
     engine::StateWorkingSet,
-    span, Flag, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type, Unit, VarId,
+    span, Flag, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type, Unit, Value, VarId,
     CONFIG_VARIABLE_ID,
 };
 
 use crate::parse_keywords::{
-    parse_alias, parse_def, parse_def_predecl, parse_hide, parse_let, parse_module, parse_use,
+    parse_alias, parse_def, parse_def_predecl, parse_extern, parse_hide, parse_let, parse_module,
+    parse_use,
 };
 
 use std::collections::HashSet;
@@ -146,27 +147,50 @@ pub fn parse_external_call(
     let mut args = vec![];
     let name_span = spans[0];
     let name = String::from_utf8_lossy(working_set.get_span_contents(name_span)).to_string();
+    let mut redirections = vec![];
     let mut error = None;
 
-    for span in &spans[1..] {
-        let contents = working_set.get_span_contents(*span);
+    let mut spans_idx = 1;
+    while spans_idx < spans.len() {
+        let span = spans[spans_idx];
+        let contents = working_set.get_span_contents(span);
+
+        let redirection = match contents {
+            b"out>" => Some(Redirection::Stdout),
+            b"err>" => Some(Redirection::Stderr),
+            b"out+err>" | b"err+out>" => Some(Redirection::StdoutAndStderr),
+            _ => None,
+        };
+
+        if let Some(redirection) = redirection {
+            spans_idx += 1;
 
-        if contents.starts_with(b"$") || contents.starts_with(b"(") {
-            let (arg, err) = parse_expression(working_set, &[*span], true);
+            match spans.get(spans_idx) {
+                Some(target_span) => {
+                    let (target, err) = parse_external_arg(working_set, *target_span);
+                    error = error.or(err);
+                    redirections.push((redirection, target));
+                }
+                None => {
+                    error = error.or_else(|| {
+                        Some(ParseError::KeywordMissingArgument(
+                            String::from_utf8_lossy(contents).to_string(),
+                            span,
+                        ))
+                    });
+                }
+            }
+        } else {
+            let (arg, err) = parse_external_arg(working_set, span);
             error = error.or(err);
             args.push(arg);
-        } else {
-            args.push(Expression {
-                expr: Expr::String(String::from_utf8_lossy(contents).to_string()),
-                span: *span,
-                ty: Type::String,
-                custom_completion: None,
-            })
         }
+
+        spans_idx += 1;
     }
     (
         Expression {
-            expr: Expr::ExternalCall(name, name_span, args),
+            expr: Expr::ExternalCall(name, name_span, args, redirections),
             span: span(spans),
             ty: Type::Unknown,
             custom_completion: None,
@@ -175,6 +199,27 @@ pub fn parse_external_call(
     )
 }
 
+fn parse_external_arg(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+) -> (Expression, Option<ParseError>) {
+    let contents = working_set.get_span_contents(span);
+
+    if contents.starts_with(b"$") || contents.starts_with(b"(") {
+        parse_expression(working_set, &[span], true)
+    } else {
+        (
+            Expression {
+                expr: Expr::String(String::from_utf8_lossy(contents).to_string()),
+                span,
+                ty: Type::String,
+                custom_completion: None,
+            },
+            None,
+        )
+    }
+}
+
 fn parse_long_flag(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -1195,6 +1240,16 @@ pub fn parse_variable_expr(
             },
             None,
         );
+    } else if contents == b"$env" {
+        return (
+            Expression {
+                expr: Expr::Var(nu_protocol::ENV_VARIABLE_ID),
+                span,
+                ty: Type::Unknown,
+                custom_completion: None,
+            },
+            None,
+        );
     } else if contents == b"$scope" {
         return (
             Expression {
@@ -1748,10 +1803,31 @@ pub fn parse_string_strict(
 
 //TODO: Handle error case for unknown shapes
 pub fn parse_shape_name(
-    _working_set: &StateWorkingSet,
+    working_set: &StateWorkingSet,
     bytes: &[u8],
     span: Span,
 ) -> (SyntaxShape, Option<ParseError>) {
+    // A shape can be followed by the command that completes it, eg) `string(git-branches)`
+    if let (Some(open), true) = (bytes.iter().position(|b| *b == b'('), bytes.ends_with(b")")) {
+        let (shape, err) = parse_shape_name(working_set, &bytes[..open], span);
+        let completion = String::from_utf8_lossy(&bytes[(open + 1)..(bytes.len() - 1)]);
+
+        if completion.trim().is_empty() {
+            return (
+                shape,
+                err.or(Some(ParseError::Expected(
+                    "completion command".into(),
+                    span,
+                ))),
+            );
+        }
+
+        return (
+            SyntaxShape::Custom(Box::new(shape), completion.trim().to_string()),
+            err,
+        );
+    }
+
     let result = match bytes {
         b"any" => SyntaxShape::Any,
         b"string" => SyntaxShape::String,
@@ -2014,6 +2090,7 @@ pub fn parse_row_condition(
                 desc: "row condition".into(),
                 shape: SyntaxShape::Any,
                 var_id: Some(var_id),
+                default_value: None,
             });
 
             let mut seen = vec![];
@@ -2087,6 +2164,7 @@ pub fn parse_signature_helper(
     enum ParseMode {
         ArgMode,
         TypeMode,
+        DefaultValueMode,
     }
 
     enum Arg {
@@ -2097,7 +2175,13 @@ pub fn parse_signature_helper(
     let mut error = None;
     let source = working_set.get_span_contents(span);
 
-    let (output, err) = lex(source, span.start, &[b'\n', b'\r', b','], &[b':'], false);
+    let (output, err) = lex(
+        source,
+        span.start,
+        &[b'\n', b'\r', b','],
+        &[b':', b'='],
+        false,
+    );
     error = error.or(err);
 
     let mut args: Vec<Arg> = vec![];
@@ -2118,12 +2202,23 @@ pub fn parse_signature_helper(
                         ParseMode::ArgMode => {
                             parse_mode = ParseMode::TypeMode;
                         }
-                        ParseMode::TypeMode => {
+                        ParseMode::TypeMode | ParseMode::DefaultValueMode => {
                             // We're seeing two types for the same thing for some reason, error
                             error =
                                 error.or_else(|| Some(ParseError::Expected("type".into(), span)));
                         }
                     }
+                } else if contents == b"=" {
+                    match parse_mode {
+                        ParseMode::ArgMode if !args.is_empty() => {
+                            parse_mode = ParseMode::DefaultValueMode;
+                        }
+                        _ => {
+                            error = error.or_else(|| {
+                                Some(ParseError::Expected("default value".into(), span))
+                            });
+                        }
+                    }
                 } else {
                     match parse_mode {
                         ParseMode::ArgMode => {
@@ -2144,6 +2239,7 @@ pub fn parse_signature_helper(
                                         short: None,
                                         required: false,
                                         var_id: Some(var_id),
+                                        default_value: None,
                                     }));
                                 } else {
                                     let short_flag = &flags[1];
@@ -2174,6 +2270,7 @@ pub fn parse_signature_helper(
                                             short: Some(chars[0]),
                                             required: false,
                                             var_id: Some(var_id),
+                                            default_value: None,
                                         }));
                                     } else {
                                         error = error.or_else(|| {
@@ -2200,6 +2297,7 @@ pub fn parse_signature_helper(
                                         short: None,
                                         required: false,
                                         var_id: None,
+                                        default_value: None,
                                     }));
                                 } else {
                                     let mut encoded_var_name = vec![0u8; 4];
@@ -2215,6 +2313,7 @@ pub fn parse_signature_helper(
                                         short: Some(chars[0]),
                                         required: false,
                                         var_id: Some(var_id),
+                                        default_value: None,
                                     }));
                                 }
                             } else if contents.starts_with(b"(-") {
@@ -2273,6 +2372,7 @@ pub fn parse_signature_helper(
                                         name,
                                         shape: SyntaxShape::Any,
                                         var_id: Some(var_id),
+                                        default_value: None,
                                     },
                                     false,
                                 ))
@@ -2289,6 +2389,7 @@ pub fn parse_signature_helper(
                                             name,
                                             shape: SyntaxShape::Any,
                                             var_id: Some(var_id),
+                                            default_value: None,
                                         },
                                         false,
                                     ));
@@ -2308,6 +2409,7 @@ pub fn parse_signature_helper(
                                         name,
                                         shape: SyntaxShape::Any,
                                         var_id: Some(var_id),
+                                        default_value: None,
                                     },
                                     true,
                                 ))
@@ -2335,6 +2437,64 @@ pub fn parse_signature_helper(
                             }
                             parse_mode = ParseMode::ArgMode;
                         }
+                        ParseMode::DefaultValueMode => {
+                            if let Some(last) = args.last_mut() {
+                                match last {
+                                    Arg::Positional(
+                                        PositionalArg {
+                                            shape,
+                                            var_id,
+                                            default_value,
+                                            ..
+                                        },
+                                        required,
+                                    ) => {
+                                        let (expression, err) =
+                                            parse_value(working_set, span, shape);
+                                        error = error.or(err);
+
+                                        // A parameter with a default doesn't have to be given
+                                        *required = false;
+                                        if *shape == SyntaxShape::Any {
+                                            working_set.set_variable_type(var_id.expect("internal error: all custom parameters must have var_ids"), expression.ty.clone());
+                                        }
+
+                                        match constant_value(&expression) {
+                                            Some(value) => *default_value = Some(value),
+                                            None => {
+                                                error = error.or(Some(
+                                                    ParseError::NonConstantDefaultValue(span),
+                                                ))
+                                            }
+                                        }
+                                    }
+                                    Arg::Flag(Flag {
+                                        arg, default_value, ..
+                                    }) => match arg {
+                                        Some(shape) => {
+                                            let (expression, err) =
+                                                parse_value(working_set, span, shape);
+                                            error = error.or(err);
+
+                                            match constant_value(&expression) {
+                                                Some(value) => *default_value = Some(value),
+                                                None => {
+                                                    error = error.or(Some(
+                                                        ParseError::NonConstantDefaultValue(span),
+                                                    ))
+                                                }
+                                            }
+                                        }
+                                        // A switch is false unless it is given
+                                        None => {
+                                            error = error
+                                                .or(Some(ParseError::DefaultValueOnSwitch(span)))
+                                        }
+                                    },
+                                }
+                            }
+                            parse_mode = ParseMode::ArgMode;
+                        }
                     }
                 }
             }
@@ -2402,6 +2562,39 @@ pub fn parse_signature_helper(
     (Box::new(sig), error)
 }
 
+// The value of an expression that doesn't have to be evaluated, eg) the default value of a
+// parameter
+fn constant_value(expression: &Expression) -> Option<Value> {
+    let span = expression.span;
+
+    match &expression.expr {
+        Expr::Bool(val) => Some(Value::Bool { val: *val, span }),
+        Expr::Int(val) => Some(Value::Int { val: *val, span }),
+        Expr::Float(val) => Some(Value::Float { val: *val, span }),
+        Expr::String(val) | Expr::Filepath(val) | Expr::GlobPattern(val) => Some(Value::String {
+            val: val.clone(),
+            span,
+        }),
+        Expr::List(items) => {
+            let vals = items
+                .iter()
+                .map(constant_value)
+                .collect::<Option<Vec<_>>>()?;
+            Some(Value::List { vals, span })
+        }
+        Expr::Record(fields) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (col, val) in fields {
+                cols.push(constant_value(col)?.as_string().ok()?);
+                vals.push(constant_value(val)?);
+            }
+            Some(Value::Record { cols, vals, span })
+        }
+        _ => None,
+    }
+}
+
 pub fn parse_list_expression(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -2673,6 +2866,7 @@ pub fn parse_block_expression(
             let mut new_sigature = Signature::new("");
             new_sigature.required_positional.push(PositionalArg {
                 var_id: Some(var_id),
+                default_value: None,
                 name: "$it".into(),
                 desc: String::new(),
                 shape: SyntaxShape::Any,
@@ -2694,6 +2888,7 @@ pub fn parse_block_expression(
             let mut signature = Signature::new("");
             signature.required_positional.push(PositionalArg {
                 var_id: Some(*var_id),
+                default_value: None,
                 name: "$it".into(),
                 desc: String::new(),
                 shape: SyntaxShape::Any,
@@ -3214,6 +3409,7 @@ pub fn parse_statement(
 
     match name {
         b"def" => parse_def(working_set, spans),
+        b"extern" => parse_extern(working_set, spans),
         b"let" => parse_let(working_set, spans),
         b"alias" => parse_alias(working_set, spans),
         b"module" => parse_module(working_set, spans),
@@ -3356,6 +3552,10 @@ pub fn parse_block(
                     }
                 }
 
+                if error.is_none() {
+                    error = check_pipeline_types(working_set, &output);
+                }
+
                 Statement::Pipeline(Pipeline {
                     expressions: output,
                 })
@@ -3468,8 +3668,11 @@ pub fn find_captures_in_expr(
             }
         }
         Expr::CellPath(_) => {}
-        Expr::ExternalCall(_, _, exprs) => {
-            for expr in exprs {
+        Expr::ExternalCall(_, _, exprs, redirections) => {
+            for expr in exprs
+                .iter()
+                .chain(redirections.iter().map(|(_, target)| target))
+            {
                 let result = find_captures_in_expr(working_set, expr, seen);
                 output.extend(&result);
             }
@@ -3560,6 +3763,7 @@ fn wrap_expr_with_collect(working_set: &mut StateWorkingSet, expr: &Expression)
         let mut signature = Signature::new("");
         signature.required_positional.push(PositionalArg {
             var_id: Some(var_id),
+            default_value: None,
             name: "$it".into(),
             desc: String::new(),
             shape: SyntaxShape::Any,
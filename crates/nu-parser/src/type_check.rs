@@ -14,6 +14,96 @@ pub fn type_compatible(lhs: &Type, rhs: &Type) -> bool {
     }
 }
 
+/// Whether a command that takes `input` can be given `output`, from the command before it in a
+/// pipeline
+pub fn input_type_compatible(output: &Type, input: &Type) -> bool {
+    match (output, input) {
+        (Type::Unknown, _) => true,
+        (_, Type::Unknown) => true,
+        (Type::ValueStream, _) => true,
+        (_, Type::ValueStream) => true,
+        (Type::Int | Type::Float | Type::Number, Type::Number) => true,
+        (Type::Number, Type::Int | Type::Float) => true,
+        (Type::List(c), Type::List(d)) => input_type_compatible(c, d),
+        (Type::Table, Type::List(item)) | (Type::List(item), Type::Table) => {
+            matches!(**item, Type::Unknown | Type::Record(_))
+        }
+        // The fields aren't compared: commands declare `record` without naming any, and the
+        // fields a command gives usually depend on its arguments or its input
+        (Type::Record(_), Type::Record(_)) => true,
+        (output, input) => output == input,
+    }
+}
+
+/// Check that each command of a pipeline can take the output of the command before it. Commands
+/// that don't declare their input and output types are taken to be fine with anything.
+pub fn check_pipeline_types(
+    working_set: &StateWorkingSet,
+    expressions: &[Expression],
+) -> Option<ParseError> {
+    for stages in expressions.windows(2) {
+        let (previous, current) = match (&stages[0].expr, &stages[1].expr) {
+            (Expr::Call(previous), Expr::Call(current)) => (previous, current),
+            _ => continue,
+        };
+
+        // Given cell paths, a command works on the values at those paths instead of on its
+        // whole input
+        if current
+            .positional
+            .iter()
+            .any(|arg| matches!(arg.expr, Expr::CellPath(_)))
+        {
+            continue;
+        }
+
+        let outputs: Vec<Type> = working_set
+            .get_decl(previous.decl_id)
+            .input_output_types()
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect();
+        let inputs: Vec<Type> = working_set
+            .get_decl(current.decl_id)
+            .input_output_types()
+            .into_iter()
+            .map(|(input, _)| input)
+            .collect();
+
+        if outputs.is_empty() || inputs.is_empty() {
+            continue;
+        }
+
+        if !outputs.iter().any(|output| {
+            inputs
+                .iter()
+                .any(|input| input_type_compatible(output, input))
+        }) {
+            return Some(ParseError::InputMismatch(
+                type_list(&outputs),
+                stages[0].span,
+                type_list(&inputs),
+                stages[1].span,
+            ));
+        }
+    }
+
+    None
+}
+
+// eg) "string or list<string>"
+fn type_list(types: &[Type]) -> String {
+    let mut names: Vec<String> = vec![];
+    for ty in types {
+        let name = ty.to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names.join(" or ")
+}
+
 pub fn math_result_type(
     _working_set: &StateWorkingSet,
     lhs: &mut Expression,
@@ -136,3 +136,20 @@ fn lex_comments() {
         }
     );
 }
+
+#[test]
+fn lex_redirections() {
+    let file = b"^cat foo out> bar.txt err> baz.txt";
+
+    let output = lex(file, 0, &[], &[], true);
+
+    assert!(output.1.is_none());
+    assert!(output.0.contains(&Token {
+        contents: TokenContents::Item,
+        span: Span { start: 9, end: 13 }
+    }));
+    assert!(output.0.contains(&Token {
+        contents: TokenContents::Item,
+        span: Span { start: 22, end: 26 }
+    }));
+}
@@ -1,7 +1,7 @@
 use nu_parser::ParseError;
 use nu_parser::*;
 use nu_protocol::{
-    ast::{Expr, Expression, Pipeline, Statement},
+    ast::{Expr, Expression, Pipeline, Redirection, Statement},
     engine::{Command, EngineState, Stack, StateWorkingSet},
     Signature, SyntaxShape,
 };
@@ -65,6 +65,31 @@ pub fn parse_int() {
     }
 }
 
+#[test]
+pub fn parse_external_redirection() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let (block, err) = parse(&mut working_set, None, b"^cat foo out+err> bar.txt", true);
+
+    assert!(err.is_none());
+    assert!(block.len() == 1);
+    match &block[0] {
+        Statement::Pipeline(Pipeline { expressions }) => {
+            assert!(expressions.len() == 1);
+            match &expressions[0].expr {
+                Expr::ExternalCall(_, _, args, redirections) => {
+                    assert_eq!(args.len(), 1);
+                    assert_eq!(redirections.len(), 1);
+                    assert_eq!(redirections[0].0, Redirection::StdoutAndStderr);
+                }
+                _ => panic!("No match"),
+            }
+        }
+        _ => panic!("No match"),
+    }
+}
+
 #[test]
 pub fn parse_call() {
     let engine_state = EngineState::new();
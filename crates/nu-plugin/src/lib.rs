@@ -5,6 +5,9 @@ mod serializers;
 #[allow(dead_code)]
 mod plugin_capnp;
 
-pub use plugin::{get_signature, serve_plugin, Plugin, PluginDeclaration};
-pub use protocol::{EvaluatedCall, LabeledError};
+pub use plugin::{
+    get_signature, plugin_hash, plugin_manager, plugin_modified, serve_plugin,
+    serve_streaming_plugin, EngineInterface, Plugin, PluginDeclaration, PluginManager, RunningPlugin, StreamingPlugin,
+};
+pub use protocol::{EvaluatedCall, LabeledError, PluginCustomValue};
 pub use serializers::{capnp::CapnpSerializer, json::JsonSerializer, EncodingType};
@@ -1,13 +1,19 @@
 use crate::{EncodingType, EvaluatedCall};
 
-use super::{create_command, OUTPUT_BUFFER_SIZE};
-use crate::protocol::{CallInfo, PluginCall, PluginResponse};
-use std::io::BufReader;
+use super::manager::PluginCallHandle;
+use super::plugin_manager;
+use crate::protocol::{
+    add_plugin_source, prepare_for_plugin, CallInfo, EngineCall, EngineCallResponse, EvalBlock,
+    LabeledError, PluginCall, PluginResponse,
+};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
+use nu_engine::eval_block;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{ast::Call, Signature, Value};
-use nu_protocol::{PipelineData, ShellError};
+use nu_protocol::{ast::Call, Signature, Span, Value, CONFIG_VARIABLE_ID};
+use nu_protocol::{PipelineData, ShellError, ValueStream};
 
 #[derive(Clone)]
 pub struct PluginDeclaration {
@@ -15,15 +21,25 @@ pub struct PluginDeclaration {
     signature: Signature,
     filename: PathBuf,
     encoding: EncodingType,
+    modified: Option<u64>,
+    hash: Option<String>,
 }
 
 impl PluginDeclaration {
-    pub fn new(filename: PathBuf, signature: Signature, encoding: EncodingType) -> Self {
+    pub fn new(
+        filename: PathBuf,
+        signature: Signature,
+        encoding: EncodingType,
+        modified: Option<u64>,
+        hash: Option<String>,
+    ) -> Self {
         Self {
             name: signature.name.clone(),
             signature,
             filename,
             encoding,
+            modified,
+            hash,
         }
     }
 }
@@ -48,90 +64,304 @@ impl Command for PluginDeclaration {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        // Call the command with self path
-        // Decode information from plugin
-        // Create PipelineData
-        let source_file = Path::new(&self.filename);
-        let mut plugin_cmd = create_command(source_file);
+        // A stream is forwarded to the plugin item by item instead of being collected first
+        let (input, input_stream) = match input {
+            PipelineData::Value(value, ..) => (prepare_for_plugin(value, &self.filename), None),
+            PipelineData::Stream(stream, ..) => (Value::Nothing { span: call.head }, Some(stream)),
+        };
+
+        // Custom values are only sent as they are to the plugin that created them
+        let call_info = Box::new(CallInfo {
+            name: self.name.clone(),
+            call: EvaluatedCall::try_from_call(call, engine_state, stack)?
+                .map_values(|value| prepare_for_plugin(value, &self.filename)),
+            input,
+        });
+        let plugin_call = match input_stream {
+            Some(..) => PluginCall::StreamStart(call_info),
+            None => PluginCall::CallInfo(call_info),
+        };
 
-        let mut child = plugin_cmd.spawn().map_err(|err| {
+        let to_shell_error = |err: ShellError| {
             let decl = engine_state.get_decl(call.decl_id);
             ShellError::SpannedLabeledError(
-                format!("Unable to spawn plugin for {}", decl.name()),
-                format!("{}", err),
+                format!("Unable to call plugin for {}", decl.name()),
+                err.to_string(),
                 call.head,
             )
-        })?;
+        };
 
-        let input = match input {
-            PipelineData::Value(value, ..) => value,
-            PipelineData::Stream(stream, ..) => {
-                let values = stream.collect::<Vec<Value>>();
+        // The plugin process is kept running between calls by the plugin manager
+        let handle = plugin_manager()
+            .start_call(&self.filename, &self.encoding, &plugin_call)
+            .map_err(to_shell_error)?;
 
-                Value::List {
-                    vals: values,
-                    span: call.head,
-                }
-            }
-        };
+        if let Some(stream) = input_stream {
+            let sender = handle.sender();
+            let ctrlc = engine_state.ctrlc.clone();
+            let filename = self.filename.clone();
 
-        // Create message to plugin to indicate that signature is required and
-        // send call to plugin asking for signature
-        if let Some(mut stdin_writer) = child.stdin.take() {
-            let encoding_clone = self.encoding.clone();
-            let plugin_call = PluginCall::CallInfo(Box::new(CallInfo {
-                name: self.name.clone(),
-                call: EvaluatedCall::try_from_call(call, engine_state, stack)?,
-                input,
-            }));
+            // Sending the input from its own thread lets the plugin answer while it reads
             std::thread::spawn(move || {
-                // PluginCall information
-                encoding_clone.encode_call(&plugin_call, &mut stdin_writer)
+                for value in stream {
+                    if is_interrupted(&ctrlc) {
+                        break;
+                    }
+                    let value = prepare_for_plugin(value, &filename);
+                    if sender
+                        .send(&PluginCall::StreamItem(Box::new(value)))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                let _ = sender.send(&PluginCall::StreamEnd);
             });
         }
 
-        // Deserialize response from plugin to extract the resulting value
-        let pipeline_data = if let Some(stdout_reader) = &mut child.stdout {
-            let reader = stdout_reader;
-            let mut buf_read = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, reader);
-
-            let response = self
-                .encoding
-                .decode_response(&mut buf_read)
-                .map_err(|err| {
-                    let decl = engine_state.get_decl(call.decl_id);
-                    ShellError::SpannedLabeledError(
-                        format!("Unable to decode call for {}", decl.name()),
-                        err.to_string(),
-                        call.head,
-                    )
-                })?;
-
-            match response {
-                PluginResponse::Value(value) => {
-                    Ok(PipelineData::Value(value.as_ref().clone(), None))
+        // The plugin can call back into the engine any number of times before it answers
+        let response = loop {
+            match handle.recv().map_err(to_shell_error)? {
+                PluginResponse::EngineCall(engine_call) => {
+                    let response = handle_engine_call(
+                        engine_call,
+                        engine_state,
+                        stack,
+                        &self.filename,
+                        &self.encoding,
+                    );
+                    handle
+                        .sender()
+                        .send(&PluginCall::EngineCallResponse(response))
+                        .map_err(to_shell_error)?;
                 }
-                PluginResponse::Error(err) => Err(err.into()),
-                PluginResponse::Signature(..) => Err(ShellError::SpannedLabeledError(
+                response => break response,
+            }
+        };
+
+        match response {
+            PluginResponse::Value(value) => Ok(PipelineData::Value(
+                add_plugin_source(*value, &self.filename, &self.encoding),
+                None,
+            )),
+            PluginResponse::StreamStart => {
+                let ctrlc = engine_state.ctrlc.clone();
+                let output = PluginOutput {
+                    handle,
+                    filename: self.filename.clone(),
+                    encoding: self.encoding.clone(),
+                    engine_state: engine_state.clone(),
+                    stack: stack.clone(),
+                    ctrlc: ctrlc.clone(),
+                    finished: false,
+                };
+
+                Ok(PipelineData::Stream(
+                    ValueStream::from_stream(output, ctrlc),
+                    None,
+                ))
+            }
+            PluginResponse::Error(err) => Err(err.into()),
+            PluginResponse::Signature(..) => Err(ShellError::SpannedLabeledError(
+                "Plugin missing value".into(),
+                "Received a signature from plugin instead of value".into(),
+                call.head,
+            )),
+            PluginResponse::StreamItem(..) | PluginResponse::StreamEnd => {
+                Err(ShellError::SpannedLabeledError(
                     "Plugin missing value".into(),
-                    "Received a signature from plugin instead of value".into(),
+                    "Received stream items from plugin before the stream started".into(),
                     call.head,
-                )),
+                ))
             }
-        } else {
-            Err(ShellError::SpannedLabeledError(
-                "Error with stdout reader".into(),
-                "no stdout reader".into(),
-                call.head,
-            ))
-        }?;
-
-        // There is no need to wait for the child process to finish
-        // The response has been collected from the plugin call
-        Ok(pipeline_data)
+            PluginResponse::EngineCall(..) => unreachable!("engine calls are answered above"),
+        }
     }
 
     fn is_plugin(&self) -> Option<(&PathBuf, &str)> {
         Some((&self.filename, self.encoding.to_str()))
     }
+
+    fn plugin_modified(&self) -> Option<u64> {
+        self.modified
+    }
+
+    fn plugin_hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+}
+
+// Answers a call the plugin makes back into the engine, in the scope the plugin was called from
+fn handle_engine_call(
+    engine_call: EngineCall,
+    engine_state: &EngineState,
+    stack: &Stack,
+    filename: &Path,
+    encoding: &EncodingType,
+) -> EngineCallResponse {
+    let result = match engine_call {
+        EngineCall::GetConfig => Ok(stack.get_var(CONFIG_VARIABLE_ID).unwrap_or(Value::Nothing {
+            span: Span::unknown(),
+        })),
+        EngineCall::GetEnv(name) => Ok(stack.get_env_var(&name).unwrap_or(Value::Nothing {
+            span: Span::unknown(),
+        })),
+        EngineCall::GetCurrentDir => current_dir(stack),
+        EngineCall::EvalBlock(eval) => {
+            // Custom values the plugin passes to the block stay tied to the plugin
+            let eval = EvalBlock {
+                positional: eval
+                    .positional
+                    .into_iter()
+                    .map(|value| add_plugin_source(value, filename, encoding))
+                    .collect(),
+                input: add_plugin_source(eval.input, filename, encoding),
+                ..*eval
+            };
+            eval_plugin_block(eval, engine_state, stack)
+        }
+    };
+
+    match result {
+        Ok(value) => EngineCallResponse::Value(Box::new(prepare_for_plugin(value, filename))),
+        Err(err) => EngineCallResponse::Error(LabeledError::from(err)),
+    }
+}
+
+fn current_dir(stack: &Stack) -> Result<Value, ShellError> {
+    if let Some(pwd) = stack.get_env_var("PWD") {
+        return Ok(pwd);
+    }
+
+    let cwd = std::env::current_dir().map_err(|err| {
+        ShellError::LabeledError(
+            "Unable to get the current directory".into(),
+            err.to_string(),
+        )
+    })?;
+
+    Ok(Value::String {
+        val: cwd.to_string_lossy().to_string(),
+        span: Span::unknown(),
+    })
+}
+
+fn eval_plugin_block(
+    eval: EvalBlock,
+    engine_state: &EngineState,
+    stack: &Stack,
+) -> Result<Value, ShellError> {
+    let EvalBlock {
+        block_id,
+        span,
+        positional,
+        input,
+    } = eval;
+
+    // The id comes from the plugin, so it is checked before the block is looked up
+    if block_id >= engine_state.num_blocks() {
+        return Err(ShellError::SpannedLabeledError(
+            "Unknown block".into(),
+            "the plugin asked to evaluate a block that doesn't exist".into(),
+            span,
+        ));
+    }
+
+    let block = engine_state.get_block(block_id);
+    let mut stack = stack.collect_captures(&block.captures);
+
+    for (position, value) in positional.into_iter().enumerate() {
+        if let Some(var_id) = block
+            .signature
+            .get_positional(position)
+            .and_then(|param| param.var_id)
+        {
+            stack.add_var(var_id, value);
+        }
+    }
+
+    let result = eval_block(
+        engine_state,
+        &mut stack,
+        block,
+        PipelineData::Value(input, None),
+    )?;
+
+    Ok(result.into_value(span))
+}
+
+fn is_interrupted(ctrlc: &Option<Arc<AtomicBool>>) -> bool {
+    match ctrlc {
+        Some(ctrlc) => ctrlc.load(Ordering::SeqCst),
+        None => false,
+    }
+}
+
+// The output stream of a plugin. If it is dropped before the plugin has finished, eg) on ctrl-c,
+// the plugin is told to stop producing values.
+struct PluginOutput {
+    handle: PluginCallHandle,
+    filename: PathBuf,
+    encoding: EncodingType,
+    // Kept to answer the engine calls the plugin makes while it streams its output
+    engine_state: EngineState,
+    stack: Stack,
+    ctrlc: Option<Arc<AtomicBool>>,
+    finished: bool,
+}
+
+impl Iterator for PluginOutput {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || is_interrupted(&self.ctrlc) {
+            return None;
+        }
+
+        let mut response = self.handle.recv();
+        while let Ok(PluginResponse::EngineCall(engine_call)) = response {
+            let engine_response = handle_engine_call(
+                engine_call,
+                &self.engine_state,
+                &self.stack,
+                &self.filename,
+                &self.encoding,
+            );
+            if let Err(error) = self
+                .handle
+                .sender()
+                .send(&PluginCall::EngineCallResponse(engine_response))
+            {
+                self.finished = true;
+                return Some(Value::Error { error });
+            }
+            response = self.handle.recv();
+        }
+
+        match response {
+            Ok(PluginResponse::StreamItem(value)) => {
+                Some(add_plugin_source(*value, &self.filename, &self.encoding))
+            }
+            Ok(PluginResponse::Error(err)) => {
+                self.finished = true;
+                Some(Value::Error { error: err.into() })
+            }
+            Ok(_) => {
+                self.finished = true;
+                None
+            }
+            Err(error) => {
+                self.finished = true;
+                Some(Value::Error { error })
+            }
+        }
+    }
+}
+
+impl Drop for PluginOutput {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.handle.cancel();
+        }
+    }
 }
@@ -0,0 +1,83 @@
+use super::{PluginInbox, Responder};
+use crate::protocol::{
+    decode_custom_values, encode_custom_values, EngineCall, EngineCallResponse, EvalBlock,
+    LabeledError, PluginResponse,
+};
+use std::sync::{Arc, Mutex};
+
+use nu_protocol::{Span, Value};
+
+/// Calls back into nushell while a plugin handles a call.
+///
+/// Each engine call is sent to nushell as a response to the plugin call in progress, and the
+/// plugin waits for nushell to answer it before carrying on.
+pub struct EngineInterface {
+    id: u64,
+    inbox: Arc<Mutex<PluginInbox>>,
+    respond: Responder,
+}
+
+impl EngineInterface {
+    pub(super) fn new(id: u64, inbox: Arc<Mutex<PluginInbox>>, respond: Responder) -> Self {
+        Self { id, inbox, respond }
+    }
+
+    /// The `$config` of the shell, or nothing if no config has been loaded
+    pub fn get_config(&self) -> Result<Value, LabeledError> {
+        self.engine_call(EngineCall::GetConfig)
+    }
+
+    /// An environment variable of the shell, as it is set in the scope the plugin was called from
+    pub fn get_env_var(&self, name: &str) -> Result<Option<Value>, LabeledError> {
+        match self.engine_call(EngineCall::GetEnv(name.into()))? {
+            Value::Nothing { .. } => Ok(None),
+            value => Ok(Some(value)),
+        }
+    }
+
+    /// The current directory of the shell. The plugin process keeps the directory it was
+    /// started in, so relative paths have to be resolved against this one instead.
+    pub fn get_current_dir(&self) -> Result<String, LabeledError> {
+        let value = self.engine_call(EngineCall::GetCurrentDir)?;
+        value.as_string().map_err(LabeledError::from)
+    }
+
+    /// Evaluate a block the plugin was given, eg) as an argument of its call. The positional
+    /// values are bound to the parameters of the block in order.
+    pub fn eval_block(
+        &self,
+        block: &Value,
+        positional: Vec<Value>,
+        input: Value,
+    ) -> Result<Value, LabeledError> {
+        let block_id = block.as_block().map_err(LabeledError::from)?;
+
+        self.engine_call(EngineCall::EvalBlock(Box::new(EvalBlock {
+            block_id,
+            span: block.span().unwrap_or_else(|_| Span::unknown()),
+            positional: positional.into_iter().map(encode_custom_values).collect(),
+            input: encode_custom_values(input),
+        })))
+    }
+
+    fn engine_call(&self, engine_call: EngineCall) -> Result<Value, LabeledError> {
+        (self.respond)(self.id, PluginResponse::EngineCall(engine_call));
+
+        let response = self
+            .inbox
+            .lock()
+            .expect("plugin inbox lock poisoned")
+            .next_engine_response(self.id);
+
+        match response {
+            Some(Ok(EngineCallResponse::Value(value))) => Ok(decode_custom_values(*value)),
+            Some(Ok(EngineCallResponse::Error(err))) => Err(err),
+            Some(Err(err)) => Err(err.into()),
+            None => Err(LabeledError {
+                label: "Engine call failed".into(),
+                msg: "the call was cancelled before nushell answered".into(),
+                span: None,
+            }),
+        }
+    }
+}
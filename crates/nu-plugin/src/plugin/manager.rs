@@ -0,0 +1,446 @@
+use super::{create_command, read_frame, write_frame, OUTPUT_BUFFER_SIZE};
+use crate::protocol::{EngineCallResponse, LabeledError, PluginCall, PluginResponse};
+use crate::EncodingType;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use nu_protocol::ShellError;
+
+// How long a plugin gets to exit by itself after its stdin is closed before it is killed
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+lazy_static! {
+    static ref PLUGIN_MANAGER: PluginManager = PluginManager::default();
+}
+
+/// The manager keeping the plugin processes of this nushell alive
+pub fn plugin_manager() -> &'static PluginManager {
+    &PLUGIN_MANAGER
+}
+
+type PendingCalls = Arc<Mutex<HashMap<u64, mpsc::Sender<Result<PluginResponse, ShellError>>>>>;
+
+/// Information about a running plugin process
+#[derive(Debug, Clone)]
+pub struct RunningPlugin {
+    pub filename: PathBuf,
+    pub encoding: &'static str,
+    pub pid: u32,
+}
+
+/// Keeps one process running per plugin, so plugins only pay their start up cost once.
+///
+/// Plugins are started the first time they are called, and started again if they have exited or
+/// crashed since the last call.
+#[derive(Default)]
+pub struct PluginManager {
+    plugins: Mutex<HashMap<PathBuf, Arc<PluginProcess>>>,
+}
+
+impl PluginManager {
+    /// Send a call to the plugin and wait for its response, starting the plugin if it isn't
+    /// running yet.
+    ///
+    /// There is no engine to answer calls back into it from here, so the plugin gets an error for
+    /// any engine call it makes while handling the call.
+    pub fn call(
+        &self,
+        filename: &Path,
+        encoding: &EncodingType,
+        plugin_call: &PluginCall,
+    ) -> Result<PluginResponse, ShellError> {
+        let handle = self.start_call(filename, encoding, plugin_call)?;
+
+        loop {
+            match handle.recv()? {
+                PluginResponse::EngineCall(_) => {
+                    handle.sender().send(&PluginCall::EngineCallResponse(
+                        EngineCallResponse::Error(LabeledError {
+                            label: "Engine not available".into(),
+                            msg: "the engine can't be called back at this point".into(),
+                            span: None,
+                        }),
+                    ))?;
+                }
+                response => return Ok(response),
+            }
+        }
+    }
+
+    /// Send a call to the plugin without waiting for the response, so stream messages can be
+    /// exchanged for it through the returned handle
+    pub fn start_call(
+        &self,
+        filename: &Path,
+        encoding: &EncodingType,
+        plugin_call: &PluginCall,
+    ) -> Result<PluginCallHandle, ShellError> {
+        let process = self.get_or_start(filename, encoding)?;
+
+        let (process, (id, responses)) = match process.start_call(plugin_call) {
+            Ok(started) => (process, started),
+            // The plugin died since it was last used, so give a fresh process one more try
+            Err(CallError::NotRunning) => {
+                let process = self.get_or_start(filename, encoding)?;
+                let started = process.start_call(plugin_call)?;
+                (process, started)
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(PluginCallHandle {
+            sender: PluginCallSender { process, id },
+            responses,
+        })
+    }
+
+    /// The plugins that currently have a running process
+    pub fn running(&self) -> Vec<RunningPlugin> {
+        let plugins = self.plugins.lock().expect("plugin manager lock poisoned");
+
+        let mut running: Vec<RunningPlugin> = plugins
+            .values()
+            .filter(|process| process.is_running())
+            .map(|process| RunningPlugin {
+                filename: process.filename.clone(),
+                encoding: process.encoding.to_str(),
+                pid: process.pid,
+            })
+            .collect();
+
+        running.sort_by(|a, b| a.filename.cmp(&b.filename));
+        running
+    }
+
+    /// Stop the process of a plugin. Returns false if the plugin wasn't running.
+    pub fn stop(&self, filename: &Path) -> bool {
+        let process = self
+            .plugins
+            .lock()
+            .expect("plugin manager lock poisoned")
+            .remove(filename);
+
+        match process {
+            Some(process) => {
+                let was_running = process.is_running();
+                process.stop();
+                was_running
+            }
+            None => false,
+        }
+    }
+
+    /// Stop all the plugin processes, eg) when nushell exits
+    pub fn stop_all(&self) {
+        let plugins: Vec<Arc<PluginProcess>> = self
+            .plugins
+            .lock()
+            .expect("plugin manager lock poisoned")
+            .drain()
+            .map(|(_, process)| process)
+            .collect();
+
+        for process in plugins {
+            process.stop();
+        }
+    }
+
+    fn get_or_start(
+        &self,
+        filename: &Path,
+        encoding: &EncodingType,
+    ) -> Result<Arc<PluginProcess>, ShellError> {
+        // Starting and stopping processes can take a while, so it happens outside the lock to
+        // keep calls to other plugins going in the meantime
+        let exited = {
+            let mut plugins = self.plugins.lock().expect("plugin manager lock poisoned");
+
+            match plugins.get(filename).cloned() {
+                Some(process) if process.is_running() => return Ok(process),
+                Some(_) => plugins.remove(filename),
+                None => None,
+            }
+        };
+
+        // Reap the exited process before starting a new one
+        if let Some(process) = exited {
+            process.stop();
+        }
+
+        let process = Arc::new(PluginProcess::start(filename, encoding)?);
+
+        let mut plugins = self.plugins.lock().expect("plugin manager lock poisoned");
+        match plugins.get(filename).cloned() {
+            // Another call started the plugin while this one was, so keep using theirs
+            Some(running) if running.is_running() => {
+                drop(plugins);
+                process.stop();
+
+                Ok(running)
+            }
+            _ => {
+                // Anything still here has exited, so it is stopped once the lock is released
+                let exited = plugins.insert(filename.to_path_buf(), process.clone());
+                drop(plugins);
+                if let Some(exited) = exited {
+                    exited.stop();
+                }
+
+                Ok(process)
+            }
+        }
+    }
+}
+
+/// A call in progress with a plugin
+pub struct PluginCallHandle {
+    sender: PluginCallSender,
+    responses: mpsc::Receiver<Result<PluginResponse, ShellError>>,
+}
+
+impl PluginCallHandle {
+    /// Wait for the next response from the plugin for this call
+    pub fn recv(&self) -> Result<PluginResponse, ShellError> {
+        match self.responses.recv() {
+            Ok(response) => response,
+            Err(_) => Err(ShellError::PluginFailedToDecode(
+                "plugin sent no more responses for the call".into(),
+            )),
+        }
+    }
+
+    /// A sender for more messages of this call, eg) the items of a stream
+    pub fn sender(&self) -> PluginCallSender {
+        self.sender.clone()
+    }
+
+    /// Tell the plugin that no more output is wanted for this call
+    pub fn cancel(&self) {
+        let _ = self.sender.send(&PluginCall::Cancel);
+    }
+}
+
+impl Drop for PluginCallHandle {
+    fn drop(&mut self) {
+        self.sender.process.forget(self.sender.id);
+    }
+}
+
+/// Sends the messages that follow the start of a call, which can happen from another thread
+#[derive(Clone)]
+pub struct PluginCallSender {
+    process: Arc<PluginProcess>,
+    id: u64,
+}
+
+impl PluginCallSender {
+    /// Send a message for the call. Fails once the call has finished, so whoever is sending
+    /// its input knows to stop.
+    pub fn send(&self, plugin_call: &PluginCall) -> Result<(), ShellError> {
+        if !self.process.is_pending(self.id) {
+            return Err(ShellError::PluginFailedToEncode(
+                "plugin call has already finished".into(),
+            ));
+        }
+
+        self.process
+            .send(self.id, plugin_call)
+            .map_err(ShellError::from)
+    }
+}
+
+enum CallError {
+    NotRunning,
+    Shell(ShellError),
+}
+
+impl From<CallError> for ShellError {
+    fn from(error: CallError) -> Self {
+        match error {
+            CallError::NotRunning => {
+                ShellError::PluginFailedToLoad("plugin process is not running".into())
+            }
+            CallError::Shell(error) => error,
+        }
+    }
+}
+
+/// A running plugin. Calls are tagged with an id so several of them can be in flight at the same
+/// time, and a reader thread hands each response to the call waiting for it.
+struct PluginProcess {
+    filename: PathBuf,
+    encoding: EncodingType,
+    pid: u32,
+    child: Mutex<Child>,
+    // Taken when the plugin is stopped, closing its stdin tells it to exit
+    stdin: Mutex<Option<ChildStdin>>,
+    pending: PendingCalls,
+    running: Arc<AtomicBool>,
+    next_id: AtomicU64,
+}
+
+impl PluginProcess {
+    fn start(filename: &Path, encoding: &EncodingType) -> Result<Self, ShellError> {
+        let mut child = create_command(filename).spawn().map_err(|err| {
+            ShellError::PluginFailedToLoad(format!("Error spawning child process: {}", err))
+        })?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| ShellError::PluginFailedToLoad("Plugin missing stdin writer".into()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| ShellError::PluginFailedToLoad("Plugin missing stdout reader".into()))?;
+
+        let pending: PendingCalls = Arc::new(Mutex::new(HashMap::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let reader_encoding = encoding.clone();
+        let reader_pending = pending.clone();
+        let reader_running = running.clone();
+        std::thread::spawn(move || {
+            let mut reader = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, stdout);
+
+            while let Ok(Some((id, payload))) = read_frame(&mut reader) {
+                let response = reader_encoding.decode_response(&mut payload.as_slice());
+
+                // A streaming call stays pending until its stream has ended, and any call stays
+                // pending while the plugin waits for the answer to an engine call
+                let stream_continues = matches!(
+                    response,
+                    Ok(PluginResponse::StreamStart)
+                        | Ok(PluginResponse::StreamItem(..))
+                        | Ok(PluginResponse::EngineCall(..))
+                );
+
+                let mut pending = reader_pending.lock().expect("plugin calls lock poisoned");
+                let sender = if stream_continues {
+                    pending.get(&id).cloned()
+                } else {
+                    pending.remove(&id)
+                };
+                drop(pending);
+
+                if let Some(sender) = sender {
+                    let _ = sender.send(response);
+                }
+            }
+
+            // The plugin closed its stdout, so nothing that is still waiting will be answered
+            reader_running.store(false, Ordering::SeqCst);
+            for (_, sender) in reader_pending
+                .lock()
+                .expect("plugin calls lock poisoned")
+                .drain()
+            {
+                let _ = sender.send(Err(ShellError::PluginFailedToDecode(
+                    "plugin exited before responding".into(),
+                )));
+            }
+        });
+
+        Ok(Self {
+            filename: filename.to_path_buf(),
+            encoding: encoding.clone(),
+            pid: child.id(),
+            child: Mutex::new(child),
+            stdin: Mutex::new(Some(stdin)),
+            pending,
+            running,
+            next_id: AtomicU64::new(0),
+        })
+    }
+
+    fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    fn start_call(
+        &self,
+        plugin_call: &PluginCall,
+    ) -> Result<(u64, mpsc::Receiver<Result<PluginResponse, ShellError>>), CallError> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = mpsc::channel();
+
+        self.pending
+            .lock()
+            .expect("plugin calls lock poisoned")
+            .insert(id, sender);
+
+        // The reader thread marks the plugin as stopped before failing the pending calls, so a
+        // call registered after that is caught here instead of waiting forever
+        if !self.is_running() {
+            self.forget(id);
+            return Err(CallError::NotRunning);
+        }
+
+        if let Err(err) = self.send(id, plugin_call) {
+            self.forget(id);
+            return Err(err);
+        }
+
+        Ok((id, receiver))
+    }
+
+    fn send(&self, id: u64, plugin_call: &PluginCall) -> Result<(), CallError> {
+        let mut payload = vec![];
+        self.encoding
+            .encode_call(plugin_call, &mut payload)
+            .map_err(CallError::Shell)?;
+
+        match self
+            .stdin
+            .lock()
+            .expect("plugin stdin lock poisoned")
+            .as_mut()
+        {
+            Some(stdin) => write_frame(stdin, id, &payload).map_err(|_| CallError::NotRunning),
+            None => Err(CallError::NotRunning),
+        }
+    }
+
+    fn is_pending(&self, id: u64) -> bool {
+        self.pending
+            .lock()
+            .expect("plugin calls lock poisoned")
+            .contains_key(&id)
+    }
+
+    fn forget(&self, id: u64) {
+        self.pending
+            .lock()
+            .expect("plugin calls lock poisoned")
+            .remove(&id);
+    }
+
+    /// Close the plugin's stdin so it can finish up, killing it if it doesn't exit in time
+    fn stop(&self) {
+        let _ = self
+            .stdin
+            .lock()
+            .expect("plugin stdin lock poisoned")
+            .take();
+
+        let mut child = self.child.lock().expect("plugin child lock poisoned");
+        let start = Instant::now();
+
+        while start.elapsed() < SHUTDOWN_TIMEOUT {
+            match child.try_wait() {
+                Ok(Some(_)) => return,
+                Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+                Err(_) => break,
+            }
+        }
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
@@ -1,14 +1,24 @@
 mod declaration;
+mod engine;
+mod manager;
 pub use declaration::PluginDeclaration;
+pub use engine::EngineInterface;
+pub use manager::{plugin_manager, PluginManager, RunningPlugin};
 
-use crate::protocol::{LabeledError, PluginCall, PluginResponse};
+use crate::protocol::{
+    decode_custom_values, encode_custom_values, CallInfo, CustomValueCall, CustomValueOp,
+    EngineCallResponse, LabeledError, PluginCall, PluginResponse,
+};
 use crate::EncodingType;
-use std::io::BufReader;
+use std::collections::{HashSet, VecDeque};
+use std::io::{BufReader, ErrorKind, Read, Write};
 use std::path::Path;
 use std::process::{Command as CommandSys, Stdio};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::UNIX_EPOCH;
 
 use nu_protocol::ShellError;
-use nu_protocol::{Signature, Value};
+use nu_protocol::{PipelineData, Signature, Value, ValueStream};
 
 use super::EvaluatedCall;
 
@@ -55,58 +65,286 @@ fn create_command(path: &Path) -> CommandSys {
     process
 }
 
-pub fn get_signature(path: &Path, encoding: &EncodingType) -> Result<Vec<Signature>, ShellError> {
-    let mut plugin_cmd = create_command(path);
+// Every message between nushell and a plugin is framed with the id of the call it belongs to
+// and its length. This lets several calls share one plugin process, and keeps the encoders from
+// reading past the end of a message.
+fn write_frame(writer: &mut impl Write, id: u64, payload: &[u8]) -> std::io::Result<()> {
+    writer.write_all(&id.to_le_bytes())?;
+    writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+    writer.write_all(payload)?;
+    writer.flush()
+}
 
-    let mut child = plugin_cmd.spawn().map_err(|err| {
-        ShellError::PluginFailedToLoad(format!("Error spawning child process: {}", err))
-    })?;
+// Returns None when the other side has closed the stream
+fn read_frame(reader: &mut impl Read) -> std::io::Result<Option<(u64, Vec<u8>)>> {
+    let mut id = [0u8; 8];
+    match reader.read_exact(&mut id) {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
 
-    // Create message to plugin to indicate that signature is required and
-    // send call to plugin asking for signature
-    if let Some(mut stdin_writer) = child.stdin.take() {
-        let encoding_clone = encoding.clone();
-        std::thread::spawn(move || {
-            encoding_clone.encode_call(&PluginCall::Signature, &mut stdin_writer)
-        });
+    let mut len = [0u8; 8];
+    reader.read_exact(&mut len)?;
+
+    let mut payload = vec![0u8; u64::from_le_bytes(len) as usize];
+    reader.read_exact(&mut payload)?;
+
+    Ok(Some((u64::from_le_bytes(id), payload)))
+}
+
+pub fn get_signature(path: &Path, encoding: &EncodingType) -> Result<Vec<Signature>, ShellError> {
+    // Asking for the signature starts the plugin, which is then kept running for its calls
+    match plugin_manager().call(path, encoding, &PluginCall::Signature)? {
+        PluginResponse::Signature(sign) => Ok(sign),
+        PluginResponse::Error(err) => Err(err.into()),
+        _ => Err(ShellError::PluginFailedToLoad(
+            "Plugin missing signature".into(),
+        )),
     }
+}
 
-    // deserialize response from plugin to extract the signature
-    let signatures = if let Some(stdout_reader) = &mut child.stdout {
-        let reader = stdout_reader;
-        let mut buf_read = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, reader);
-        let response = encoding.decode_response(&mut buf_read)?;
+/// The modification time of the plugin's file in seconds since the unix epoch, which tells if the
+/// signatures saved for the plugin are still up to date
+pub fn plugin_modified(path: &Path) -> Option<u64> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|modified| modified.as_secs())
+}
 
-        match response {
-            PluginResponse::Signature(sign) => Ok(sign),
-            PluginResponse::Error(err) => Err(err.into()),
-            _ => Err(ShellError::PluginFailedToLoad(
-                "Plugin missing signature".into(),
-            )),
-        }
-    } else {
-        Err(ShellError::PluginFailedToLoad(
-            "Plugin missing stdout reader".into(),
-        ))
-    }?;
+/// A hash of the contents of the plugin's file, which tells if the plugin has really changed when
+/// only its modification time has
+pub fn plugin_hash(path: &Path) -> Option<String> {
+    use sha2::{Digest, Sha256};
 
-    // There is no need to wait for the child process to finish since the
-    // signature has being collected
-    Ok(signatures)
+    std::fs::read(path)
+        .ok()
+        .map(|contents| format!("{:x}", Sha256::digest(&contents)))
 }
 
 // The next trait and functions are part of the plugin that is being created
 // The `Plugin` trait defines the API which plugins use to "hook" into nushell.
+// The engine passed to `run` lets the plugin call back into nushell while it handles the call,
+// eg) to read the environment or to evaluate a block it was given.
 pub trait Plugin {
     fn signature(&self) -> Vec<Signature>;
     fn run(
         &mut self,
         name: &str,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: &Value,
     ) -> Result<Value, LabeledError>;
 }
 
+// Plugins that work on streams implement this trait instead of `Plugin`. When nushell passes
+// them a stream, their input is a `PipelineData::Stream` that receives its values lazily, and
+// they can answer with a stream of their own.
+pub trait StreamingPlugin {
+    fn signature(&self) -> Vec<Signature>;
+    fn run(
+        &mut self,
+        name: &str,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError>;
+}
+
+// Serves a `Plugin` like a streaming one, collecting streamed input into a list
+struct CollectingPlugin<'a, P: Plugin>(&'a mut P);
+
+impl<'a, P: Plugin> StreamingPlugin for CollectingPlugin<'a, P> {
+    fn signature(&self) -> Vec<Signature> {
+        self.0.signature()
+    }
+
+    fn run(
+        &mut self,
+        name: &str,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let input = input.into_value(call.head);
+
+        self.0
+            .run(name, engine, call, &input)
+            .map(|value| PipelineData::Value(value, None))
+    }
+}
+
+type ReceivedCall = (u64, Result<PluginCall, ShellError>);
+
+// Encodes a response and writes it to stdout. It is shared with the engine interfaces, which
+// send their engine calls the same way.
+type Responder = Arc<dyn Fn(u64, PluginResponse) + Send + Sync>;
+
+// The calls a plugin receives from nushell. They are read on their own thread, so the plugin
+// notices a cancelled call while it is still producing output.
+struct PluginInbox {
+    receiver: mpsc::Receiver<ReceivedCall>,
+    // Messages that arrived while waiting for a message of a different kind or call, eg) the
+    // input of a streaming call while waiting for the answer to an engine call
+    deferred: VecDeque<ReceivedCall>,
+    cancelled: Arc<Mutex<HashSet<u64>>>,
+}
+
+impl PluginInbox {
+    // Only a few stream items are read ahead, so nushell doesn't send more input than the
+    // plugin is using
+    const READ_AHEAD: usize = 16;
+
+    fn start(encoder: impl PluginEncoder + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(Self::READ_AHEAD);
+        let cancelled = Arc::new(Mutex::new(HashSet::new()));
+
+        let reader_cancelled = cancelled.clone();
+        std::thread::spawn(move || {
+            let mut stdin_buf = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, std::io::stdin());
+
+            while let Ok(Some((id, payload))) = read_frame(&mut stdin_buf) {
+                let plugin_call = encoder.decode_call(&mut payload.as_slice());
+
+                // The cancel is still passed on, to wake up a call that is waiting for a message
+                if let Ok(PluginCall::Cancel) = plugin_call {
+                    reader_cancelled
+                        .lock()
+                        .expect("cancelled calls lock poisoned")
+                        .insert(id);
+                }
+
+                if sender.send((id, plugin_call)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            receiver,
+            deferred: VecDeque::new(),
+            cancelled,
+        }
+    }
+
+    // The next call to handle, or None once nushell has closed the plugin's stdin. Left over
+    // messages of calls that already finished are skipped.
+    fn next_call(&mut self) -> Option<ReceivedCall> {
+        loop {
+            let (id, plugin_call) = match self.deferred.pop_front() {
+                Some(received) => received,
+                None => self.receiver.recv().ok()?,
+            };
+
+            match plugin_call {
+                Ok(PluginCall::StreamItem(..))
+                | Ok(PluginCall::StreamEnd)
+                | Ok(PluginCall::EngineCallResponse(..))
+                | Ok(PluginCall::Cancel) => continue,
+                plugin_call => return Some((id, plugin_call)),
+            }
+        }
+    }
+
+    // The next input value of a streaming call, or None once its input has ended
+    fn next_item(&mut self, id: u64) -> Option<Value> {
+        let is_item = |plugin_call: &Result<PluginCall, ShellError>| {
+            matches!(
+                plugin_call,
+                Ok(PluginCall::StreamItem(..)) | Ok(PluginCall::StreamEnd) | Err(..)
+            )
+        };
+
+        match self.next_message(id, is_item)? {
+            Ok(PluginCall::StreamItem(value)) => Some(*value),
+            Err(error) => Some(Value::Error { error }),
+            _ => None,
+        }
+    }
+
+    // The answer to the engine call the call has just made, or None if the call was cancelled
+    // before nushell answered
+    fn next_engine_response(&mut self, id: u64) -> Option<Result<EngineCallResponse, ShellError>> {
+        let is_response = |plugin_call: &Result<PluginCall, ShellError>| {
+            matches!(
+                plugin_call,
+                Ok(PluginCall::EngineCallResponse(..)) | Err(..)
+            )
+        };
+
+        match self.next_message(id, is_response)? {
+            Ok(PluginCall::EngineCallResponse(response)) => Some(Ok(response)),
+            Err(error) => Some(Err(error)),
+            _ => None,
+        }
+    }
+
+    // Waits for a message of the call that is wanted, setting aside any other message for later
+    fn next_message(
+        &mut self,
+        id: u64,
+        wanted: impl Fn(&Result<PluginCall, ShellError>) -> bool,
+    ) -> Option<Result<PluginCall, ShellError>> {
+        let deferred = self
+            .deferred
+            .iter()
+            .position(|(call_id, plugin_call)| *call_id == id && wanted(plugin_call));
+        if let Some(index) = deferred {
+            return self
+                .deferred
+                .remove(index)
+                .map(|(_, plugin_call)| plugin_call);
+        }
+
+        loop {
+            if self.is_cancelled(id) {
+                return None;
+            }
+
+            let (call_id, plugin_call) = self.receiver.recv().ok()?;
+            if call_id == id && wanted(&plugin_call) {
+                return Some(plugin_call);
+            }
+
+            self.deferred.push_back((call_id, plugin_call));
+        }
+    }
+
+    fn is_cancelled(&self, id: u64) -> bool {
+        self.cancelled
+            .lock()
+            .expect("cancelled calls lock poisoned")
+            .contains(&id)
+    }
+
+    fn finish(&self, id: u64) {
+        self.cancelled
+            .lock()
+            .expect("cancelled calls lock poisoned")
+            .remove(&id);
+    }
+}
+
+// The input of a streaming call, read from the inbox as the plugin iterates over it
+struct PluginInput {
+    inbox: Arc<Mutex<PluginInbox>>,
+    id: u64,
+}
+
+impl Iterator for PluginInput {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inbox
+            .lock()
+            .expect("plugin inbox lock poisoned")
+            .next_item(self.id)
+            .map(decode_custom_values)
+    }
+}
+
 // Function used in the plugin definition for the communication protocol between
 // nushell and the external plugin.
 // When creating a new plugin you have to use this function as the main
@@ -118,44 +356,208 @@ pub trait Plugin {
 //
 // where plugin is your struct that implements the Plugin trait
 //
+// The plugin keeps answering calls until nushell closes its stdin. Each call and response
+// is preceded by the id of the call and the length of the message, both as little endian u64.
+//
 // Note. When defining a plugin in other language but Rust, you will have to compile
 // the plugin.capnp schema to create the object definitions that will be returned from
 // the plugin.
 // The object that is expected to be received by nushell is the PluginResponse struct.
 // That should be encoded correctly and sent to StdOut for nushell to decode and
 // and present its result
-pub fn serve_plugin(plugin: &mut impl Plugin, encoder: impl PluginEncoder) {
-    let mut stdin_buf = BufReader::with_capacity(OUTPUT_BUFFER_SIZE, std::io::stdin());
-    let plugin_call = encoder.decode_call(&mut stdin_buf);
-
-    match plugin_call {
-        Err(err) => {
-            let response = PluginResponse::Error(err.into());
-            encoder
-                .encode_response(&response, &mut std::io::stdout())
-                .expect("Error encoding response");
-        }
-        Ok(plugin_call) => {
-            match plugin_call {
-                // Sending the signature back to nushell to create the declaration definition
-                PluginCall::Signature => {
-                    let response = PluginResponse::Signature(plugin.signature());
-                    encoder
-                        .encode_response(&response, &mut std::io::stdout())
-                        .expect("Error encoding response");
-                }
-                PluginCall::CallInfo(call_info) => {
-                    let value = plugin.run(&call_info.name, &call_info.call, &call_info.input);
-
-                    let response = match value {
-                        Ok(value) => PluginResponse::Value(Box::new(value)),
-                        Err(err) => PluginResponse::Error(err),
-                    };
-                    encoder
-                        .encode_response(&response, &mut std::io::stdout())
-                        .expect("Error encoding response");
+pub fn serve_plugin(plugin: &mut impl Plugin, encoder: impl PluginEncoder + Send + 'static) {
+    serve_streaming_plugin(&mut CollectingPlugin(plugin), encoder)
+}
+
+// The same as `serve_plugin`, for plugins implementing the StreamingPlugin trait
+pub fn serve_streaming_plugin(
+    plugin: &mut impl StreamingPlugin,
+    encoder: impl PluginEncoder + Send + 'static,
+) {
+    let inbox = Arc::new(Mutex::new(PluginInbox::start(encoder.clone())));
+    let writer = Mutex::new((encoder, std::io::stdout()));
+
+    let respond: Responder = Arc::new(move |id: u64, response: PluginResponse| {
+        let mut writer = writer.lock().expect("plugin stdout lock poisoned");
+        let (encoder, stdout) = &mut *writer;
+
+        let mut payload = vec![];
+        encoder
+            .encode_response(&response, &mut payload)
+            .expect("Error encoding response");
+        write_frame(stdout, id, &payload).expect("Error writing response");
+    });
+
+    loop {
+        let next_call = inbox
+            .lock()
+            .expect("plugin inbox lock poisoned")
+            .next_call();
+        let (id, plugin_call) = match next_call {
+            Some(received) => received,
+            None => break,
+        };
+
+        let ((name, call), input) = match plugin_call {
+            Err(err) => {
+                respond(id, PluginResponse::Error(err.into()));
+                continue;
+            }
+            // Sending the signature back to nushell to create the declaration definition
+            Ok(PluginCall::Signature) => {
+                respond(id, PluginResponse::Signature(plugin.signature()));
+                continue;
+            }
+            Ok(PluginCall::CustomValueOp(custom_value_call)) => {
+                let response = match run_custom_value_call(*custom_value_call) {
+                    Ok(value) => PluginResponse::Value(Box::new(encode_custom_values(value))),
+                    Err(err) => PluginResponse::Error(err.into()),
+                };
+                respond(id, response);
+                continue;
+            }
+            Ok(PluginCall::CallInfo(call_info)) => {
+                let CallInfo { name, call, input } = *call_info;
+                let input = PipelineData::Value(decode_custom_values(input), None);
+                ((name, call), input)
+            }
+            Ok(PluginCall::StreamStart(call_info)) => {
+                let input = PluginInput {
+                    inbox: inbox.clone(),
+                    id,
+                };
+                (
+                    (call_info.name, call_info.call),
+                    PipelineData::Stream(ValueStream::from_stream(input, None), None),
+                )
+            }
+            Ok(_) => continue,
+        };
+
+        let call = call.map_values(decode_custom_values);
+        let engine = EngineInterface::new(id, inbox.clone(), respond.clone());
+
+        match plugin.run(&name, &engine, &call, input) {
+            Ok(PipelineData::Value(value, ..)) => respond(
+                id,
+                PluginResponse::Value(Box::new(encode_custom_values(value))),
+            ),
+            Ok(PipelineData::Stream(stream, ..)) => {
+                respond(id, PluginResponse::StreamStart);
+
+                for value in stream {
+                    if inbox
+                        .lock()
+                        .expect("plugin inbox lock poisoned")
+                        .is_cancelled(id)
+                    {
+                        break;
+                    }
+                    respond(
+                        id,
+                        PluginResponse::StreamItem(Box::new(encode_custom_values(value))),
+                    );
                 }
+
+                respond(id, PluginResponse::StreamEnd);
             }
+            Err(err) => respond(id, PluginResponse::Error(err)),
+        }
+
+        inbox.lock().expect("plugin inbox lock poisoned").finish(id);
+    }
+}
+
+// Works with a custom value nushell has handed back to the plugin that created it
+fn run_custom_value_call(custom_value_call: CustomValueCall) -> Result<Value, ShellError> {
+    let CustomValueCall { value, span, op } = custom_value_call;
+    let custom_value = value.decode()?;
+
+    match op {
+        CustomValueOp::ToBaseValue => custom_value.to_base_value(span),
+        CustomValueOp::FollowPathInt(count) => custom_value.follow_path_int(count, span),
+        CustomValueOp::FollowPathString(column_name) => {
+            custom_value.follow_path_string(column_name, span)
+        }
+        CustomValueOp::Operation(operator, op_span, right) => {
+            custom_value.operation(span, operator, op_span, &decode_custom_values(right))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Span;
+
+    #[test]
+    fn frame_round_trip() {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_frame(&mut buffer, 7, b"first").expect("unable to write frame");
+        write_frame(&mut buffer, 8, b"").expect("unable to write frame");
+
+        let mut reader = buffer.as_slice();
+        assert_eq!(
+            read_frame(&mut reader).expect("unable to read frame"),
+            Some((7, b"first".to_vec()))
+        );
+        assert_eq!(
+            read_frame(&mut reader).expect("unable to read frame"),
+            Some((8, vec![]))
+        );
+        assert_eq!(read_frame(&mut reader).expect("unable to read frame"), None);
+    }
+
+    #[test]
+    fn inbox_sets_aside_other_messages() {
+        let (sender, receiver) = mpsc::sync_channel(PluginInbox::READ_AHEAD);
+        let mut inbox = PluginInbox {
+            receiver,
+            deferred: VecDeque::new(),
+            cancelled: Arc::new(Mutex::new(HashSet::new())),
+        };
+
+        let item = Value::Int {
+            val: 1,
+            span: Span::unknown(),
+        };
+        let answer = Value::Int {
+            val: 2,
+            span: Span::unknown(),
+        };
+
+        sender
+            .send((0, Ok(PluginCall::StreamItem(Box::new(item.clone())))))
+            .expect("unable to send message");
+        sender
+            .send((1, Ok(PluginCall::Signature)))
+            .expect("unable to send message");
+        sender
+            .send((
+                0,
+                Ok(PluginCall::EngineCallResponse(EngineCallResponse::Value(
+                    Box::new(answer.clone()),
+                ))),
+            ))
+            .expect("unable to send message");
+        sender
+            .send((0, Ok(PluginCall::StreamEnd)))
+            .expect("unable to send message");
+        drop(sender);
+
+        // The stream item and the other call are kept while waiting for the engine's answer
+        match inbox.next_engine_response(0) {
+            Some(Ok(EngineCallResponse::Value(value))) => assert_eq!(*value, answer),
+            _ => panic!("expected the engine call response"),
+        }
+
+        assert_eq!(inbox.next_item(0), Some(item));
+        assert_eq!(inbox.next_item(0), None);
+
+        match inbox.next_call() {
+            Some((1, Ok(PluginCall::Signature))) => {}
+            _ => panic!("expected the signature call"),
         }
+        assert!(inbox.next_call().is_none());
     }
 }
@@ -46,6 +46,20 @@ impl EvaluatedCall {
         })
     }
 
+    // Applies the function to the values of all the arguments, eg) to translate the custom values
+    // in them when they cross over to or from the plugin
+    pub(crate) fn map_values(self, f: impl Fn(Value) -> Value) -> Self {
+        Self {
+            head: self.head,
+            positional: self.positional.into_iter().map(&f).collect(),
+            named: self
+                .named
+                .into_iter()
+                .map(|(name, value)| (name, value.map(&f)))
+                .collect(),
+        }
+    }
+
     pub fn has_flag(&self, flag_name: &str) -> bool {
         for name in &self.named {
             if flag_name == name.0.item {
@@ -1,7 +1,12 @@
 mod evaluated_call;
+mod plugin_custom_value;
 
 pub use evaluated_call::EvaluatedCall;
-use nu_protocol::{ShellError, Signature, Span, Value};
+use nu_protocol::{ast::Operator, BlockId, ShellError, Signature, Span, Value};
+pub use plugin_custom_value::PluginCustomValue;
+pub(crate) use plugin_custom_value::{
+    add_plugin_source, decode_custom_values, encode_custom_values, prepare_for_plugin,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -11,11 +16,64 @@ pub struct CallInfo {
     pub input: Value,
 }
 
+// What a plugin is asked to do with one of its custom values, mirroring the methods of the
+// CustomValue trait
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum CustomValueOp {
+    ToBaseValue,
+    FollowPathInt(usize),
+    FollowPathString(String),
+    Operation(Operator, Span, Value),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CustomValueCall {
+    pub value: PluginCustomValue,
+    pub span: Span,
+    pub op: CustomValueOp,
+}
+
+// Information a plugin can ask nushell for while it is running a call
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum EngineCall {
+    GetConfig,
+    GetEnv(String),
+    GetCurrentDir,
+    EvalBlock(Box<EvalBlock>),
+}
+
+// Runs a block the plugin received as an argument, with the values for the block's parameters
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EvalBlock {
+    pub block_id: BlockId,
+    pub span: Span,
+    pub positional: Vec<Value>,
+    pub input: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum EngineCallResponse {
+    Error(LabeledError),
+    Value(Box<Value>),
+}
+
 // Information sent to the plugin
+//
+// A call with streaming input starts with StreamStart, whose input is left empty. The input
+// values follow one by one as StreamItem with the same call id, until StreamEnd. Cancel tells
+// the plugin that nushell doesn't want any more output for the call. CustomValueOp hands a
+// custom value back to the plugin that created it, which answers with a Value.
+// EngineCallResponse answers an EngineCall the plugin made during the call.
 #[derive(Serialize, Deserialize, Debug)]
 pub enum PluginCall {
     Signature,
     CallInfo(Box<CallInfo>),
+    StreamStart(Box<CallInfo>),
+    StreamItem(Box<Value>),
+    StreamEnd,
+    Cancel,
+    CustomValueOp(Box<CustomValueCall>),
+    EngineCallResponse(EngineCallResponse),
 }
 
 #[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
@@ -82,9 +140,17 @@ impl From<ShellError> for LabeledError {
 }
 
 // Information received from the plugin
+//
+// A plugin producing a stream answers with StreamStart, followed by its values as StreamItem
+// and finally StreamEnd. An Error ends the stream as well. An EngineCall can be sent at any point
+// before the call has ended, the plugin then waits for nushell's EngineCallResponse.
 #[derive(Serialize, Deserialize)]
 pub enum PluginResponse {
     Error(LabeledError),
     Signature(Vec<Signature>),
     Value(Box<Value>),
+    StreamStart,
+    StreamItem(Box<Value>),
+    StreamEnd,
+    EngineCall(EngineCall),
 }
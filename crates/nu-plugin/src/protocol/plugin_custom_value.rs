@@ -0,0 +1,340 @@
+use super::{CustomValueCall, CustomValueOp, PluginCall, PluginResponse};
+use crate::plugin::plugin_manager;
+use crate::EncodingType;
+use nu_protocol::{ast::Operator, Category, CustomValue, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A custom value created by a plugin.
+///
+/// Nushell can't read the data of a plugin's custom value, so it keeps it as an opaque blob
+/// together with the plugin that created it. Converting the value, following a cell path into it
+/// or using it in an operation is done by sending it back to that plugin.
+///
+/// Plugins don't use this type themselves. Their custom values are encoded into it when they are
+/// sent to nushell and decoded again when nushell sends them back, which requires the plugin's
+/// custom value types to be registered with `#[typetag::serde]`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PluginCustomValue {
+    pub name: String,
+    pub data: Vec<u8>,
+    // The plugin the value came from. Only known on the nushell side, after the value has been
+    // received from the plugin.
+    #[serde(skip)]
+    pub(crate) source: Option<(PathBuf, EncodingType)>,
+}
+
+impl PluginCustomValue {
+    pub fn new(name: String, data: Vec<u8>) -> Self {
+        Self {
+            name,
+            data,
+            source: None,
+        }
+    }
+
+    // Encodes one of the plugin's own custom values
+    fn encode(val: &dyn CustomValue) -> Result<Self, ShellError> {
+        let data = serde_json::to_vec(val).map_err(|e| {
+            ShellError::PluginFailedToEncode(format!(
+                "unable to encode custom value '{}': {}",
+                val.value_string(),
+                e
+            ))
+        })?;
+
+        Ok(Self::new(val.value_string(), data))
+    }
+
+    // Decodes the value back into the plugin's own custom value type
+    pub(crate) fn decode(&self) -> Result<Box<dyn CustomValue>, ShellError> {
+        serde_json::from_slice(&self.data).map_err(|e| {
+            ShellError::PluginFailedToDecode(format!(
+                "unable to decode custom value '{}': {}",
+                self.name, e
+            ))
+        })
+    }
+
+    fn call_plugin(&self, span: Span, op: CustomValueOp) -> Result<Value, ShellError> {
+        let (filename, encoding) = self.source.as_ref().ok_or_else(|| {
+            ShellError::SpannedLabeledError(
+                "Unknown plugin".into(),
+                format!("the plugin that created '{}' is not known", self.name),
+                span,
+            )
+        })?;
+
+        // The other side of an operation has to be something the plugin can read as well
+        let op = match op {
+            CustomValueOp::Operation(operator, op_span, right) => {
+                CustomValueOp::Operation(operator, op_span, prepare_for_plugin(right, filename))
+            }
+            op => op,
+        };
+
+        let plugin_call = PluginCall::CustomValueOp(Box::new(CustomValueCall {
+            value: self.clone(),
+            span,
+            op,
+        }));
+
+        match plugin_manager().call(filename, encoding, &plugin_call)? {
+            PluginResponse::Value(value) => Ok(add_plugin_source(*value, filename, encoding)),
+            PluginResponse::Error(err) => Err(err.into()),
+            _ => Err(ShellError::SpannedLabeledError(
+                "Plugin missing value".into(),
+                format!(
+                    "Received an unexpected response for custom value '{}'",
+                    self.name
+                ),
+                span,
+            )),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for PluginCustomValue {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self.clone()),
+            span,
+        }
+    }
+
+    fn category(&self) -> Category {
+        Category::Custom(self.name.clone())
+    }
+
+    fn value_string(&self) -> String {
+        self.name.clone()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        self.call_plugin(span, CustomValueOp::ToBaseValue)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_int(&self, count: usize, span: Span) -> Result<Value, ShellError> {
+        self.call_plugin(span, CustomValueOp::FollowPathInt(count))
+    }
+
+    fn follow_path_string(&self, column_name: String, span: Span) -> Result<Value, ShellError> {
+        self.call_plugin(span, CustomValueOp::FollowPathString(column_name))
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        self.call_plugin(
+            lhs_span,
+            CustomValueOp::Operation(operator, op, right.clone()),
+        )
+    }
+}
+
+// Replaces every custom value found in the value, including the ones nested in lists and records
+fn map_custom_values(value: Value, f: &impl Fn(Box<dyn CustomValue>, Span) -> Value) -> Value {
+    match value {
+        Value::CustomValue { val, span } => f(val, span),
+        Value::List { vals, span } => Value::List {
+            vals: vals
+                .into_iter()
+                .map(|val| map_custom_values(val, f))
+                .collect(),
+            span,
+        },
+        Value::Record { cols, vals, span } => Value::Record {
+            cols,
+            vals: vals
+                .into_iter()
+                .map(|val| map_custom_values(val, f))
+                .collect(),
+            span,
+        },
+        value => value,
+    }
+}
+
+// Nushell side: tag the custom values received from a plugin with the plugin they came from
+pub(crate) fn add_plugin_source(value: Value, filename: &Path, encoding: &EncodingType) -> Value {
+    map_custom_values(
+        value,
+        &|val, span| match val.as_any().downcast_ref::<PluginCustomValue>() {
+            Some(custom_value) => Value::CustomValue {
+                val: Box::new(PluginCustomValue {
+                    source: Some((filename.to_path_buf(), encoding.clone())),
+                    ..custom_value.clone()
+                }),
+                span,
+            },
+            None => Value::CustomValue { val, span },
+        },
+    )
+}
+
+// Nushell side: only the plugin's own custom values are sent to it as they are. Any other custom
+// value is sent as its base value, since the plugin has no way to read it.
+pub(crate) fn prepare_for_plugin(value: Value, filename: &Path) -> Value {
+    map_custom_values(value, &|val, span| {
+        let from_plugin = val
+            .as_any()
+            .downcast_ref::<PluginCustomValue>()
+            .and_then(|custom_value| custom_value.source.as_ref())
+            .map(|(source, _)| source == filename)
+            .unwrap_or(false);
+
+        if from_plugin {
+            Value::CustomValue { val, span }
+        } else {
+            match val.to_base_value(span) {
+                Ok(value) => value,
+                Err(error) => Value::Error { error },
+            }
+        }
+    })
+}
+
+// Plugin side: encode the plugin's custom values before they are sent to nushell
+pub(crate) fn encode_custom_values(value: Value) -> Value {
+    map_custom_values(value, &|val, span| {
+        if val.as_any().is::<PluginCustomValue>() {
+            return Value::CustomValue { val, span };
+        }
+
+        match PluginCustomValue::encode(val.as_ref()) {
+            Ok(custom_value) => Value::CustomValue {
+                val: Box::new(custom_value),
+                span,
+            },
+            Err(error) => Value::Error { error },
+        }
+    })
+}
+
+// Plugin side: decode the custom values nushell sends back into the plugin's own types
+pub(crate) fn decode_custom_values(value: Value) -> Value {
+    map_custom_values(
+        value,
+        &|val, span| match val.as_any().downcast_ref::<PluginCustomValue>() {
+            Some(custom_value) => match custom_value.decode() {
+                Ok(val) => Value::CustomValue { val, span },
+                Err(error) => Value::Error { error },
+            },
+            None => Value::CustomValue { val, span },
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    struct Counter {
+        count: i64,
+    }
+
+    #[typetag::serde]
+    impl CustomValue for Counter {
+        fn clone_value(&self, span: Span) -> Value {
+            Value::CustomValue {
+                val: Box::new(self.clone()),
+                span,
+            }
+        }
+
+        fn category(&self) -> Category {
+            Category::Custom("counter".into())
+        }
+
+        fn value_string(&self) -> String {
+            "counter".into()
+        }
+
+        fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+            Ok(Value::Int {
+                val: self.count,
+                span,
+            })
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn follow_path_int(&self, _count: usize, span: Span) -> Result<Value, ShellError> {
+            self.to_base_value(span)
+        }
+
+        fn follow_path_string(
+            &self,
+            _column_name: String,
+            span: Span,
+        ) -> Result<Value, ShellError> {
+            self.to_base_value(span)
+        }
+    }
+
+    #[test]
+    fn custom_value_encode_decode() {
+        let span = Span { start: 1, end: 5 };
+        let value = Value::List {
+            vals: vec![Value::CustomValue {
+                val: Box::new(Counter { count: 7 }),
+                span,
+            }],
+            span,
+        };
+
+        let encoded = encode_custom_values(value);
+        let encoded_val = match &encoded {
+            Value::List { vals, .. } => match &vals[0] {
+                Value::CustomValue { val, .. } => val,
+                _ => panic!("expected a custom value"),
+            },
+            _ => panic!("expected a list"),
+        };
+        let plugin_custom_value = encoded_val
+            .as_any()
+            .downcast_ref::<PluginCustomValue>()
+            .expect("expected a plugin custom value");
+        assert_eq!(plugin_custom_value.name, "counter");
+
+        match decode_custom_values(encoded) {
+            Value::List { vals, .. } => match &vals[0] {
+                Value::CustomValue { val, .. } => {
+                    let counter = val
+                        .as_any()
+                        .downcast_ref::<Counter>()
+                        .expect("expected the decoded counter");
+                    assert_eq!(counter.count, 7);
+                }
+                _ => panic!("expected a custom value"),
+            },
+            _ => panic!("expected a list"),
+        }
+    }
+
+    #[test]
+    fn unknown_custom_value_sent_as_base_value() {
+        let span = Span { start: 1, end: 5 };
+        let value = Value::CustomValue {
+            val: Box::new(Counter { count: 3 }),
+            span,
+        };
+
+        assert_eq!(
+            prepare_for_plugin(value, Path::new("nu_plugin_example")),
+            Value::Int { val: 3, span }
+        );
+    }
+}
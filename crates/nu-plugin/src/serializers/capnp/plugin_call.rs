@@ -1,9 +1,15 @@
 use super::signature::deserialize_signature;
 use super::{call, signature, value};
-use crate::plugin_capnp::{plugin_call, plugin_response};
-use crate::protocol::{CallInfo, LabeledError, PluginCall, PluginResponse};
+use crate::plugin_capnp::{
+    self, call_info, custom_value_call, engine_call, engine_call_response, eval_block,
+    labeled_error, plugin_call, plugin_response,
+};
+use crate::protocol::{
+    CallInfo, CustomValueCall, CustomValueOp, EngineCall, EngineCallResponse, EvalBlock,
+    LabeledError, PluginCall, PluginResponse,
+};
 use capnp::serialize;
-use nu_protocol::{ShellError, Signature, Span};
+use nu_protocol::{ast::Operator, ShellError, Signature, Span, Value};
 
 pub fn encode_call(
     plugin_call: &PluginCall,
@@ -16,32 +22,138 @@ pub fn encode_call(
     match &plugin_call {
         PluginCall::Signature => builder.set_signature(()),
         PluginCall::CallInfo(call_info) => {
-            let mut call_info_builder = builder.reborrow().init_call_info();
+            let call_info_builder = builder.reborrow().init_call_info();
+            serialize_call_info(call_info, call_info_builder)?;
+        }
+        PluginCall::StreamStart(call_info) => {
+            let call_info_builder = builder.reborrow().init_stream_start();
+            serialize_call_info(call_info, call_info_builder)?;
+        }
+        PluginCall::StreamItem(val) => {
+            let value_builder = builder.reborrow().init_stream_item();
+            value::serialize_value(val, value_builder);
+        }
+        PluginCall::StreamEnd => builder.set_stream_end(()),
+        PluginCall::Cancel => builder.set_cancel(()),
+        PluginCall::CustomValueOp(custom_value_call) => {
+            let call_builder = builder.reborrow().init_custom_value_op();
+            serialize_custom_value_call(custom_value_call, call_builder);
+        }
+        PluginCall::EngineCallResponse(response) => {
+            let mut response_builder = builder.reborrow().init_engine_call_response();
+            match response {
+                EngineCallResponse::Error(error) => {
+                    serialize_labeled_error(error, response_builder.reborrow().init_error())
+                }
+                EngineCallResponse::Value(val) => {
+                    value::serialize_value(val, response_builder.reborrow().init_value())
+                }
+            }
+        }
+    };
 
-            // Serializing name from the call
-            call_info_builder.set_name(call_info.name.as_str());
+    serialize::write_message(writer, &message)
+        .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))
+}
 
-            // Serializing argument information from the call
-            let call_builder = call_info_builder
-                .reborrow()
-                .get_call()
-                .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
+fn serialize_call_info(
+    call_info: &CallInfo,
+    mut call_info_builder: call_info::Builder,
+) -> Result<(), ShellError> {
+    // Serializing name from the call
+    call_info_builder.set_name(call_info.name.as_str());
 
-            call::serialize_call(&call_info.call, call_builder)
-                .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
+    // Serializing argument information from the call
+    let call_builder = call_info_builder
+        .reborrow()
+        .get_call()
+        .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
 
-            // Serializing the input value from the call info
-            let value_builder = call_info_builder
-                .reborrow()
-                .get_input()
-                .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
+    call::serialize_call(&call_info.call, call_builder)
+        .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
 
-            value::serialize_value(&call_info.input, value_builder);
+    // Serializing the input value from the call info
+    let value_builder = call_info_builder
+        .reborrow()
+        .get_input()
+        .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))?;
+
+    value::serialize_value(&call_info.input, value_builder);
+
+    Ok(())
+}
+
+fn serialize_custom_value_call(
+    custom_value_call: &CustomValueCall,
+    mut builder: custom_value_call::Builder,
+) {
+    value::serialize_custom_value(&custom_value_call.value, builder.reborrow().init_value());
+
+    let mut span = builder.reborrow().init_span();
+    span.set_start(custom_value_call.span.start as u64);
+    span.set_end(custom_value_call.span.end as u64);
+
+    match &custom_value_call.op {
+        CustomValueOp::ToBaseValue => builder.set_to_base_value(()),
+        CustomValueOp::FollowPathInt(count) => builder.set_follow_path_int(*count as u64),
+        CustomValueOp::FollowPathString(column_name) => builder.set_follow_path_string(column_name),
+        CustomValueOp::Operation(operator, op_span, right) => {
+            let mut operation_builder = builder.reborrow().init_operation();
+            operation_builder.set_operator(serialize_operator(operator));
+
+            let mut span = operation_builder.reborrow().init_op_span();
+            span.set_start(op_span.start as u64);
+            span.set_end(op_span.end as u64);
+
+            value::serialize_value(right, operation_builder.reborrow().init_right());
         }
-    };
+    }
+}
 
-    serialize::write_message(writer, &message)
-        .map_err(|e| ShellError::PluginFailedToEncode(e.to_string()))
+fn serialize_operator(operator: &Operator) -> plugin_capnp::Operator {
+    match operator {
+        Operator::Equal => plugin_capnp::Operator::Equal,
+        Operator::NotEqual => plugin_capnp::Operator::NotEqual,
+        Operator::LessThan => plugin_capnp::Operator::LessThan,
+        Operator::GreaterThan => plugin_capnp::Operator::GreaterThan,
+        Operator::LessThanOrEqual => plugin_capnp::Operator::LessThanOrEqual,
+        Operator::GreaterThanOrEqual => plugin_capnp::Operator::GreaterThanOrEqual,
+        Operator::Contains => plugin_capnp::Operator::Contains,
+        Operator::NotContains => plugin_capnp::Operator::NotContains,
+        Operator::Plus => plugin_capnp::Operator::Plus,
+        Operator::Minus => plugin_capnp::Operator::Minus,
+        Operator::Multiply => plugin_capnp::Operator::Multiply,
+        Operator::Divide => plugin_capnp::Operator::Divide,
+        Operator::In => plugin_capnp::Operator::In,
+        Operator::NotIn => plugin_capnp::Operator::NotIn,
+        Operator::Modulo => plugin_capnp::Operator::Modulo,
+        Operator::And => plugin_capnp::Operator::And,
+        Operator::Or => plugin_capnp::Operator::Or,
+        Operator::Pow => plugin_capnp::Operator::Pow,
+    }
+}
+
+fn deserialize_operator(operator: plugin_capnp::Operator) -> Operator {
+    match operator {
+        plugin_capnp::Operator::Equal => Operator::Equal,
+        plugin_capnp::Operator::NotEqual => Operator::NotEqual,
+        plugin_capnp::Operator::LessThan => Operator::LessThan,
+        plugin_capnp::Operator::GreaterThan => Operator::GreaterThan,
+        plugin_capnp::Operator::LessThanOrEqual => Operator::LessThanOrEqual,
+        plugin_capnp::Operator::GreaterThanOrEqual => Operator::GreaterThanOrEqual,
+        plugin_capnp::Operator::Contains => Operator::Contains,
+        plugin_capnp::Operator::NotContains => Operator::NotContains,
+        plugin_capnp::Operator::Plus => Operator::Plus,
+        plugin_capnp::Operator::Minus => Operator::Minus,
+        plugin_capnp::Operator::Multiply => Operator::Multiply,
+        plugin_capnp::Operator::Divide => Operator::Divide,
+        plugin_capnp::Operator::In => Operator::In,
+        plugin_capnp::Operator::NotIn => Operator::NotIn,
+        plugin_capnp::Operator::Modulo => Operator::Modulo,
+        plugin_capnp::Operator::And => Operator::And,
+        plugin_capnp::Operator::Or => Operator::Or,
+        plugin_capnp::Operator::Pow => Operator::Pow,
+    }
 }
 
 pub fn decode_call(reader: &mut impl std::io::BufRead) -> Result<PluginCall, ShellError> {
@@ -60,31 +172,139 @@ pub fn decode_call(reader: &mut impl std::io::BufRead) -> Result<PluginCall, She
         Ok(plugin_call::CallInfo(reader)) => {
             let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let name = reader
-                .get_name()
-                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            Ok(PluginCall::CallInfo(Box::new(deserialize_call_info(
+                reader,
+            )?)))
+        }
+        Ok(plugin_call::StreamStart(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let call_reader = reader
-                .get_call()
+            Ok(PluginCall::StreamStart(Box::new(deserialize_call_info(
+                reader,
+            )?)))
+        }
+        Ok(plugin_call::StreamItem(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let val = value::deserialize_value(reader)
                 .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let call = call::deserialize_call(call_reader)?;
+            Ok(PluginCall::StreamItem(Box::new(val)))
+        }
+        Ok(plugin_call::StreamEnd(())) => Ok(PluginCall::StreamEnd),
+        Ok(plugin_call::Cancel(())) => Ok(PluginCall::Cancel),
+        Ok(plugin_call::CustomValueOp(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let input_reader = reader
-                .get_input()
-                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            Ok(PluginCall::CustomValueOp(Box::new(
+                deserialize_custom_value_call(reader)?,
+            )))
+        }
+        Ok(plugin_call::EngineCallResponse(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let input = value::deserialize_value(input_reader)?;
+            let response = match reader.which() {
+                Err(capnp::NotInSchema(_)) => {
+                    return Err(ShellError::PluginFailedToDecode(
+                        "engine call response not in schema".into(),
+                    ))
+                }
+                Ok(engine_call_response::Error(reader)) => {
+                    let reader =
+                        reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+                    EngineCallResponse::Error(deserialize_labeled_error(reader)?)
+                }
+                Ok(engine_call_response::Value(reader)) => {
+                    let reader =
+                        reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+                    EngineCallResponse::Value(Box::new(value::deserialize_value(reader)?))
+                }
+            };
 
-            Ok(PluginCall::CallInfo(Box::new(CallInfo {
-                name: name.to_string(),
-                call,
-                input,
-            })))
+            Ok(PluginCall::EngineCallResponse(response))
         }
     }
 }
 
+fn deserialize_custom_value_call(
+    reader: custom_value_call::Reader,
+) -> Result<CustomValueCall, ShellError> {
+    let value_reader = reader
+        .get_value()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+    let value = value::deserialize_custom_value(value_reader)?;
+
+    let span_reader = reader
+        .get_span()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+    let span = Span {
+        start: span_reader.get_start() as usize,
+        end: span_reader.get_end() as usize,
+    };
+
+    let op = match reader.which() {
+        Err(capnp::NotInSchema(_)) => {
+            return Err(ShellError::PluginFailedToDecode(
+                "custom value operation not in schema".into(),
+            ))
+        }
+        Ok(custom_value_call::ToBaseValue(())) => CustomValueOp::ToBaseValue,
+        Ok(custom_value_call::FollowPathInt(count)) => CustomValueOp::FollowPathInt(count as usize),
+        Ok(custom_value_call::FollowPathString(column_name)) => {
+            let column_name =
+                column_name.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            CustomValueOp::FollowPathString(column_name.to_string())
+        }
+        Ok(custom_value_call::Operation(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            let operator = reader
+                .get_operator()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            let op_span = reader
+                .get_op_span()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let op_span = Span {
+                start: op_span.get_start() as usize,
+                end: op_span.get_end() as usize,
+            };
+
+            let right_reader = reader
+                .get_right()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let right = value::deserialize_value(right_reader)?;
+
+            CustomValueOp::Operation(deserialize_operator(operator), op_span, right)
+        }
+    };
+
+    Ok(CustomValueCall { value, span, op })
+}
+
+fn deserialize_call_info(reader: call_info::Reader) -> Result<CallInfo, ShellError> {
+    let name = reader
+        .get_name()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let call_reader = reader
+        .get_call()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let call = call::deserialize_call(call_reader)?;
+
+    let input_reader = reader
+        .get_input()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let input = value::deserialize_value(input_reader)?;
+
+    Ok(CallInfo {
+        name: name.to_string(),
+        call,
+        input,
+    })
+}
+
 pub fn encode_response(
     plugin_response: &PluginResponse,
     writer: &mut impl std::io::Write,
@@ -95,15 +315,7 @@ pub fn encode_response(
 
     match &plugin_response {
         PluginResponse::Error(msg) => {
-            let mut error_builder = builder.reborrow().init_error();
-            error_builder.set_label(&msg.label);
-            error_builder.set_msg(&msg.msg);
-
-            if let Some(span) = msg.span {
-                let mut span_builder = error_builder.reborrow().init_span();
-                span_builder.set_start(span.start as u64);
-                span_builder.set_end(span.end as u64);
-            }
+            serialize_labeled_error(msg, builder.reborrow().init_error());
         }
         PluginResponse::Signature(signatures) => {
             let mut signature_list_builder =
@@ -118,6 +330,15 @@ pub fn encode_response(
             let value_builder = builder.reborrow().init_value();
             value::serialize_value(val, value_builder);
         }
+        PluginResponse::StreamStart => builder.set_stream_start(()),
+        PluginResponse::StreamItem(val) => {
+            let value_builder = builder.reborrow().init_stream_item();
+            value::serialize_value(val, value_builder);
+        }
+        PluginResponse::StreamEnd => builder.set_stream_end(()),
+        PluginResponse::EngineCall(engine_call) => {
+            serialize_engine_call(engine_call, builder.reborrow().init_engine_call());
+        }
     };
 
     serialize::write_message(writer, &message)
@@ -139,34 +360,7 @@ pub fn decode_response(reader: &mut impl std::io::BufRead) -> Result<PluginRespo
         Ok(plugin_response::Error(reader)) => {
             let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
 
-            let msg = reader
-                .get_msg()
-                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
-
-            let label = reader
-                .get_label()
-                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
-
-            let span = if reader.has_span() {
-                let span = reader
-                    .get_span()
-                    .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
-
-                Some(Span {
-                    start: span.get_start() as usize,
-                    end: span.get_end() as usize,
-                })
-            } else {
-                None
-            };
-
-            let error = LabeledError {
-                label: label.into(),
-                msg: msg.into(),
-                span,
-            };
-
-            Ok(PluginResponse::Error(error))
+            Ok(PluginResponse::Error(deserialize_labeled_error(reader)?))
         }
         Ok(plugin_response::Signature(reader)) => {
             let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
@@ -185,13 +379,141 @@ pub fn decode_response(reader: &mut impl std::io::BufRead) -> Result<PluginRespo
 
             Ok(PluginResponse::Value(Box::new(val)))
         }
+        Ok(plugin_response::StreamStart(())) => Ok(PluginResponse::StreamStart),
+        Ok(plugin_response::StreamItem(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let val = value::deserialize_value(reader)
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            Ok(PluginResponse::StreamItem(Box::new(val)))
+        }
+        Ok(plugin_response::StreamEnd(())) => Ok(PluginResponse::StreamEnd),
+        Ok(plugin_response::EngineCall(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            Ok(PluginResponse::EngineCall(deserialize_engine_call(reader)?))
+        }
+    }
+}
+
+fn serialize_labeled_error(error: &LabeledError, mut builder: labeled_error::Builder) {
+    builder.set_label(&error.label);
+    builder.set_msg(&error.msg);
+
+    if let Some(span) = error.span {
+        let mut span_builder = builder.reborrow().init_span();
+        span_builder.set_start(span.start as u64);
+        span_builder.set_end(span.end as u64);
+    }
+}
+
+fn deserialize_labeled_error(reader: labeled_error::Reader) -> Result<LabeledError, ShellError> {
+    let msg = reader
+        .get_msg()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let label = reader
+        .get_label()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let span = if reader.has_span() {
+        let span = reader
+            .get_span()
+            .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+        Some(Span {
+            start: span.get_start() as usize,
+            end: span.get_end() as usize,
+        })
+    } else {
+        None
+    };
+
+    Ok(LabeledError {
+        label: label.into(),
+        msg: msg.into(),
+        span,
+    })
+}
+
+fn serialize_engine_call(engine_call: &EngineCall, mut builder: engine_call::Builder) {
+    match engine_call {
+        EngineCall::GetConfig => builder.set_get_config(()),
+        EngineCall::GetEnv(name) => builder.set_get_env(name),
+        EngineCall::GetCurrentDir => builder.set_get_current_dir(()),
+        EngineCall::EvalBlock(eval_block) => {
+            serialize_eval_block(eval_block, builder.reborrow().init_eval_block())
+        }
+    }
+}
+
+fn serialize_eval_block(eval_block: &EvalBlock, mut builder: eval_block::Builder) {
+    builder.set_block_id(eval_block.block_id as u64);
+
+    let mut span = builder.reborrow().init_span();
+    span.set_start(eval_block.span.start as u64);
+    span.set_end(eval_block.span.end as u64);
+
+    let mut positional_builder = builder
+        .reborrow()
+        .init_positional(eval_block.positional.len() as u32);
+    for (index, value) in eval_block.positional.iter().enumerate() {
+        value::serialize_value(value, positional_builder.reborrow().get(index as u32));
+    }
+
+    value::serialize_value(&eval_block.input, builder.reborrow().init_input());
+}
+
+fn deserialize_engine_call(reader: engine_call::Reader) -> Result<EngineCall, ShellError> {
+    match reader.which() {
+        Err(capnp::NotInSchema(_)) => Err(ShellError::PluginFailedToDecode(
+            "engine call not in schema".into(),
+        )),
+        Ok(engine_call::GetConfig(())) => Ok(EngineCall::GetConfig),
+        Ok(engine_call::GetEnv(name)) => {
+            let name = name.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            Ok(EngineCall::GetEnv(name.to_string()))
+        }
+        Ok(engine_call::GetCurrentDir(())) => Ok(EngineCall::GetCurrentDir),
+        Ok(engine_call::EvalBlock(reader)) => {
+            let reader = reader.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            let span = reader
+                .get_span()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let span = Span {
+                start: span.get_start() as usize,
+                end: span.get_end() as usize,
+            };
+
+            let positional = reader
+                .get_positional()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?
+                .iter()
+                .map(value::deserialize_value)
+                .collect::<Result<Vec<Value>, ShellError>>()?;
+
+            let input_reader = reader
+                .get_input()
+                .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+            let input = value::deserialize_value(input_reader)?;
+
+            Ok(EngineCall::EvalBlock(Box::new(EvalBlock {
+                block_id: reader.get_block_id() as usize,
+                span,
+                positional,
+                input,
+            })))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::protocol::{EvaluatedCall, LabeledError, PluginCall, PluginResponse};
+    use crate::protocol::{
+        EvaluatedCall, LabeledError, PluginCall, PluginCustomValue, PluginResponse,
+    };
     use nu_protocol::{Signature, Span, Spanned, SyntaxShape, Value};
 
     #[test]
@@ -204,7 +526,7 @@ mod tests {
 
         match returned {
             PluginCall::Signature => {}
-            PluginCall::CallInfo(_) => panic!("decoded into wrong value"),
+            _ => panic!("decoded into wrong value"),
         }
     }
 
@@ -252,7 +574,6 @@ mod tests {
         let returned = decode_call(&mut buffer.as_slice()).expect("unable to deserialize message");
 
         match returned {
-            PluginCall::Signature => panic!("returned wrong call type"),
             PluginCall::CallInfo(call_info) => {
                 assert_eq!(name, call_info.name);
                 assert_eq!(input, call_info.input);
@@ -278,6 +599,7 @@ mod tests {
                         }
                     });
             }
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -303,8 +625,6 @@ mod tests {
             decode_response(&mut buffer.as_slice()).expect("unable to deserialize message");
 
         match returned {
-            PluginResponse::Error(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
             PluginResponse::Signature(returned_signature) => {
                 assert!(returned_signature.len() == 1);
                 assert_eq!(signature.name, returned_signature[0].name);
@@ -335,6 +655,7 @@ mod tests {
                     returned_signature[0].rest_positional,
                 );
             }
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -353,11 +674,10 @@ mod tests {
             decode_response(&mut buffer.as_slice()).expect("unable to deserialize message");
 
         match returned {
-            PluginResponse::Error(_) => panic!("returned wrong call type"),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
             PluginResponse::Value(returned_value) => {
                 assert_eq!(&value, returned_value.as_ref())
             }
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -377,8 +697,7 @@ mod tests {
 
         match returned {
             PluginResponse::Error(msg) => assert_eq!(error, msg),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -398,8 +717,130 @@ mod tests {
 
         match returned {
             PluginResponse::Error(msg) => assert_eq!(error, msg),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
+            _ => panic!("returned wrong call type"),
+        }
+    }
+
+    #[test]
+    fn call_round_trip_stream_item() {
+        let value = Value::String {
+            val: "item".into(),
+            span: Span { start: 0, end: 4 },
+        };
+        let plugin_call = PluginCall::StreamItem(Box::new(value.clone()));
+
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_call(&plugin_call, &mut buffer).expect("unable to serialize message");
+        let returned = decode_call(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned {
+            PluginCall::StreamItem(returned_value) => assert_eq!(&value, returned_value.as_ref()),
+            _ => panic!("returned wrong call type"),
+        }
+    }
+
+    #[test]
+    fn call_round_trip_custom_value_op() {
+        let right = Value::Int {
+            val: 2,
+            span: Span { start: 6, end: 7 },
+        };
+        let plugin_call = PluginCall::CustomValueOp(Box::new(CustomValueCall {
+            value: PluginCustomValue::new("handle".into(), vec![7, 8, 9]),
+            span: Span { start: 0, end: 3 },
+            op: CustomValueOp::Operation(Operator::Plus, Span { start: 4, end: 5 }, right.clone()),
+        }));
+
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_call(&plugin_call, &mut buffer).expect("unable to serialize message");
+        let returned = decode_call(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned {
+            PluginCall::CustomValueOp(custom_value_call) => {
+                assert_eq!(custom_value_call.value.name, "handle");
+                assert_eq!(custom_value_call.value.data, vec![7, 8, 9]);
+                assert_eq!(custom_value_call.span, Span { start: 0, end: 3 });
+
+                match custom_value_call.op {
+                    CustomValueOp::Operation(operator, op_span, returned_right) => {
+                        assert_eq!(operator, Operator::Plus);
+                        assert_eq!(op_span, Span { start: 4, end: 5 });
+                        assert_eq!(returned_right, right);
+                    }
+                    _ => panic!("returned wrong operation"),
+                }
+            }
+            _ => panic!("returned wrong call type"),
+        }
+    }
+
+    #[test]
+    fn response_round_trip_engine_call() {
+        let input = Value::String {
+            val: "input".into(),
+            span: Span { start: 0, end: 5 },
+        };
+        let positional = Value::Int {
+            val: 4,
+            span: Span { start: 6, end: 7 },
+        };
+        let response = PluginResponse::EngineCall(EngineCall::EvalBlock(Box::new(EvalBlock {
+            block_id: 42,
+            span: Span { start: 8, end: 20 },
+            positional: vec![positional.clone()],
+            input: input.clone(),
+        })));
+
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_response(&response, &mut buffer).expect("unable to serialize message");
+        let returned =
+            decode_response(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned {
+            PluginResponse::EngineCall(EngineCall::EvalBlock(eval_block)) => {
+                assert_eq!(eval_block.block_id, 42);
+                assert_eq!(eval_block.span, Span { start: 8, end: 20 });
+                assert_eq!(eval_block.positional, vec![positional]);
+                assert_eq!(eval_block.input, input);
+            }
+            _ => panic!("returned wrong response type"),
+        }
+    }
+
+    #[test]
+    fn call_round_trip_engine_call_response() {
+        let plugin_call = PluginCall::EngineCallResponse(EngineCallResponse::Error(LabeledError {
+            label: "label".into(),
+            msg: "msg".into(),
+            span: None,
+        }));
+
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_call(&plugin_call, &mut buffer).expect("unable to serialize message");
+        let returned = decode_call(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned {
+            PluginCall::EngineCallResponse(EngineCallResponse::Error(error)) => {
+                assert_eq!(error.label, "label");
+                assert_eq!(error.msg, "msg");
+                assert_eq!(error.span, None);
+            }
+            _ => panic!("returned wrong call type"),
+        }
+    }
+
+    #[test]
+    fn response_round_trip_stream_end() {
+        let response = PluginResponse::StreamEnd;
+
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_response(&response, &mut buffer).expect("unable to serialize message");
+        let returned =
+            decode_response(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned {
+            PluginResponse::StreamEnd => {}
+            _ => panic!("returned wrong call type"),
         }
     }
 }
@@ -1,5 +1,7 @@
-use crate::plugin_capnp::{argument, flag, signature, Category as PluginCategory, Shape};
-use nu_protocol::{Category, Flag, PositionalArg, ShellError, Signature, SyntaxShape};
+use crate::plugin_capnp::{
+    argument, flag, input_output_type, signature, Category as PluginCategory, Shape,
+};
+use nu_protocol::{Category, Flag, PositionalArg, ShellError, Signature, SyntaxShape, Type};
 
 pub(crate) fn serialize_signature(signature: &Signature, mut builder: signature::Builder) {
     builder.set_name(signature.name.as_str());
@@ -56,6 +58,15 @@ pub(crate) fn serialize_signature(signature: &Signature, mut builder: signature:
         let inner_builder = named_list.reborrow().get(index as u32);
         serialize_flag(arg, inner_builder)
     }
+
+    // Serializing the input and output types
+    let mut types_list = builder
+        .reborrow()
+        .init_input_output_types(signature.input_output_types.len() as u32);
+    for (index, (input, output)) in signature.input_output_types.iter().enumerate() {
+        let inner_builder = types_list.reborrow().get(index as u32);
+        serialize_input_output_type(input, output, inner_builder)
+    }
 }
 
 fn serialize_argument(arg: &PositionalArg, mut builder: argument::Builder) {
@@ -67,10 +78,21 @@ fn serialize_argument(arg: &PositionalArg, mut builder: argument::Builder) {
         SyntaxShape::String => builder.set_shape(Shape::String),
         SyntaxShape::Int => builder.set_shape(Shape::Int),
         SyntaxShape::Number => builder.set_shape(Shape::Number),
+        SyntaxShape::Block(_) => builder.set_shape(Shape::Block),
         _ => builder.set_shape(Shape::Any),
     }
 }
 
+// A type can nest other types, so each one travels as its JSON encoding
+fn serialize_input_output_type(
+    input: &Type,
+    output: &Type,
+    mut builder: input_output_type::Builder,
+) {
+    builder.set_input(serde_json::to_string(input).unwrap_or_default().as_str());
+    builder.set_output(serde_json::to_string(output).unwrap_or_default().as_str());
+}
+
 fn serialize_flag(arg: &Flag, mut builder: flag::Builder) {
     builder.set_long(arg.long.as_str());
     builder.set_required(arg.required);
@@ -88,6 +110,7 @@ fn serialize_flag(arg: &Flag, mut builder: flag::Builder) {
             SyntaxShape::String => builder.set_arg(Shape::String),
             SyntaxShape::Int => builder.set_arg(Shape::Int),
             SyntaxShape::Number => builder.set_arg(Shape::Number),
+            SyntaxShape::Block(_) => builder.set_arg(Shape::Block),
             _ => builder.set_arg(Shape::Any),
         },
     }
@@ -165,6 +188,18 @@ pub(crate) fn deserialize_signature(reader: signature::Reader) -> Result<Signatu
         .map(deserialize_flag)
         .collect::<Result<Vec<Flag>, ShellError>>()?;
 
+    // Deserializing input and output types
+    let input_output_types = if reader.has_input_output_types() {
+        reader
+            .get_input_output_types()
+            .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?
+            .iter()
+            .map(deserialize_input_output_type)
+            .collect::<Result<Vec<(Type, Type)>, ShellError>>()?
+    } else {
+        vec![]
+    };
+
     Ok(Signature {
         name: name.to_string(),
         usage: usage.to_string(),
@@ -175,6 +210,7 @@ pub(crate) fn deserialize_signature(reader: signature::Reader) -> Result<Signatu
         named,
         is_filter,
         creates_scope: false,
+        input_output_types,
         category,
     })
 }
@@ -197,6 +233,7 @@ fn deserialize_argument(reader: argument::Reader) -> Result<PositionalArg, Shell
         Shape::Int => SyntaxShape::Int,
         Shape::Number => SyntaxShape::Number,
         Shape::Boolean => SyntaxShape::Boolean,
+        Shape::Block => SyntaxShape::Block(None),
         Shape::Any => SyntaxShape::Any,
         Shape::None => SyntaxShape::Any,
     };
@@ -206,9 +243,29 @@ fn deserialize_argument(reader: argument::Reader) -> Result<PositionalArg, Shell
         desc: desc.to_string(),
         shape,
         var_id: None,
+        default_value: None,
     })
 }
 
+fn deserialize_input_output_type(
+    reader: input_output_type::Reader,
+) -> Result<(Type, Type), ShellError> {
+    let input = reader
+        .get_input()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let output = reader
+        .get_output()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let input =
+        serde_json::from_str(input).map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+    let output = serde_json::from_str(output)
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    Ok((input, output))
+}
+
 fn deserialize_flag(reader: flag::Reader) -> Result<Flag, ShellError> {
     let long = reader
         .get_long()
@@ -241,6 +298,7 @@ fn deserialize_flag(reader: flag::Reader) -> Result<Flag, ShellError> {
         Shape::Int => Some(SyntaxShape::Int),
         Shape::Number => Some(SyntaxShape::Number),
         Shape::Boolean => Some(SyntaxShape::Boolean),
+        Shape::Block => Some(SyntaxShape::Block(None)),
     };
 
     Ok(Flag {
@@ -250,6 +308,7 @@ fn deserialize_flag(reader: flag::Reader) -> Result<Flag, ShellError> {
         required,
         desc: desc.to_string(),
         var_id: None,
+        default_value: None,
     })
 }
 
@@ -257,7 +316,7 @@ fn deserialize_flag(reader: flag::Reader) -> Result<Flag, ShellError> {
 mod tests {
     use super::*;
     use capnp::serialize;
-    use nu_protocol::{Category, Signature, SyntaxShape};
+    use nu_protocol::{Category, Signature, SyntaxShape, Type};
 
     pub fn write_buffer(
         signature: &Signature,
@@ -295,6 +354,11 @@ mod tests {
             .required_named("string", SyntaxShape::String, "second named", Some('x'))
             .switch("switch", "some switch", None)
             .rest("remaining", SyntaxShape::Int, "remaining")
+            .input_output_type(Type::List(Box::new(Type::Int)), Type::Int)
+            .input_output_type(
+                Type::Record(vec![("name".to_string(), Type::String)]),
+                Type::Table,
+            )
             .category(Category::Conversions);
 
         let mut buffer: Vec<u8> = Vec::new();
@@ -307,6 +371,10 @@ mod tests {
         assert_eq!(signature.extra_usage, returned_signature.extra_usage);
         assert_eq!(signature.is_filter, returned_signature.is_filter);
         assert_eq!(signature.category, returned_signature.category);
+        assert_eq!(
+            signature.input_output_types,
+            returned_signature.input_output_types
+        );
 
         signature
             .required_positional
@@ -353,6 +421,10 @@ mod tests {
         assert_eq!(signature.extra_usage, returned_signature.extra_usage);
         assert_eq!(signature.is_filter, returned_signature.is_filter);
         assert_eq!(signature.category, returned_signature.category);
+        assert_eq!(
+            signature.input_output_types,
+            returned_signature.input_output_types
+        );
 
         signature
             .required_positional
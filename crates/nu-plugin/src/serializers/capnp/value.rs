@@ -1,4 +1,5 @@
-use crate::plugin_capnp::value;
+use crate::plugin_capnp::{custom_value, value};
+use crate::protocol::PluginCustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
 pub(crate) fn serialize_value(value: &Value, mut builder: value::Builder) {
@@ -48,6 +49,21 @@ pub(crate) fn serialize_value(value: &Value, mut builder: value::Builder) {
 
             *span
         }
+        Value::Block { val, span } => {
+            builder.set_block(*val as u64);
+            *span
+        }
+        Value::CustomValue { val, span } => {
+            match val.as_any().downcast_ref::<PluginCustomValue>() {
+                Some(custom_value) => {
+                    serialize_custom_value(custom_value, builder.reborrow().init_custom())
+                }
+                // Other custom values are replaced by their base value before they are sent
+                None => builder.set_void(()),
+            }
+
+            *span
+        }
         _ => {
             // If there is the need to pass other type of value to the plugin
             // we have to define the encoding for that object in this match
@@ -60,6 +76,14 @@ pub(crate) fn serialize_value(value: &Value, mut builder: value::Builder) {
     span.set_end(value_span.end as u64);
 }
 
+pub(crate) fn serialize_custom_value(
+    custom_value: &PluginCustomValue,
+    mut builder: custom_value::Builder,
+) {
+    builder.set_name(&custom_value.name);
+    builder.set_data(&custom_value.data);
+}
+
 pub(crate) fn deserialize_value(reader: value::Reader) -> Result<Value, ShellError> {
     let span_reader = reader
         .get_span()
@@ -116,12 +140,39 @@ pub(crate) fn deserialize_value(reader: value::Reader) -> Result<Value, ShellErr
                 span,
             })
         }
+        Ok(value::Block(val)) => Ok(Value::Block {
+            val: val as usize,
+            span,
+        }),
+        Ok(value::Custom(custom_value)) => {
+            let custom_value =
+                custom_value.map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+            Ok(Value::CustomValue {
+                val: Box::new(deserialize_custom_value(custom_value)?),
+                span,
+            })
+        }
         Err(capnp::NotInSchema(_)) => Ok(Value::Nothing {
             span: Span::unknown(),
         }),
     }
 }
 
+pub(crate) fn deserialize_custom_value(
+    reader: custom_value::Reader,
+) -> Result<PluginCustomValue, ShellError> {
+    let name = reader
+        .get_name()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    let data = reader
+        .get_data()
+        .map_err(|e| ShellError::PluginFailedToDecode(e.to_string()))?;
+
+    Ok(PluginCustomValue::new(name.to_string(), data.to_vec()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +220,10 @@ mod tests {
                 val: "a string".into(),
                 span: Span { start: 4, end: 50 },
             },
+            Value::Block {
+                val: 12,
+                span: Span { start: 5, end: 60 },
+            },
         ];
 
         for value in values {
@@ -362,4 +417,31 @@ mod tests {
 
         assert_eq!(record, returned_record)
     }
+
+    #[test]
+    fn custom_value_round_trip() {
+        let value = Value::CustomValue {
+            val: Box::new(PluginCustomValue::new("handle".into(), vec![1, 2, 3, 4])),
+            span: Span { start: 1, end: 10 },
+        };
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_buffer(&value, &mut buffer).expect("unable to serialize message");
+        let returned_value =
+            read_buffer(&mut buffer.as_slice()).expect("unable to deserialize message");
+
+        match returned_value {
+            Value::CustomValue { val, span } => {
+                let custom_value = val
+                    .as_any()
+                    .downcast_ref::<PluginCustomValue>()
+                    .expect("expected a plugin custom value");
+
+                assert_eq!(custom_value.name, "handle");
+                assert_eq!(custom_value.data, vec![1, 2, 3, 4]);
+                assert_eq!(span, Span { start: 1, end: 10 });
+            }
+            _ => panic!("decoded into wrong value"),
+        }
+    }
 }
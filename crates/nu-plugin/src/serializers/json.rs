@@ -2,7 +2,7 @@ use nu_protocol::ShellError;
 
 use crate::{plugin::PluginEncoder, protocol::PluginResponse};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct JsonSerializer;
 
 impl PluginEncoder for JsonSerializer {
@@ -44,7 +44,9 @@ impl PluginEncoder for JsonSerializer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::protocol::{CallInfo, EvaluatedCall, LabeledError, PluginCall, PluginResponse};
+    use crate::protocol::{
+        CallInfo, EvaluatedCall, LabeledError, PluginCall, PluginCustomValue, PluginResponse,
+    };
     use nu_protocol::{Signature, Span, Spanned, SyntaxShape, Value};
 
     #[test]
@@ -62,7 +64,7 @@ mod tests {
 
         match returned {
             PluginCall::Signature => {}
-            PluginCall::CallInfo(_) => panic!("decoded into wrong value"),
+            _ => panic!("decoded into wrong value"),
         }
     }
 
@@ -115,7 +117,6 @@ mod tests {
             .expect("unable to deserialize message");
 
         match returned {
-            PluginCall::Signature => panic!("returned wrong call type"),
             PluginCall::CallInfo(call_info) => {
                 assert_eq!(name, call_info.name);
                 assert_eq!(input, call_info.input);
@@ -141,6 +142,7 @@ mod tests {
                         }
                     });
             }
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -170,8 +172,6 @@ mod tests {
             .expect("unable to deserialize message");
 
         match returned {
-            PluginResponse::Error(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
             PluginResponse::Signature(returned_signature) => {
                 assert!(returned_signature.len() == 1);
                 assert_eq!(signature.name, returned_signature[0].name);
@@ -202,6 +202,7 @@ mod tests {
                     returned_signature[0].rest_positional,
                 );
             }
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -224,11 +225,45 @@ mod tests {
             .expect("unable to deserialize message");
 
         match returned {
-            PluginResponse::Error(_) => panic!("returned wrong call type"),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
             PluginResponse::Value(returned_value) => {
                 assert_eq!(&value, returned_value.as_ref())
             }
+            _ => panic!("returned wrong call type"),
+        }
+    }
+
+    #[test]
+    fn response_round_trip_custom_value() {
+        let value = Value::CustomValue {
+            val: Box::new(PluginCustomValue::new("handle".into(), vec![1, 2, 3])),
+            span: Span { start: 2, end: 30 },
+        };
+
+        let response = PluginResponse::Value(Box::new(value));
+
+        let encoder = JsonSerializer {};
+        let mut buffer: Vec<u8> = Vec::new();
+        encoder
+            .encode_response(&response, &mut buffer)
+            .expect("unable to serialize message");
+        let returned = encoder
+            .decode_response(&mut buffer.as_slice())
+            .expect("unable to deserialize message");
+
+        match returned {
+            PluginResponse::Value(returned_value) => match *returned_value {
+                Value::CustomValue { val, .. } => {
+                    let custom_value = val
+                        .as_any()
+                        .downcast_ref::<PluginCustomValue>()
+                        .expect("expected a plugin custom value");
+
+                    assert_eq!(custom_value.name, "handle");
+                    assert_eq!(custom_value.data, vec![1, 2, 3]);
+                }
+                _ => panic!("decoded into wrong value"),
+            },
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -252,8 +287,7 @@ mod tests {
 
         match returned {
             PluginResponse::Error(msg) => assert_eq!(error, msg),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
+            _ => panic!("returned wrong call type"),
         }
     }
 
@@ -277,8 +311,7 @@ mod tests {
 
         match returned {
             PluginResponse::Error(msg) => assert_eq!(error, msg),
-            PluginResponse::Signature(_) => panic!("returned wrong call type"),
-            PluginResponse::Value(_) => panic!("returned wrong call type"),
+            _ => panic!("returned wrong call type"),
         }
     }
 }
@@ -130,8 +130,11 @@ impl Expression {
                 false
             }
             Expr::CellPath(_) => false,
-            Expr::ExternalCall(_, _, args) => {
-                for arg in args {
+            Expr::ExternalCall(_, _, args, redirections) => {
+                for arg in args
+                    .iter()
+                    .chain(redirections.iter().map(|(_, target)| target))
+                {
                     if arg.has_in_variable(working_set) {
                         return true;
                     }
@@ -277,10 +280,13 @@ impl Expression {
                 }
             }
             Expr::CellPath(_) => {}
-            Expr::ExternalCall(_, _, args) => {
+            Expr::ExternalCall(_, _, args, redirections) => {
                 for arg in args {
                     arg.replace_in_variable(working_set, new_var_id)
                 }
+                for (_, target) in redirections {
+                    target.replace_in_variable(working_set, new_var_id)
+                }
             }
             Expr::Filepath(_) => {}
             Expr::Float(_) => {}
@@ -0,0 +1,7 @@
+/// Where the output of an external command is sent with `out>`, `err>` or `out+err>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redirection {
+    Stdout,
+    Stderr,
+    StdoutAndStderr,
+}
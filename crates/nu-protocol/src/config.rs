@@ -1,4 +1,4 @@
-use crate::{ShellError, Value};
+use crate::{BlockId, ShellError, Span, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -16,6 +16,8 @@ pub struct Config {
     pub float_precision: i64,
     pub filesize_format: String,
     pub use_ansi_coloring: bool,
+    pub env_conversions: HashMap<String, EnvConversion>,
+    pub history_cwd_recall: bool,
 }
 
 impl Default for Config {
@@ -31,6 +33,8 @@ impl Default for Config {
             float_precision: 4,
             filesize_format: "auto".into(),
             use_ansi_coloring: true,
+            env_conversions: HashMap::new(),
+            history_cwd_recall: false,
         }
     }
 }
@@ -47,6 +51,42 @@ pub enum FooterMode {
     Auto,
 }
 
+/// Blocks used to convert an environment variable from and to a string, eg) when reading it on
+/// startup and when passing it to an external command
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EnvConversion {
+    pub from_string: Option<(BlockId, Span)>,
+    pub to_string: Option<(BlockId, Span)>,
+}
+
+impl EnvConversion {
+    pub fn from_record(value: &Value) -> Result<Self, ShellError> {
+        let (cols, vals) = value.as_record()?;
+
+        let mut conv_map = HashMap::new();
+
+        for (k, v) in cols.iter().zip(vals) {
+            if (k == "from_string") || (k == "to_string") {
+                conv_map.insert(k.as_str(), (v.as_block()?, v.span()?));
+            } else {
+                return Err(ShellError::UnsupportedConfigValue(
+                    "'from_string' and 'to_string' fields".into(),
+                    k.into(),
+                    value.span()?,
+                ));
+            }
+        }
+
+        let from_string = conv_map.get("from_string").cloned();
+        let to_string = conv_map.get("to_string").cloned();
+
+        Ok(EnvConversion {
+            from_string,
+            to_string,
+        })
+    }
+}
+
 impl Value {
     pub fn into_config(self) -> Result<Config, ShellError> {
         let v = self.as_record()?;
@@ -126,9 +166,22 @@ impl Value {
                 "use_ansi_coloring" => {
                     config.use_ansi_coloring = value.as_bool()?;
                 }
+                "history_cwd_recall" => {
+                    config.history_cwd_recall = value.as_bool()?;
+                }
                 "filesize_format" => {
                     config.filesize_format = value.as_string()?.to_lowercase();
                 }
+                "env_conversions" => {
+                    let (env_vars, conversions) = value.as_record()?;
+                    let mut env_conversions = HashMap::new();
+
+                    for (env_var, record) in env_vars.iter().zip(conversions) {
+                        env_conversions.insert(env_var.into(), EnvConversion::from_record(record)?);
+                    }
+
+                    config.env_conversions = env_conversions;
+                }
                 _ => {}
             }
         }
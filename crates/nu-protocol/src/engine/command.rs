@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::{ast::Call, BlockId, Example, PipelineData, ShellError, Signature};
+use crate::{ast::Call, BlockId, Example, PipelineData, ShellError, Signature, Type};
 
 use super::{EngineState, Stack};
 
@@ -53,10 +53,31 @@ pub trait Command: Send + Sync + CommandClone {
         None
     }
 
+    // The modification time of the plugin's file when the command's signature was read from it,
+    // in seconds since the unix epoch
+    fn plugin_modified(&self) -> Option<u64> {
+        None
+    }
+
+    // The hash of the plugin's file when the command's signature was read from it
+    fn plugin_hash(&self) -> Option<&str> {
+        None
+    }
+
     // If command is a block i.e. def blah [] { }, get the block id
     fn get_block_id(&self) -> Option<BlockId> {
         None
     }
+
+    // Is the signature of an external command, declared with extern, and run as that external
+    fn is_known_external(&self) -> bool {
+        false
+    }
+
+    // The types of input the command takes, each with the type of output it gives for it
+    fn input_output_types(&self) -> Vec<(Type, Type)> {
+        self.signature().input_output_types
+    }
 }
 
 pub trait CommandClone {
@@ -6,7 +6,7 @@ use crate::{
 use core::panic;
 use std::{
     collections::HashMap,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{atomic::AtomicBool, Arc, Mutex},
 };
 
 #[cfg(feature = "plugin")]
@@ -140,6 +140,8 @@ pub struct EngineState {
     overlays: im::Vector<Overlay>,
     pub scope: im::Vector<ScopeFrame>,
     pub ctrlc: Option<Arc<AtomicBool>>,
+    // Changes made by a command while it runs, which are merged once the evaluation is done
+    pending_delta: Arc<Mutex<Option<StateDelta>>>,
     #[cfg(feature = "plugin")]
     pub plugin_signatures: Option<PathBuf>,
 }
@@ -148,23 +150,58 @@ pub const NU_VARIABLE_ID: usize = 0;
 pub const SCOPE_VARIABLE_ID: usize = 1;
 pub const IN_VARIABLE_ID: usize = 2;
 pub const CONFIG_VARIABLE_ID: usize = 3;
+pub const ENV_VARIABLE_ID: usize = 4;
 
 impl EngineState {
     pub fn new() -> Self {
         Self {
             files: im::vector![],
             file_contents: im::vector![],
-            vars: im::vector![Type::Unknown, Type::Unknown, Type::Unknown, Type::Unknown],
+            vars: im::vector![
+                Type::Unknown,
+                Type::Unknown,
+                Type::Unknown,
+                Type::Unknown,
+                Type::Unknown
+            ],
             decls: im::vector![],
             blocks: im::vector![],
             overlays: im::vector![],
             scope: im::vector![ScopeFrame::new()],
             ctrlc: None,
+            pending_delta: Arc::new(Mutex::new(None)),
             #[cfg(feature = "plugin")]
             plugin_signatures: None,
         }
     }
 
+    /// Hands over changes that a command, like `plugin rescan`, wants to make to the global state.
+    /// Commands only get to read the state while they run, so the changes wait for whoever runs
+    /// the evaluation to merge them with `merge_pending_delta`.
+    ///
+    /// The delta has to be made from the state as it is during the evaluation. A newer delta
+    /// replaces one that is still waiting, as both would be made from the same state.
+    pub fn queue_delta(&self, delta: StateDelta) {
+        *self
+            .pending_delta
+            .lock()
+            .expect("pending delta lock poisoned") = Some(delta);
+    }
+
+    /// Merges the changes queued by the commands of the last evaluation, if there are any
+    pub fn merge_pending_delta(&mut self) -> Result<(), ShellError> {
+        let delta = self
+            .pending_delta
+            .lock()
+            .expect("pending delta lock poisoned")
+            .take();
+
+        match delta {
+            Some(delta) => self.merge_delta(delta),
+            None => Ok(()),
+        }
+    }
+
     /// Merges a `StateDelta` onto the current state. These deltas come from a system, like the parser, that
     /// creates a new set of definitions and visible symbols in the current scope. We make this transactional
     /// as there are times when we want to run the parser and immediately throw away the results (namely:
@@ -233,9 +270,25 @@ impl EngineState {
                     let (path, encoding) = decl.is_plugin().expect("plugin should have file name");
                     let file_name = path.to_str().expect("path should be a str");
 
+                    // The modification time tells if the plugin has changed since its signature
+                    // was saved, in which case it is asked for its signatures again
+                    let modified = decl
+                        .plugin_modified()
+                        .map(|modified| format!(" --modified {}", modified))
+                        .unwrap_or_default();
+
+                    // The hash tells if the plugin really changed when only its time did
+                    let hash = decl
+                        .plugin_hash()
+                        .map(|hash| format!(" --hash {}", hash))
+                        .unwrap_or_default();
+
                     serde_json::to_string_pretty(&decl.signature())
                         .map(|signature| {
-                            format!("register {} -e {} {}\n\n", file_name, encoding, signature)
+                            format!(
+                                "register {} -e {}{}{} {}\n\n",
+                                file_name, encoding, modified, hash, signature
+                            )
                         })
                         .map_err(|err| ShellError::PluginFailedToLoad(err.to_string()))
                         .and_then(|line| {
@@ -310,15 +363,18 @@ impl EngineState {
 
     #[cfg(feature = "plugin")]
     pub fn plugin_decls(&self) -> impl Iterator<Item = &Box<dyn Command + 'static>> {
-        let mut unique_plugin_decls = HashMap::new();
-
-        // Make sure there are no duplicate decls: Newer one overwrites the older one
-        for decl in self.decls.iter().filter(|d| d.is_plugin().is_some()) {
-            unique_plugin_decls.insert(decl.name(), decl);
-        }
-
-        let mut plugin_decls: Vec<(&str, &Box<dyn Command>)> =
-            unique_plugin_decls.into_iter().collect();
+        // Only the decls that their name still finds count, which leaves out the ones replaced by
+        // a newer decl and the ones hidden, eg) the commands a plugin no longer has
+        let mut plugin_decls: Vec<(&str, &Box<dyn Command>)> = self
+            .decls
+            .iter()
+            .enumerate()
+            .filter(|(decl_id, decl)| {
+                decl.is_plugin().is_some()
+                    && self.find_decl(decl.name().as_bytes()) == Some(*decl_id)
+            })
+            .map(|(_, decl)| (decl.name(), decl))
+            .collect();
 
         // Sort the plugins by name so we don't end up with a random plugin file each time
         plugin_decls.sort_by(|a, b| a.0.cmp(b.0));
@@ -15,7 +15,9 @@ mod value;
 pub use value::Value;
 
 pub use config::*;
-pub use engine::{CONFIG_VARIABLE_ID, IN_VARIABLE_ID, NU_VARIABLE_ID, SCOPE_VARIABLE_ID};
+pub use engine::{
+    CONFIG_VARIABLE_ID, ENV_VARIABLE_ID, IN_VARIABLE_ID, NU_VARIABLE_ID, SCOPE_VARIABLE_ID,
+};
 pub use example::*;
 pub use exportable::*;
 pub use id::*;
@@ -1,4 +1,4 @@
-use std::sync::{atomic::AtomicBool, Arc};
+use std::sync::{atomic::AtomicBool, mpsc, Arc};
 
 use crate::{ast::PathMember, Config, ShellError, Span, Value, ValueStream};
 
@@ -45,6 +45,17 @@ pub struct PipelineMetadata {
 #[derive(Debug)]
 pub enum DataSource {
     Ls,
+    /// The stdout of an external command. How the command exited is sent once the stdout has
+    /// been drained
+    External(mpsc::Receiver<ExternalExit>),
+}
+
+/// How an external command finished running
+#[derive(Debug)]
+pub struct ExternalExit {
+    /// Only captured when the stderr is collected, eg) by `complete`
+    pub stderr: Option<String>,
+    pub exit_code: i64,
 }
 
 impl PipelineData {
@@ -62,6 +73,24 @@ impl PipelineData {
         }
     }
 
+    /// Take the exit of the external this data is streamed from, so the exit code can still be
+    /// read once the data has been drained
+    pub fn take_external_exit(&mut self) -> Option<mpsc::Receiver<ExternalExit>> {
+        let metadata = match self {
+            PipelineData::Value(_, metadata) | PipelineData::Stream(_, metadata) => metadata,
+        };
+
+        match metadata.take() {
+            Some(PipelineMetadata {
+                data_source: DataSource::External(exit),
+            }) => Some(exit),
+            other => {
+                *metadata = other;
+                None
+            }
+        }
+    }
+
     pub fn into_interruptible_iter(self, ctrlc: Option<Arc<AtomicBool>>) -> PipelineIterator {
         let mut iter = self.into_iter();
 
@@ -2,7 +2,7 @@ use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ast::Operator, Span, Type};
+use crate::{ast::Operator, Span, Type, Value};
 
 /// The fundamental error type for the evaluation engine. These cases represent different kinds of errors
 /// the evaluator might face, along with helpful spans to label. An error renderer will take this error value
@@ -103,6 +103,10 @@ pub enum ShellError {
     #[diagnostic(code(nu::shell::variable_not_found), url(docsrs))]
     EnvVarNotAString(#[label = "does not evaluate to a string"] Span),
 
+    #[error("Unsupported config value")]
+    #[diagnostic(code(nu::shell::unsupported_config_value), url(docsrs))]
+    UnsupportedConfigValue(String, String, #[label("expected {0}, got {1}")] Span),
+
     #[error("Not found.")]
     #[diagnostic(code(nu::parser::not_found), url(docsrs))]
     NotFound(#[label = "did not find anything under this name"] Span),
@@ -138,6 +142,13 @@ pub enum ShellError {
         #[label = "value originates here"] Span,
     ),
 
+    #[error("Column already exists")]
+    #[diagnostic(code(nu::shell::column_already_exists), url(docsrs))]
+    ColumnAlreadyExists(
+        #[label = "column already exists"] Span,
+        #[label = "value originates here"] Span,
+    ),
+
     #[error("Not a list value")]
     #[diagnostic(code(nu::shell::not_a_list), url(docsrs))]
     NotAList(
@@ -242,6 +253,88 @@ pub enum ShellError {
     #[error("{0}")]
     #[diagnostic()]
     LabeledError(String, String),
+
+    /// Control flow signal raised by `break`, caught by the enclosing loop
+    #[error("Break used outside of loop")]
+    #[diagnostic(code(nu::shell::break_outside_loop), url(docsrs))]
+    Break(#[label = "used outside of loop"] Span),
+
+    /// Control flow signal raised by `continue`, caught by the enclosing loop
+    #[error("Continue used outside of loop")]
+    #[diagnostic(code(nu::shell::continue_outside_loop), url(docsrs))]
+    Continue(#[label = "used outside of loop"] Span),
+
+    /// Control flow signal raised by `return`, caught by the enclosing custom command
+    #[error("Return used outside of custom command")]
+    #[diagnostic(code(nu::shell::return_outside_custom_command), url(docsrs))]
+    Return(#[label = "used outside of custom command"] Span, Box<Value>),
+}
+
+impl ShellError {
+    /// Whether this is a `break`, `continue` or `return` signal rather than an actual failure
+    pub fn is_control_flow(&self) -> bool {
+        matches!(
+            self,
+            ShellError::Break(..) | ShellError::Continue(..) | ShellError::Return(..)
+        )
+    }
+
+    /// The name of the error variant, eg) "CantFindColumn"
+    pub fn variant_name(&self) -> String {
+        format!("{:?}", self)
+            .chars()
+            .take_while(|c| c.is_alphanumeric())
+            .collect()
+    }
+
+    /// Turn the error into a record so that scripts can inspect it, eg) inside a `catch` block
+    pub fn into_value(self, span: Span) -> Value {
+        let (label, error_span) = match self.labels().and_then(|mut labels| labels.next()) {
+            Some(labeled) => (
+                Value::String {
+                    val: labeled.label().unwrap_or_default().to_string(),
+                    span,
+                },
+                Value::Record {
+                    cols: vec!["start".into(), "end".into()],
+                    vals: vec![
+                        Value::Int {
+                            val: labeled.offset() as i64,
+                            span,
+                        },
+                        Value::Int {
+                            val: (labeled.offset() + labeled.len()) as i64,
+                            span,
+                        },
+                    ],
+                    span,
+                },
+            ),
+            None => (Value::Nothing { span }, Value::Nothing { span }),
+        };
+
+        Value::Record {
+            cols: vec![
+                "msg".into(),
+                "label".into(),
+                "span".into(),
+                "variant".into(),
+            ],
+            vals: vec![
+                Value::String {
+                    val: self.to_string(),
+                    span,
+                },
+                label,
+                error_span,
+                Value::String {
+                    val: self.variant_name(),
+                    span,
+                },
+            ],
+            span,
+        }
+    }
 }
 
 impl From<std::io::Error> for ShellError {
@@ -8,9 +8,11 @@ use crate::engine::Stack;
 use crate::BlockId;
 use crate::PipelineData;
 use crate::SyntaxShape;
+use crate::Type;
+use crate::Value;
 use crate::VarId;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Flag {
     pub long: String,
     pub short: Option<char>,
@@ -19,15 +21,17 @@ pub struct Flag {
     pub desc: String,
     // For custom commands
     pub var_id: Option<VarId>,
+    pub default_value: Option<Value>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PositionalArg {
     pub name: String,
     pub desc: String,
     pub shape: SyntaxShape,
     // For custom commands
     pub var_id: Option<VarId>,
+    pub default_value: Option<Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -94,6 +98,9 @@ pub struct Signature {
     pub named: Vec<Flag>,
     pub is_filter: bool,
     pub creates_scope: bool,
+    // The types of input the command takes, each with the type of output it gives for it.
+    // Empty when the command hasn't said, and then any input is allowed
+    pub input_output_types: Vec<(Type, Type)>,
     // Signature category used to classify commands stored in the list of declarations
     pub category: Category,
 }
@@ -121,6 +128,7 @@ impl Signature {
             desc: "Display this help message".into(),
             required: false,
             var_id: None,
+            default_value: None,
         };
 
         Signature {
@@ -133,6 +141,7 @@ impl Signature {
             named: vec![flag],
             is_filter: false,
             creates_scope: false,
+            input_output_types: vec![],
             category: Category::Default,
         }
     }
@@ -158,6 +167,7 @@ impl Signature {
             desc: desc.into(),
             shape: shape.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -175,6 +185,7 @@ impl Signature {
             desc: desc.into(),
             shape: shape.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -191,6 +202,7 @@ impl Signature {
             desc: desc.into(),
             shape: shape.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -213,6 +225,7 @@ impl Signature {
             required: false,
             desc: desc.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -235,6 +248,7 @@ impl Signature {
             required: true,
             desc: desc.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -256,6 +270,7 @@ impl Signature {
             required: false,
             desc: desc.into(),
             var_id: None,
+            default_value: None,
         });
 
         self
@@ -268,6 +283,18 @@ impl Signature {
         self
     }
 
+    /// Add a type of input the command takes, and the type of output it gives for it
+    pub fn input_output_type(mut self, input_type: Type, output_type: Type) -> Signature {
+        self.input_output_types.push((input_type, output_type));
+        self
+    }
+
+    /// Set the types of input the command takes, and the type of output it gives for each
+    pub fn input_output_types(mut self, input_output_types: Vec<(Type, Type)>) -> Signature {
+        self.input_output_types = input_output_types;
+        self
+    }
+
     /// Sets that signature will create a scope as it parses
     pub fn creates_scope(mut self) -> Signature {
         self.creates_scope = true;
@@ -400,6 +427,12 @@ impl Signature {
             block_id,
         })
     }
+
+    /// Turns the signature of an external command, declared with extern, into a command that
+    /// is parsed like an internal one and run as the external
+    pub fn into_known_external(self) -> Box<dyn Command> {
+        Box::new(KnownExternal { signature: self })
+    }
 }
 
 #[derive(Clone)]
@@ -464,3 +497,36 @@ impl Command for BlockCommand {
         Some(self.block_id)
     }
 }
+
+#[derive(Clone)]
+struct KnownExternal {
+    signature: Signature,
+}
+
+impl Command for KnownExternal {
+    fn name(&self) -> &str {
+        &self.signature.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.signature.clone()
+    }
+
+    fn usage(&self) -> &str {
+        &self.signature.usage
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        _input: PipelineData,
+    ) -> Result<crate::PipelineData, crate::ShellError> {
+        panic!("Internal error: can't run a known external with 'run', use is_known_external");
+    }
+
+    fn is_known_external(&self) -> bool {
+        true
+    }
+}
@@ -38,6 +38,7 @@ impl Display for Type {
             Type::Float => write!(f, "float"),
             Type::Int => write!(f, "int"),
             Type::Range => write!(f, "range"),
+            Type::Record(fields) if fields.is_empty() => write!(f, "record"),
             Type::Record(fields) => write!(
                 f,
                 "record<{}>",
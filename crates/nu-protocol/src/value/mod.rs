@@ -643,6 +643,74 @@ impl Value {
         Ok(())
     }
 
+    /// Insert a new value at the given cell path, creating any missing columns along the way
+    pub fn insert_data_at_cell_path(
+        &mut self,
+        cell_path: &[PathMember],
+        new_val: Value,
+    ) -> Result<(), ShellError> {
+        match cell_path.first() {
+            Some(path_member) => match path_member {
+                PathMember::String {
+                    val: col_name,
+                    span,
+                } => match self {
+                    Value::List { vals, .. } => {
+                        for val in vals.iter_mut() {
+                            val.insert_data_at_cell_path(cell_path, new_val.clone())?
+                        }
+                    }
+                    Value::Record {
+                        cols,
+                        vals,
+                        span: v_span,
+                    } => match cols.iter().position(|col| col == col_name) {
+                        Some(idx) => {
+                            if cell_path.len() == 1 {
+                                return Err(ShellError::ColumnAlreadyExists(*span, *v_span));
+                            }
+                            vals[idx].insert_data_at_cell_path(&cell_path[1..], new_val)?
+                        }
+                        None => {
+                            let mut new_col = Value::Record {
+                                cols: vec![],
+                                vals: vec![],
+                                span: *span,
+                            };
+                            new_col.insert_data_at_cell_path(&cell_path[1..], new_val)?;
+
+                            cols.push(col_name.clone());
+                            vals.push(new_col);
+                        }
+                    },
+                    v => {
+                        return Err(ShellError::IncompatiblePathAccess(
+                            format!("{}", v.get_type()),
+                            *span,
+                        ))
+                    }
+                },
+                PathMember::Int { val: row_num, span } => match self {
+                    Value::List { vals, .. } => {
+                        if let Some(v) = vals.get_mut(*row_num) {
+                            v.insert_data_at_cell_path(&cell_path[1..], new_val)?
+                        } else if vals.len() == *row_num && cell_path.len() == 1 {
+                            // Inserting right after the last row appends to the list
+                            vals.push(new_val);
+                        } else {
+                            return Err(ShellError::AccessBeyondEnd(vals.len(), *span));
+                        }
+                    }
+                    v => return Err(ShellError::NotAList(*span, v.span()?)),
+                },
+            },
+            None => {
+                *self = new_val;
+            }
+        }
+        Ok(())
+    }
+
     pub fn is_true(&self) -> bool {
         matches!(self, Value::Bool { val: true, .. })
     }
@@ -27,6 +27,7 @@ impl GStat {
         &self,
         value: &Value,
         path: Option<Spanned<String>>,
+        current_dir: &str,
         span: &Span,
     ) -> Result<Value, LabeledError> {
         // use std::any::Any;
@@ -64,8 +65,12 @@ impl GStat {
             a_path.item = piped_value;
         }
 
+        // The plugin keeps running across `cd`, so relative paths are resolved against the
+        // current directory of the shell instead of the plugin's own
+        let full_path = PathBuf::from(current_dir).join(&a_path.item);
+
         // This path has to exist
-        if !std::path::Path::new(&a_path.item).exists() {
+        if !full_path.exists() {
             return Err(LabeledError {
                 label: "error with path".to_string(),
                 msg: format!("path does not exist [{}]", &a_path.item),
@@ -77,7 +82,7 @@ impl GStat {
             });
         }
 
-        let metadata = match std::fs::metadata(&a_path.item) {
+        let metadata = match std::fs::metadata(&full_path) {
             Ok(md) => md,
             Err(e) => {
                 return Err(LabeledError {
@@ -108,7 +113,7 @@ impl GStat {
             });
         }
 
-        let repo_path = match PathBuf::from(&a_path.item).canonicalize() {
+        let repo_path = match full_path.canonicalize() {
             Ok(p) => p,
             Err(e) => {
                 return Err(LabeledError {
@@ -1,5 +1,5 @@
 use crate::GStat;
-use nu_plugin::{EvaluatedCall, LabeledError, Plugin};
+use nu_plugin::{EngineInterface, EvaluatedCall, LabeledError, Plugin};
 use nu_protocol::{Category, Signature, Span, Spanned, SyntaxShape, Value};
 
 impl Plugin for GStat {
@@ -13,6 +13,7 @@ impl Plugin for GStat {
     fn run(
         &mut self,
         name: &str,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: &Value,
     ) -> Result<Value, LabeledError> {
@@ -23,7 +24,8 @@ impl Plugin for GStat {
         }
 
         let repo_path: Option<Spanned<String>> = call.opt(0)?;
+        let current_dir = engine.get_current_dir()?;
         // eprintln!("input value: {:#?}", &input);
-        self.gstat(input, repo_path, &call.head)
+        self.gstat(input, repo_path, &current_dir, &call.head)
     }
 }
@@ -1,6 +1,6 @@
 use crate::inc::SemVerAction;
 use crate::Inc;
-use nu_plugin::{EvaluatedCall, LabeledError, Plugin};
+use nu_plugin::{EngineInterface, EvaluatedCall, LabeledError, Plugin};
 use nu_protocol::{Signature, Span, Value};
 
 impl Plugin for Inc {
@@ -27,6 +27,7 @@ impl Plugin for Inc {
     fn run(
         &mut self,
         name: &str,
+        _engine: &EngineInterface,
         call: &EvaluatedCall,
         input: &Value,
     ) -> Result<Value, LabeledError> {
@@ -0,0 +1,185 @@
+use nu_protocol::{ShellError, Signature, SyntaxShape};
+
+/// The flags and arguments engine-q was started with
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NushellCliArgs {
+    pub script_name: Option<String>,
+    pub args_to_script: Vec<String>,
+    pub commands: Option<String>,
+    pub config_file: Option<String>,
+    pub env_file: Option<String>,
+    pub redirect_stdin: bool,
+    pub login_shell: bool,
+    pub no_config: bool,
+    pub no_history: bool,
+    pub help: bool,
+}
+
+impl NushellCliArgs {
+    /// Whether engine-q should start the REPL rather than run commands or a script and exit
+    pub fn is_interactive(&self) -> bool {
+        self.commands.is_none() && self.script_name.is_none()
+    }
+}
+
+pub fn nu_signature() -> Signature {
+    Signature::build("engine-q")
+        .desc("The nushell language and shell.")
+        .named(
+            "commands",
+            SyntaxShape::String,
+            "run the given commands and then exit",
+            Some('c'),
+        )
+        .switch(
+            "stdin",
+            "redirect the standard input into the commands or script as $in",
+            None,
+        )
+        .named(
+            "config",
+            SyntaxShape::Filepath,
+            "start with an alternate config file",
+            None,
+        )
+        .named(
+            "env-config",
+            SyntaxShape::Filepath,
+            "start with an alternate environment config file",
+            None,
+        )
+        .switch(
+            "no-config",
+            "start without loading the default config files",
+            None,
+        )
+        .switch(
+            "no-history",
+            "start without reading or saving history",
+            None,
+        )
+        .switch(
+            "login",
+            "start as a login shell, also loading login.nu",
+            Some('l'),
+        )
+        .optional(
+            "script file",
+            SyntaxShape::Filepath,
+            "the script to run, instead of starting the REPL",
+        )
+        .rest(
+            "script args",
+            SyntaxShape::String,
+            "the arguments passed to the main command of the script",
+        )
+}
+
+/// Split the command line into engine-q's own flags and the script with its arguments.
+///
+/// Flags are only recognised before the script name, everything after it belongs to the script.
+pub fn parse_commandline_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<NushellCliArgs, ShellError> {
+    let signature = nu_signature();
+    let mut cli_args = NushellCliArgs::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            cli_args.script_name = args.next();
+            break;
+        }
+
+        let flags = if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (long, None),
+            };
+
+            match signature.get_long_flag(name) {
+                Some(flag) => vec![(flag, value)],
+                None => {
+                    return Err(ShellError::LabeledError(
+                        "Unknown flag".into(),
+                        format!("engine-q has no flag named '--{}'", name),
+                    ))
+                }
+            }
+        } else if arg.len() > 1 && arg.starts_with('-') {
+            let mut flags = vec![];
+            for short in arg.chars().skip(1) {
+                match signature.get_short_flag(short) {
+                    Some(flag) => flags.push((flag, None)),
+                    None => {
+                        return Err(ShellError::LabeledError(
+                            "Unknown flag".into(),
+                            format!("engine-q has no flag named '-{}'", short),
+                        ))
+                    }
+                }
+            }
+            flags
+        } else {
+            cli_args.script_name = Some(arg);
+            break;
+        };
+
+        let last = flags.len() - 1;
+        for (idx, (flag, value)) in flags.into_iter().enumerate() {
+            let value = match (&flag.arg, value) {
+                (None, None) => None,
+                (None, Some(_)) => {
+                    return Err(ShellError::LabeledError(
+                        "Unexpected flag value".into(),
+                        format!("'--{}' is a switch and doesn't take a value", flag.long),
+                    ))
+                }
+                (Some(_), Some(value)) => Some(value),
+                // Only the last of a group of short flags can take a value, eg) `-lc 'ls'`.
+                // Check that before consuming anything, so the next argument stays put.
+                (Some(_), None) if idx != last => {
+                    return Err(ShellError::LabeledError(
+                        "Missing flag value".into(),
+                        format!(
+                            "'--{}' needs a value and must come last in '{}'",
+                            flag.long, arg
+                        ),
+                    ))
+                }
+                (Some(_), None) => match args.next() {
+                    Some(value) => Some(value),
+                    None => {
+                        return Err(ShellError::LabeledError(
+                            "Missing flag value".into(),
+                            format!("'--{}' needs a value", flag.long),
+                        ))
+                    }
+                },
+            };
+
+            match (flag.long.as_str(), value) {
+                ("commands", Some(value)) => cli_args.commands = Some(value),
+                ("config", Some(value)) => cli_args.config_file = Some(value),
+                ("env-config", Some(value)) => cli_args.env_file = Some(value),
+                ("stdin", _) => cli_args.redirect_stdin = true,
+                ("login", _) => cli_args.login_shell = true,
+                ("no-config", _) => cli_args.no_config = true,
+                ("no-history", _) => cli_args.no_history = true,
+                ("help", _) => cli_args.help = true,
+                _ => {}
+            }
+        }
+    }
+
+    cli_args.args_to_script = args.collect();
+
+    if cli_args.commands.is_some() && cli_args.script_name.is_some() {
+        return Err(ShellError::LabeledError(
+            "Too many inputs".into(),
+            "engine-q takes either --commands or a script file, not both".into(),
+        ));
+    }
+
+    Ok(cli_args)
+}
@@ -7,52 +7,79 @@ use dialoguer::{
 };
 use miette::{IntoDiagnostic, Result};
 use nu_cli::{CliError, NuCompleter, NuHighlighter, NuValidator, NushellPrompt};
-use nu_command::create_default_context;
-use nu_engine::eval_block;
+use nu_command::{create_default_context, history_path, hostname, CommandHistory, HistoryEntry};
+use nu_engine::{convert_env_values, eval_block, get_full_help};
 use nu_parser::parse;
 use nu_protocol::{
-    ast::Call,
+    ast::{Block, Call},
     engine::{EngineState, Stack, StateWorkingSet},
-    Config, PipelineData, ShellError, Span, Value, CONFIG_VARIABLE_ID,
+    Config, ExternalExit, PipelineData, ShellError, Span, Value, CONFIG_VARIABLE_ID,
+    IN_VARIABLE_ID,
 };
-use reedline::{Completer, CompletionActionHandler, DefaultPrompt, LineBuffer, Prompt};
+use reedline::{CompletionActionHandler, LineBuffer};
 use std::{
-    io::Write,
+    io::{Read, Write},
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        mpsc, Arc,
     },
+    time::Instant,
 };
 
+mod commandline;
+mod prompt_update;
 #[cfg(test)]
 mod tests;
 
-// Name of environment variable where the prompt could be stored
-const PROMPT_COMMAND: &str = "PROMPT_COMMAND";
+use commandline::{nu_signature, parse_commandline_args, NushellCliArgs};
+use prompt_update::{update_prompt, PromptCache};
+
+// How many of the last commands the line editor can recall
+const HISTORY_RECALL_SIZE: usize = 1000;
 
 struct FuzzyCompletion {
-    completer: Box<dyn Completer>,
+    completer: NuCompleter,
 }
 
 impl CompletionActionHandler for FuzzyCompletion {
     fn handle(&mut self, present_buffer: &mut LineBuffer) {
         let completions = self
             .completer
-            .complete(present_buffer.get_buffer(), present_buffer.offset());
+            .complete_with_descriptions(present_buffer.get_buffer(), present_buffer.offset());
 
         if completions.is_empty() {
             // do nothing
         } else if completions.len() == 1 {
-            let span = completions[0].0;
+            let span = completions[0].span;
 
             let mut offset = present_buffer.offset();
-            offset += completions[0].1.len() - (span.end - span.start);
+            offset += completions[0].value.len() - (span.end - span.start);
 
             // TODO improve the support for multiline replace
-            present_buffer.replace(span.start..span.end, &completions[0].1);
+            present_buffer.replace(span.start..span.end, &completions[0].value);
             present_buffer.set_insertion_point(offset);
         } else {
-            let selections: Vec<_> = completions.iter().map(|(_, string)| string).collect();
+            // The descriptions are lined up after the longest completion
+            let width = completions
+                .iter()
+                .map(|suggestion| suggestion.value.chars().count())
+                .max()
+                .unwrap_or(0);
+            let selections: Vec<_> = completions
+                .iter()
+                .map(|suggestion| match &suggestion.description {
+                    Some(description) => {
+                        format!(
+                            "{:width$}  {}",
+                            suggestion.value,
+                            description,
+                            width = width
+                        )
+                    }
+                    None => suggestion.value.clone(),
+                })
+                .collect();
 
             let _ = crossterm::terminal::disable_raw_mode();
             println!();
@@ -68,13 +95,13 @@ impl CompletionActionHandler for FuzzyCompletion {
             let _ = crossterm::terminal::enable_raw_mode();
 
             if let Some(result) = result {
-                let span = completions[result].0;
+                let span = completions[result].span;
 
                 let mut offset = present_buffer.offset();
-                offset += completions[result].1.len() - (span.end - span.start);
+                offset += completions[result].value.len() - (span.end - span.start);
 
                 // TODO improve the support for multiline replace
-                present_buffer.replace(span.start..span.end, &completions[result].1);
+                present_buffer.replace(span.start..span.end, &completions[result].value);
                 present_buffer.set_insertion_point(offset);
             }
         }
@@ -105,281 +132,469 @@ fn main() -> Result<()> {
     engine_state.ctrlc = Some(engine_state_ctrlc);
     // End ctrl-c protection section
 
-    if let Some(path) = std::env::args().nth(1) {
-        let file = std::fs::read(&path).into_diagnostic()?;
-
-        let (block, delta) = {
-            let mut working_set = StateWorkingSet::new(&engine_state);
-            let (output, err) = parse(&mut working_set, Some(&path), &file, false);
-            if let Some(err) = err {
-                report_error(&working_set, &err);
-
-                std::process::exit(1);
-            }
-            (output, working_set.render())
-        };
-
-        if let Err(err) = engine_state.merge_delta(delta) {
+    let cli_args = match parse_commandline_args(std::env::args().skip(1)) {
+        Ok(cli_args) => cli_args,
+        Err(err) => {
             let working_set = StateWorkingSet::new(&engine_state);
             report_error(&working_set, &err);
+
+            std::process::exit(1);
         }
+    };
 
-        let mut stack = nu_protocol::engine::Stack::new();
+    if cli_args.help {
+        println!("{}", get_full_help(&nu_signature(), &[], &engine_state));
+        return Ok(());
+    }
 
-        for (k, v) in std::env::vars() {
-            stack.add_env_var(k, v);
-        }
+    let mut stack = nu_protocol::engine::Stack::new();
 
-        // Set up our initial config to start from
-        stack.vars.insert(
-            CONFIG_VARIABLE_ID,
-            Value::Record {
-                cols: vec![],
-                vals: vec![],
+    for (k, v) in std::env::vars() {
+        stack.add_env_var(
+            k,
+            Value::String {
+                val: v,
                 span: Span::unknown(),
             },
         );
+    }
 
-        let config = match stack.get_config() {
-            Ok(config) => config,
-            Err(e) => {
-                let working_set = StateWorkingSet::new(&engine_state);
+    // Set up our initial config to start from
+    stack.vars.insert(
+        CONFIG_VARIABLE_ID,
+        Value::Record {
+            cols: vec![],
+            vals: vec![],
+            span: Span::unknown(),
+        },
+    );
+
+    // Load the config startup files. Outside of the REPL only the ones asked for are loaded.
+    let load_defaults = cli_args.is_interactive() && !cli_args.no_config;
+    let env_path = config_file_path(cli_args.env_file.as_deref(), "env.nu", load_defaults);
+    let config_path = config_file_path(cli_args.config_file.as_deref(), "config.nu", load_defaults);
+    let login_path = config_file_path(
+        None,
+        "login.nu",
+        cli_args.login_shell && !cli_args.no_config,
+    );
+
+    for path in [env_path, config_path, login_path].into_iter().flatten() {
+        if cli_args.is_interactive() {
+            // FIXME: remove this message when we're ready
+            println!("Loading config from: {:?}", path);
+        }
+        load_config_file(&mut engine_state, &mut stack, &path);
+    }
 
-                report_error(&working_set, &e);
-                Config::default()
-            }
+    // Translate environment variables from Strings to Values
+    let config = match stack.get_config() {
+        Ok(config) => config,
+        Err(e) => {
+            let working_set = StateWorkingSet::new(&engine_state);
+
+            report_error(&working_set, &e);
+            Config::default()
+        }
+    };
+    if let Some(e) = convert_env_values(&engine_state, &mut stack, &config) {
+        let working_set = StateWorkingSet::new(&engine_state);
+        report_error(&working_set, &e);
+    }
+
+    if cli_args.is_interactive() {
+        let result = run_repl(engine_state, stack, &cli_args, ctrlc);
+        stop_plugins();
+
+        return result;
+    }
+
+    let input = if cli_args.redirect_stdin {
+        let mut buffer = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buffer)
+            .into_diagnostic()?;
+
+        let stdin = Value::String {
+            val: buffer,
+            span: Span::unknown(),
         };
 
-        match eval_block(
-            &engine_state,
-            &mut stack,
-            &block,
-            PipelineData::new(Span::unknown()),
-        ) {
-            Ok(pipeline_data) => {
-                for item in pipeline_data {
-                    if let Value::Error { error } = item {
-                        let working_set = StateWorkingSet::new(&engine_state);
+        // Also bind $in, so it can be used at the start of a pipeline
+        stack.add_var(IN_VARIABLE_ID, stdin.clone());
 
-                        report_error(&working_set, &error);
+        PipelineData::Value(stdin, None)
+    } else {
+        PipelineData::new(Span::unknown())
+    };
 
-                        std::process::exit(1);
-                    }
-                    println!("{}", item.into_string("\n", &config));
-                }
+    // Don't let an exit code inherited from the parent process leak into ours
+    set_last_exit_code(&mut stack, 0);
 
-                // Next, let's check if there are any flags we want to pass to the main function
-                let args: Vec<String> = std::env::args().skip(2).collect();
+    if let Some(commands) = &cli_args.commands {
+        eval_source(
+            &mut engine_state,
+            &mut stack,
+            commands,
+            "<commandline>",
+            input,
+        );
+    } else if let Some(script_name) = &cli_args.script_name {
+        run_script(
+            &mut engine_state,
+            &mut stack,
+            script_name,
+            &cli_args.args_to_script,
+            input,
+        )?;
+    }
 
-                if args.is_empty() && engine_state.find_decl(b"main").is_none() {
-                    return Ok(());
-                }
+    stop_plugins();
 
-                let args = format!("main {}", args.join(" ")).as_bytes().to_vec();
+    std::process::exit(last_exit_code(&stack))
+}
 
-                let (block, delta) = {
-                    let mut working_set = StateWorkingSet::new(&engine_state);
-                    let (output, err) = parse(&mut working_set, Some("<cmdline>"), &args, false);
-                    if let Some(err) = err {
-                        report_error(&working_set, &err);
+fn run_script(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    path: &str,
+    args: &[String],
+    input: PipelineData,
+) -> Result<()> {
+    let file = std::fs::read(path).into_diagnostic()?;
 
-                        std::process::exit(1);
-                    }
-                    (output, working_set.render())
-                };
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let (output, err) = parse(&mut working_set, Some(path), &file, false);
+        if let Some(err) = err {
+            report_error(&working_set, &err);
 
-                if let Err(err) = engine_state.merge_delta(delta) {
-                    let working_set = StateWorkingSet::new(&engine_state);
-                    report_error(&working_set, &err);
-                }
+            std::process::exit(1);
+        }
+        (output, working_set.render())
+    };
 
-                match eval_block(
-                    &engine_state,
-                    &mut stack,
-                    &block,
-                    PipelineData::new(Span::unknown()),
-                ) {
-                    Ok(pipeline_data) => {
-                        for item in pipeline_data {
-                            if let Value::Error { error } = item {
-                                let working_set = StateWorkingSet::new(&engine_state);
+    if let Err(err) = engine_state.merge_delta(delta) {
+        let working_set = StateWorkingSet::new(engine_state);
+        report_error(&working_set, &err);
+    }
 
-                                report_error(&working_set, &error);
+    // The input goes to the main function if there is one, otherwise to the script itself
+    let has_main = engine_state.find_decl(b"main").is_some();
+    let (script_input, main_input) = if has_main {
+        (PipelineData::new(Span::unknown()), input)
+    } else {
+        (input, PipelineData::new(Span::unknown()))
+    };
 
-                                std::process::exit(1);
-                            }
-                            println!("{}", item.into_string("\n", &config));
-                        }
-                    }
-                    Err(err) => {
-                        let working_set = StateWorkingSet::new(&engine_state);
+    run_and_print(engine_state, stack, &block, script_input);
 
-                        report_error(&working_set, &err);
+    // Next, let's check if there are any flags we want to pass to the main function
+    if args.is_empty() && !has_main {
+        return Ok(());
+    }
 
-                        std::process::exit(1);
-                    }
-                }
-            }
-            Err(err) => {
-                let working_set = StateWorkingSet::new(&engine_state);
+    let args = format!("main {}", args.join(" ")).as_bytes().to_vec();
 
-                report_error(&working_set, &err);
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let (output, err) = parse(&mut working_set, Some("<cmdline>"), &args, false);
+        if let Some(err) = err {
+            report_error(&working_set, &err);
 
-                std::process::exit(1);
-            }
+            std::process::exit(1);
         }
+        (output, working_set.render())
+    };
 
-        Ok(())
-    } else {
-        use reedline::{FileBackedHistory, Reedline, Signal};
+    if let Err(err) = engine_state.merge_delta(delta) {
+        let working_set = StateWorkingSet::new(engine_state);
+        report_error(&working_set, &err);
+    }
 
-        let mut entry_num = 0;
+    run_and_print(engine_state, stack, &block, main_input);
 
-        let default_prompt = DefaultPrompt::new(1);
-        let mut nu_prompt = NushellPrompt::new();
-        let mut stack = nu_protocol::engine::Stack::new();
+    Ok(())
+}
 
-        for (k, v) in std::env::vars() {
-            stack.add_env_var(k, v);
-        }
+/// Run a block of a script, printing its output line by line. Any error ends the process.
+fn run_and_print(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    block: &Block,
+    input: PipelineData,
+) {
+    let config = stack.get_config().unwrap_or_default();
 
-        // Set up our initial config to start from
-        stack.vars.insert(
-            CONFIG_VARIABLE_ID,
-            Value::Record {
-                cols: vec![],
-                vals: vec![],
-                span: Span::unknown(),
-            },
-        );
+    match eval_block(engine_state, stack, block, input) {
+        Ok(mut pipeline_data) => {
+            let external_exit = pipeline_data.take_external_exit();
 
-        // Load config startup file
-        if let Some(mut config_path) = nu_path::config_dir() {
-            config_path.push("nushell");
-            config_path.push("config.nu");
+            for item in pipeline_data {
+                if let Value::Error { error } = item {
+                    let working_set = StateWorkingSet::new(engine_state);
 
-            if config_path.exists() {
-                // FIXME: remove this message when we're ready
-                println!("Loading config from: {:?}", config_path);
-                let config_filename = config_path.to_string_lossy().to_owned();
+                    report_error(&working_set, &error);
 
-                if let Ok(contents) = std::fs::read_to_string(&config_path) {
-                    eval_source(&mut engine_state, &mut stack, &contents, &config_filename);
+                    std::process::exit(1);
                 }
+                println!("{}", item.into_string("\n", &config));
             }
+
+            read_external_exit(stack, external_exit);
         }
+        Err(err) => {
+            let working_set = StateWorkingSet::new(engine_state);
 
-        let history_path = if let Some(mut history_path) = nu_path::config_dir() {
-            history_path.push("nushell");
-            history_path.push("history.txt");
+            report_error(&working_set, &err);
 
-            Some(history_path)
-        } else {
-            None
-        };
+            std::process::exit(1);
+        }
+    }
+}
 
-        #[cfg(feature = "plugin")]
-        {
-            // Reading signatures from signature file
-            // The plugin.nu file stores the parsed signature collected from each registered plugin
-            if let Some(mut plugin_path) = nu_path::config_dir() {
-                // Path to store plugins signatures
-                plugin_path.push("nushell");
-                plugin_path.push("plugin.nu");
-                engine_state.plugin_signatures = Some(plugin_path.clone());
+fn run_repl(
+    mut engine_state: EngineState,
+    mut stack: Stack,
+    cli_args: &NushellCliArgs,
+    ctrlc: Arc<AtomicBool>,
+) -> Result<()> {
+    use reedline::{FileBackedHistory, History, Reedline, Signal};
 
-                let plugin_filename = plugin_path.to_string_lossy().to_owned();
+    let mut entry_num = 0;
 
-                if let Ok(contents) = std::fs::read_to_string(&plugin_path) {
-                    eval_source(&mut engine_state, &mut stack, &contents, &plugin_filename);
-                }
+    let mut nu_prompt = NushellPrompt::new();
+    let mut prompt_cache = PromptCache::default();
+
+    // The history the line editor recalls from, which also knows how each command went
+    let command_history = match history_path() {
+        Some(path) if !cli_args.no_history => match CommandHistory::open(&path) {
+            Ok(command_history) => Some(command_history),
+            Err(err) => {
+                let working_set = StateWorkingSet::new(&engine_state);
+                report_error(&working_set, &err);
+                None
+            }
+        },
+        _ => None,
+    };
+    let hostname = hostname();
+
+    #[cfg(feature = "plugin")]
+    {
+        // Reading signatures from signature file
+        // The plugin.nu file stores the parsed signature collected from each registered plugin
+        if let Some(mut plugin_path) = nu_path::config_dir() {
+            // Path to store plugins signatures
+            plugin_path.push("nushell");
+            plugin_path.push("plugin.nu");
+            engine_state.plugin_signatures = Some(plugin_path.clone());
+
+            let plugin_filename = plugin_path.to_string_lossy().to_owned();
+
+            if let Ok(contents) = std::fs::read_to_string(&plugin_path) {
+                eval_source(
+                    &mut engine_state,
+                    &mut stack,
+                    &contents,
+                    &plugin_filename,
+                    PipelineData::new(Span::unknown()),
+                );
             }
         }
+    }
 
-        loop {
-            let config = match stack.get_config() {
-                Ok(config) => config,
-                Err(e) => {
-                    let working_set = StateWorkingSet::new(&engine_state);
+    loop {
+        let config = match stack.get_config() {
+            Ok(config) => config,
+            Err(e) => {
+                let working_set = StateWorkingSet::new(&engine_state);
 
-                    report_error(&working_set, &e);
-                    Config::default()
-                }
-            };
+                report_error(&working_set, &e);
+                Config::default()
+            }
+        };
 
-            //Reset the ctrl-c handler
-            ctrlc.store(false, Ordering::SeqCst);
+        //Reset the ctrl-c handler
+        ctrlc.store(false, Ordering::SeqCst);
+
+        let line_editor = Reedline::create()
+            .into_diagnostic()?
+            .with_completion_action_handler(Box::new(FuzzyCompletion {
+                completer: NuCompleter::new(engine_state.clone(), stack.clone()),
+            }))
+            .with_highlighter(Box::new(NuHighlighter {
+                engine_state: engine_state.clone(),
+                config: config.clone(),
+            }))
+            .with_animation(config.animate_prompt)
+            // .with_completion_action_handler(Box::new(
+            //     ListCompletionHandler::default().with_completer(Box::new(completer)),
+            // ))
+            .with_validator(Box::new(NuValidator {
+                engine_state: engine_state.clone(),
+            }))
+            .with_ansi_colors(config.use_ansi_coloring);
+        //FIXME: if config.use_ansi_coloring is false then we should
+        // turn off the hinter but I don't see any way to do that yet.
+
+        let mut line_editor = if let Some(command_history) = &command_history {
+            // The line editor only keeps its history in memory, the entries are saved below
+            let cwd = config.history_cwd_recall.then(|| current_dir(&stack));
+
+            let mut recall = FileBackedHistory::new(HISTORY_RECALL_SIZE);
+            match command_history.recent_commands(cwd.as_deref(), HISTORY_RECALL_SIZE) {
+                Ok(commands) => commands
+                    .into_iter()
+                    .for_each(|command| recall.append(command)),
+                Err(err) => {
+                    let working_set = StateWorkingSet::new(&engine_state);
+                    report_error(&working_set, &err);
+                }
+            }
 
-            let line_editor = Reedline::create()
+            line_editor
+                .with_history(Box::new(recall))
                 .into_diagnostic()?
-                .with_completion_action_handler(Box::new(FuzzyCompletion {
-                    completer: Box::new(NuCompleter::new(engine_state.clone())),
-                }))
-                .with_highlighter(Box::new(NuHighlighter {
-                    engine_state: engine_state.clone(),
-                    config: config.clone(),
-                }))
-                .with_animation(config.animate_prompt)
-                // .with_completion_action_handler(Box::new(
-                //     ListCompletionHandler::default().with_completer(Box::new(completer)),
-                // ))
-                .with_validator(Box::new(NuValidator {
-                    engine_state: engine_state.clone(),
-                }))
-                .with_ansi_colors(config.use_ansi_coloring);
-            //FIXME: if config.use_ansi_coloring is false then we should
-            // turn off the hinter but I don't see any way to do that yet.
-
-            let mut line_editor = if let Some(history_path) = history_path.clone() {
-                line_editor
-                    .with_history(Box::new(
-                        FileBackedHistory::with_file(1000, history_path.clone())
-                            .into_diagnostic()?,
-                    ))
-                    .into_diagnostic()?
-            } else {
-                line_editor
-            };
+        } else {
+            line_editor
+        };
 
-            let prompt = update_prompt(
-                PROMPT_COMMAND,
-                &engine_state,
-                &stack,
-                &mut nu_prompt,
-                &default_prompt,
-            );
+        let prompt = update_prompt(
+            &config,
+            &engine_state,
+            &stack,
+            &mut nu_prompt,
+            &mut prompt_cache,
+        );
 
-            entry_num += 1;
-
-            let input = line_editor.read_line(prompt);
-            match input {
-                Ok(Signal::Success(s)) => {
-                    eval_source(
-                        &mut engine_state,
-                        &mut stack,
-                        &s,
-                        &format!("entry #{}", entry_num),
-                    );
-                }
-                Ok(Signal::CtrlC) => {
-                    println!("Ctrl-c");
-                }
-                Ok(Signal::CtrlD) => {
-                    break;
-                }
-                Ok(Signal::CtrlL) => {
-                    line_editor.clear_screen().into_diagnostic()?;
-                }
-                Err(err) => {
-                    let message = err.to_string();
-                    if !message.contains("duration") {
-                        println!("Error: {:?}", err);
+        entry_num += 1;
+
+        let input = line_editor.read_line(prompt);
+        match input {
+            Ok(Signal::Success(s)) => {
+                let cwd = current_dir(&stack);
+                let start_timestamp = chrono::Local::now();
+                let start_time = Instant::now();
+
+                // External commands and errors set their own exit code
+                set_last_exit_code(&mut stack, 0);
+
+                eval_source(
+                    &mut engine_state,
+                    &mut stack,
+                    &s,
+                    &format!("entry #{}", entry_num),
+                    PipelineData::new(Span::unknown()),
+                );
+
+                let duration_ms = start_time.elapsed().as_millis() as u64;
+                stack.add_env_var(
+                    "CMD_DURATION_MS".into(),
+                    Value::Int {
+                        val: duration_ms as i64,
+                        span: Span::unknown(),
+                    },
+                );
+
+                if let Some(command_history) = &command_history {
+                    if !s.trim().is_empty() {
+                        let entry = HistoryEntry {
+                            command: s,
+                            cwd: Some(cwd),
+                            start_timestamp: Some(start_timestamp.into()),
+                            duration_ms: Some(duration_ms),
+                            hostname: hostname.clone(),
+                            exit_status: Some(last_exit_code(&stack) as i64),
+                        };
+
+                        if let Err(err) = command_history.append(&entry) {
+                            let working_set = StateWorkingSet::new(&engine_state);
+                            report_error(&working_set, &err);
+                        }
                     }
                 }
             }
+            Ok(Signal::CtrlC) => {
+                println!("Ctrl-c");
+            }
+            Ok(Signal::CtrlD) => {
+                break;
+            }
+            Ok(Signal::CtrlL) => {
+                line_editor.clear_screen().into_diagnostic()?;
+            }
+            Err(err) => {
+                let message = err.to_string();
+                if !message.contains("duration") {
+                    println!("Error: {:?}", err);
+                }
+            }
         }
+    }
+
+    Ok(())
+}
 
-        Ok(())
+/// The path of a config file: the one given on the command line, or else the default one in the
+/// nushell config directory if `use_default` is set and the file exists
+fn config_file_path(flag: Option<&str>, default_name: &str, use_default: bool) -> Option<PathBuf> {
+    if let Some(path) = flag {
+        return Some(PathBuf::from(path));
+    }
+
+    if !use_default {
+        return None;
+    }
+
+    let mut config_path = nu_path::config_dir()?;
+    config_path.push("nushell");
+    config_path.push(default_name);
+
+    if config_path.exists() {
+        Some(config_path)
+    } else {
+        None
+    }
+}
+
+fn load_config_file(engine_state: &mut EngineState, stack: &mut Stack, path: &Path) {
+    let config_filename = path.to_string_lossy().to_owned();
+
+    match std::fs::read_to_string(path) {
+        Ok(contents) => {
+            eval_source(
+                engine_state,
+                stack,
+                &contents,
+                &config_filename,
+                PipelineData::new(Span::unknown()),
+            );
+        }
+        Err(err) => {
+            let working_set = StateWorkingSet::new(engine_state);
+            report_error(
+                &working_set,
+                &ShellError::LabeledError(
+                    format!("Could not read config file '{}'", config_filename),
+                    err.to_string(),
+                ),
+            );
+        }
+    }
+}
+
+// Plugins also exit when nushell does, as their stdin is closed, but this waits for them
+fn stop_plugins() {
+    #[cfg(feature = "plugin")]
+    nu_plugin::plugin_manager().stop_all();
+}
+
+/// The exit code of the last external command, or 1 if the last thing to run failed
+fn last_exit_code(stack: &Stack) -> i32 {
+    match stack.get_env_var("LAST_EXIT_CODE") {
+        Some(Value::Int { val, .. }) => val as i32,
+        _ => 0,
     }
 }
 
@@ -438,62 +653,12 @@ fn print_pipeline_data(
     Ok(())
 }
 
-fn update_prompt<'prompt>(
-    env_variable: &str,
-    engine_state: &EngineState,
-    stack: &Stack,
-    nu_prompt: &'prompt mut NushellPrompt,
-    default_prompt: &'prompt DefaultPrompt,
-) -> &'prompt dyn Prompt {
-    let prompt_command = match stack.get_env_var(env_variable) {
-        Some(prompt) => prompt,
-        None => return default_prompt as &dyn Prompt,
-    };
-
-    // Checking if the PROMPT_COMMAND is the same to avoid evaluating constantly
-    // the same command, thus saturating the contents in the EngineState
-    if !nu_prompt.is_new_prompt(prompt_command.as_str()) {
-        return nu_prompt as &dyn Prompt;
-    }
-
-    let block = {
-        let mut working_set = StateWorkingSet::new(engine_state);
-        let (output, err) = parse(&mut working_set, None, prompt_command.as_bytes(), false);
-        if let Some(err) = err {
-            report_error(&working_set, &err);
-            return default_prompt as &dyn Prompt;
-        }
-        output
-    };
-
-    let mut stack = stack.clone();
-
-    let evaluated_prompt = match eval_block(
-        engine_state,
-        &mut stack,
-        &block,
-        PipelineData::new(Span::unknown()),
-    ) {
-        Ok(pipeline_data) => {
-            let config = stack.get_config().unwrap_or_default();
-            pipeline_data.collect_string("", &config)
-        }
-        Err(..) => {
-            // If we can't run the custom prompt, give them the default
-            return default_prompt as &dyn Prompt;
-        }
-    };
-
-    nu_prompt.update_prompt(prompt_command, evaluated_prompt);
-
-    nu_prompt as &dyn Prompt
-}
-
 fn eval_source(
     engine_state: &mut EngineState,
     stack: &mut Stack,
     source: &str,
     fname: &str,
+    input: PipelineData,
 ) -> bool {
     let (block, delta) = {
         let mut working_set = StateWorkingSet::new(engine_state);
@@ -505,6 +670,7 @@ fn eval_source(
         );
         if let Some(err) = err {
             report_error(&working_set, &err);
+            set_last_exit_code(stack, 1);
             return false;
         }
 
@@ -516,21 +682,29 @@ fn eval_source(
         report_error(&working_set, &err);
     }
 
-    match eval_block(
-        engine_state,
-        stack,
-        &block,
-        PipelineData::new(Span::unknown()),
-    ) {
-        Ok(pipeline_data) => {
+    let result = eval_block(engine_state, stack, &block, input);
+
+    // Commands like `plugin rescan` leave their changes to the definitions to be merged here
+    if let Err(err) = engine_state.merge_pending_delta() {
+        let working_set = StateWorkingSet::new(engine_state);
+        report_error(&working_set, &err);
+    }
+
+    match result {
+        Ok(mut pipeline_data) => {
+            let external_exit = pipeline_data.take_external_exit();
+
             if let Err(err) = print_pipeline_data(pipeline_data, engine_state, stack) {
                 let working_set = StateWorkingSet::new(engine_state);
 
                 report_error(&working_set, &err);
+                set_last_exit_code(stack, 1);
 
                 return false;
             }
 
+            read_external_exit(stack, external_exit);
+
             // reset vt processing, aka ansi because illbehaved externals can break it
             #[cfg(windows)]
             {
@@ -541,6 +715,7 @@ fn eval_source(
             let working_set = StateWorkingSet::new(engine_state);
 
             report_error(&working_set, &err);
+            set_last_exit_code(stack, 1);
 
             return false;
         }
@@ -549,6 +724,33 @@ fn eval_source(
     true
 }
 
+/// The directory a command runs in
+fn current_dir(stack: &Stack) -> String {
+    match stack.get_env_var("PWD") {
+        Some(pwd) => pwd.as_string().unwrap_or_default(),
+        None => std::env::current_dir()
+            .map(|cwd| cwd.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn set_last_exit_code(stack: &mut Stack, exit_code: i64) {
+    stack.add_env_var(
+        "LAST_EXIT_CODE".into(),
+        Value::Int {
+            val: exit_code,
+            span: Span::unknown(),
+        },
+    );
+}
+
+/// Set the exit code of the external whose output was just printed, now that it has finished
+fn read_external_exit(stack: &mut Stack, external_exit: Option<mpsc::Receiver<ExternalExit>>) {
+    if let Some(Ok(ExternalExit { exit_code, .. })) = external_exit.map(|exit| exit.recv()) {
+        set_last_exit_code(stack, exit_code);
+    }
+}
+
 #[cfg(windows)]
 pub fn enable_vt_processing() -> Result<(), ShellError> {
     pub const ENABLE_PROCESSED_OUTPUT: u32 = 0x0001;
@@ -0,0 +1,154 @@
+use nu_cli::NushellPrompt;
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    ast::Block,
+    engine::{EngineState, Stack, StateWorkingSet},
+    Config, PipelineData, Span, Value,
+};
+use reedline::Prompt;
+
+use crate::{current_dir, report_error};
+
+// Name of environment variable where the prompt could be stored
+pub(crate) const PROMPT_COMMAND: &str = "PROMPT_COMMAND";
+pub(crate) const PROMPT_COMMAND_RIGHT: &str = "PROMPT_COMMAND_RIGHT";
+pub(crate) const PROMPT_INDICATOR: &str = "PROMPT_INDICATOR";
+pub(crate) const PROMPT_INDICATOR_VI_INSERT: &str = "PROMPT_INDICATOR_VI_INSERT";
+pub(crate) const PROMPT_INDICATOR_VI_NORMAL: &str = "PROMPT_INDICATOR_VI_NORMAL";
+pub(crate) const PROMPT_MULTILINE_INDICATOR: &str = "PROMPT_MULTILINE_INDICATOR";
+
+const PROMPT_VARIABLES: [&str; 6] = [
+    PROMPT_COMMAND,
+    PROMPT_COMMAND_RIGHT,
+    PROMPT_INDICATOR,
+    PROMPT_INDICATOR_VI_INSERT,
+    PROMPT_INDICATOR_VI_NORMAL,
+    PROMPT_MULTILINE_INDICATOR,
+];
+
+/// What the prompt was last evaluated with. The prompt is only evaluated again when one of these
+/// changes, so a slow prompt (eg. one showing the git status) doesn't run for every line.
+#[derive(Default)]
+pub(crate) struct PromptCache {
+    key: Option<PromptKey>,
+}
+
+#[derive(PartialEq)]
+struct PromptKey {
+    cwd: String,
+    last_exit_code: Option<Value>,
+    definitions: Vec<Option<Value>>,
+}
+
+impl PromptKey {
+    fn new(stack: &Stack) -> Self {
+        PromptKey {
+            cwd: current_dir(stack),
+            last_exit_code: stack.get_env_var("LAST_EXIT_CODE"),
+            definitions: PROMPT_VARIABLES
+                .iter()
+                .map(|name| stack.get_env_var(name))
+                .collect(),
+        }
+    }
+}
+
+/// Evaluate the prompt env variables into the prompt, unless nothing they depend on has changed
+/// since the last time
+pub(crate) fn update_prompt<'prompt>(
+    config: &Config,
+    engine_state: &EngineState,
+    stack: &Stack,
+    nu_prompt: &'prompt mut NushellPrompt,
+    cache: &mut PromptCache,
+) -> &'prompt dyn Prompt {
+    let key = PromptKey::new(stack);
+    if cache.key.as_ref() == Some(&key) {
+        return nu_prompt as &dyn Prompt;
+    }
+
+    // Without a prompt command, the prompt shows the current directory
+    let left_prompt_string = get_prompt_string(PROMPT_COMMAND, config, engine_state, stack)
+        .or_else(|| Some(key.cwd.clone()));
+
+    nu_prompt.update_all_prompt_strings(
+        left_prompt_string,
+        get_prompt_string(PROMPT_COMMAND_RIGHT, config, engine_state, stack),
+        get_prompt_string(PROMPT_INDICATOR, config, engine_state, stack),
+        get_prompt_string(PROMPT_MULTILINE_INDICATOR, config, engine_state, stack),
+        (
+            get_prompt_string(PROMPT_INDICATOR_VI_INSERT, config, engine_state, stack),
+            get_prompt_string(PROMPT_INDICATOR_VI_NORMAL, config, engine_state, stack),
+        ),
+    );
+
+    cache.key = Some(key);
+
+    nu_prompt as &dyn Prompt
+}
+
+// A prompt variable is a block that is evaluated to get that part of the prompt. The prompt
+// commands can also hold their code as a string, while the other variables are used as they are.
+// If the evaluation fails, the default for that part of the prompt is used.
+fn get_prompt_string(
+    prompt: &str,
+    config: &Config,
+    engine_state: &EngineState,
+    stack: &Stack,
+) -> Option<String> {
+    match stack.get_env_var(prompt)? {
+        Value::Block { val: block_id, .. } => {
+            let block = engine_state.get_block(block_id);
+            let mut stack = stack.collect_captures(&block.captures);
+
+            eval_prompt_block(block, config, engine_state, &mut stack)
+        }
+        Value::String { val: source, .. }
+            if prompt == PROMPT_COMMAND || prompt == PROMPT_COMMAND_RIGHT =>
+        {
+            let (block, delta) = {
+                let mut working_set = StateWorkingSet::new(engine_state);
+                let (output, err) = parse(&mut working_set, None, source.as_bytes(), false);
+                if let Some(err) = err {
+                    report_error(&working_set, &err);
+                    return None;
+                }
+                (output, working_set.render())
+            };
+
+            // The blocks of the code have to be found while it runs, but they are only kept for
+            // this evaluation, so evaluating the prompt again and again doesn't grow the state
+            let mut engine_state = engine_state.clone();
+            if let Err(err) = engine_state.merge_delta(delta) {
+                let working_set = StateWorkingSet::new(&engine_state);
+                report_error(&working_set, &err);
+                return None;
+            }
+
+            eval_prompt_block(&block, config, &engine_state, &mut stack.clone())
+        }
+        value => Some(value.into_string("", config)),
+    }
+}
+
+fn eval_prompt_block(
+    block: &Block,
+    config: &Config,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+) -> Option<String> {
+    match eval_block(
+        engine_state,
+        stack,
+        block,
+        PipelineData::new(Span::unknown()),
+    ) {
+        Ok(pipeline_data) => Some(pipeline_data.collect_string("", config)),
+        Err(err) => {
+            let working_set = StateWorkingSet::new(engine_state);
+            report_error(&working_set, &err);
+            None
+        }
+    }
+}
@@ -1023,6 +1023,58 @@ fn custom_switch4() -> TestResult {
     )
 }
 
+#[test]
+fn custom_default_positional() -> TestResult {
+    run_test(
+        r#"def florb [ x: int = 5 ] { $x }; (florb) + (florb 3)"#,
+        "8",
+    )
+}
+
+#[test]
+fn custom_default_flag() -> TestResult {
+    run_test(
+        r#"def florb [ --name: string = "bar" ] { $name }; florb"#,
+        "bar",
+    )
+}
+
+#[test]
+fn custom_default_in_help() -> TestResult {
+    run_test(
+        r#"def florb [ --name: string = "bar" ] { $name }; florb --help | str contains 'default: "bar"'"#,
+        "true",
+    )
+}
+
+#[test]
+fn custom_default_must_be_constant() -> TestResult {
+    fail_test(r#"def florb [ x = (1 + 2) ] { $x }"#, "not a constant")
+}
+
+#[test]
+fn custom_param_type_checked_at_runtime() -> TestResult {
+    fail_test(
+        r#"def florb [ x: int ] { $x }; florb ("foo" | str trim)"#,
+        "expected int for x",
+    )
+}
+
+#[test]
+fn pipeline_input_type_mismatch() -> TestResult {
+    fail_test("ls | str length", "takes string or list<string>")
+}
+
+#[test]
+fn pipeline_input_types_match() -> TestResult {
+    run_test("date now | date format '%Y' | str length", "4")
+}
+
+#[test]
+fn pipeline_input_type_with_cell_path() -> TestResult {
+    run_test("(ls | str length name | length) >= 0", "true")
+}
+
 #[test]
 fn bad_var_name() -> TestResult {
     fail_test(r#"let $"foo bar" = 4"#, "can't contain")
@@ -1266,3 +1318,300 @@ fn comment_multiline() -> TestResult {
         "10",
     )
 }
+
+#[test]
+fn open_and_save_roundtrip() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("roundtrip.json");
+
+    run_test(
+        &format!(
+            r#"[[a b]; [1 2]] | save "{0}"; open "{0}" | get b.0"#,
+            path.display()
+        ),
+        "2",
+    )
+}
+
+#[test]
+fn save_refuses_to_overwrite() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("exists.txt");
+
+    fail_test(
+        &format!(r#"'a' | save "{0}"; 'b' | save "{0}""#, path.display()),
+        "already exists",
+    )
+}
+
+#[test]
+fn reduce_with_fold() -> TestResult {
+    run_test("[1 2 3] | reduce -f 10 { |it, acc| $it + $acc }", "16")
+}
+
+#[test]
+fn reduce_needs_named_parameters() -> TestResult {
+    fail_test(
+        "[1 2 3] | reduce -f 10 { |it| $it }",
+        "Expected two block parameters",
+    )
+}
+
+#[test]
+fn insert_existing_column_fails() -> TestResult {
+    fail_test("{'a': 1} | insert a 2", "already exists")
+}
+
+#[test]
+fn try_catch_error_record() -> TestResult {
+    run_test(
+        "try { error make {msg: 'oops', label: 'here'} } catch { |e| $e.msg }",
+        "oops",
+    )
+}
+
+#[test]
+fn try_without_catch() -> TestResult {
+    run_test(
+        "try { error make {msg: 'oops', label: 'here'} }; 'recovered'",
+        "recovered",
+    )
+}
+
+#[test]
+fn error_make_raises() -> TestResult {
+    fail_test(
+        "error make {msg: 'custom failure', label: 'here'}",
+        "custom failure",
+    )
+}
+
+#[test]
+fn loop_with_break() -> TestResult {
+    run_test("def foo [] { loop { break }; 'done' }; foo", "done")
+}
+
+#[test]
+fn for_with_break_and_continue() -> TestResult {
+    run_test(
+        "for x in 1..10 { if $x == 2 { continue } else if $x == 4 { break } else { $x } } | length",
+        "2",
+    )
+}
+
+#[test]
+fn early_return() -> TestResult {
+    run_test(r#"def foo [] { return "early"; "late" }; foo"#, "early")
+}
+
+#[test]
+fn return_from_inside_loop() -> TestResult {
+    run_test(
+        "def foo [] { for x in [1 2 3] { if $x == 2 { return $x } }; 0 }; foo",
+        "2",
+    )
+}
+
+#[test]
+fn break_outside_loop() -> TestResult {
+    fail_test("break", "outside of loop")
+}
+
+#[test]
+fn break_inside_custom_command() -> TestResult {
+    fail_test(
+        "def f [] { break }; for x in [1 2 3] { f; $x }",
+        "outside of loop",
+    )
+}
+
+#[test]
+fn for_over_endless_range_streams() -> TestResult {
+    run_test("for x in 1.. { $x } | first 3 | math sum", "6")
+}
+
+#[test]
+fn endless_stream_before_semicolon_is_dropped() -> TestResult {
+    run_test("for x in 1.. { $x }; 'done'", "done")
+}
+
+#[test]
+fn env_variable() -> TestResult {
+    run_test(r#"let-env FOO = "bar"; $env.FOO"#, "bar")
+}
+
+#[test]
+fn let_env_structured_value() -> TestResult {
+    run_test("let-env FOO = [a b c]; $nu.env.FOO | length", "3")
+}
+
+#[test]
+fn with_env_structured_value() -> TestResult {
+    run_test("with-env [FOO {'a': 1}] { $nu.env.FOO.a }", "1")
+}
+
+#[test]
+fn complete_needs_external_input() -> TestResult {
+    fail_test("[1 2] | complete", "external command")
+}
+
+#[test]
+fn commands_flag() -> TestResult {
+    let output = Command::cargo_bin("engine-q")?
+        .args(["-c", "[1 2 3] | length"])
+        .output()?;
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "3");
+
+    Ok(())
+}
+
+#[test]
+fn stdin_flag() -> TestResult {
+    let mut child = Command::cargo_bin("engine-q")?
+        .args(["--stdin", "-c", "$in | str length"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()?;
+
+    child
+        .stdin
+        .take()
+        .ok_or("stdin not piped")?
+        .write_all(b"hello")?;
+
+    let output = child.wait_with_output()?;
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "5");
+
+    Ok(())
+}
+
+#[test]
+fn error_sets_exit_code() -> TestResult {
+    let output = Command::cargo_bin("engine-q")?
+        .args(["-c", "error make {msg: 'oops'}"])
+        .output()?;
+
+    assert_eq!(output.status.code(), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_cli_flag() -> TestResult {
+    let output = Command::cargo_bin("engine-q")?
+        .arg("--not-a-flag")
+        .output()?;
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown flag"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_flag_value_in_wrong_position() -> TestResult {
+    let output = Command::cargo_bin("engine-q")?
+        .args(["-cl", "ls"])
+        .output()?;
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("must come last in '-cl'"));
+
+    Ok(())
+}
+
+#[test]
+fn extern_unknown_flag() -> TestResult {
+    fail_test(
+        r#"extern "git clone" [repo: string, --depth: int]; git clone --not-a-flag"#,
+        "doesn't have flag",
+    )
+}
+
+#[test]
+fn extern_flag_is_type_checked() -> TestResult {
+    fail_test(
+        r#"extern "git clone" [repo: string, --depth: int]; git clone --depth deep repo"#,
+        "int",
+    )
+}
+
+#[cfg(not(windows))]
+#[test]
+fn extern_runs_external() -> TestResult {
+    run_test(r#"extern sh [-c: string]; sh -c "echo hello""#, "hello")
+}
+
+#[cfg(not(windows))]
+#[test]
+fn exit_code_of_piped_external() -> TestResult {
+    run_test(
+        r#"^sh -c "exit 3" | lines; let c = $nu.env.LAST_EXIT_CODE; let-env LAST_EXIT_CODE = 0; $"($c)""#,
+        "3",
+    )
+}
+
+#[cfg(not(windows))]
+#[test]
+fn external_exit_code_sets_exit_status() -> TestResult {
+    let output = Command::cargo_bin("engine-q")?
+        .args(["-c", r#"^sh -c "exit 3""#])
+        .output()?;
+
+    assert_eq!(output.status.code(), Some(3));
+
+    Ok(())
+}
+
+#[cfg(not(windows))]
+#[test]
+fn dropped_external_output_does_not_hang() -> TestResult {
+    run_test(r#"^yes | first 1; 'done'"#, "done")
+}
+
+#[test]
+fn custom_input_output_types() -> TestResult {
+    run_test(
+        r#"def shout [] : string -> string { $in | str upcase }; "hi" | shout"#,
+        "HI",
+    )
+}
+
+#[test]
+fn custom_output_type_checked_in_pipeline() -> TestResult {
+    fail_test(
+        "def five []: nothing -> int { 5 }; five | str length",
+        "gives int",
+    )
+}
+
+#[test]
+fn custom_input_output_types_need_arrow() -> TestResult {
+    fail_test("def five [] : int { 5 }", "input and output types")
+}
+
+#[test]
+fn sort_by_dates_within_a_day() -> TestResult {
+    run_test(
+        r#"["2021-01-01 12:00:00 +0000" "2021-01-01 08:00:00 +0000"] | into datetime | sort-by | first | date format '%H'"#,
+        "08",
+    )
+}
+
+#[test]
+fn sort_by_whole_records() -> TestResult {
+    run_test("[[a b]; [2 1] [1 3] [1 2]] | sort-by | first | get b", "2")
+}
+
+#[cfg(not(windows))]
+#[test]
+fn failing_env_conversion_is_reported() -> TestResult {
+    fail_test(
+        r#"let config = {env_conversions: {FOO: {to_string: { |v| 5 }}}}; let-env FOO = [a b]; ^sh -c "echo $FOO""#,
+        "Failed to convert environment variable 'FOO'",
+    )
+}